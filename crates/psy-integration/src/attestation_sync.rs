@@ -0,0 +1,130 @@
+//! Attestation Reputation Sync
+//!
+//! Keeps attestation reputation events in step with the registry's
+//! attestation freshness.
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::AgentRegistry;
+use crate::reputation::{ReputationError, ReputationEvent, ReputationTracker};
+
+/// Attestation state last seen by `sync_attestation_reputation`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct AttestationSyncState {
+    /// Registration's `last_attestation`
+    pub last_attestation: u64,
+    /// Whether the attestation was fresh
+    pub fresh: bool,
+}
+
+/// Events emitted by one sync, agent IDs in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AttestationSyncReport {
+    /// Agents credited with `AttestationVerified`
+    pub verified: Vec<String>,
+    /// Agents penalized with `AttestationExpired`
+    pub expired: Vec<String>,
+}
+
+impl AttestationSyncReport {
+    /// Whether no events were emitted
+    pub fn is_empty(&self) -> bool {
+        self.verified.is_empty() && self.expired.is_empty()
+    }
+}
+
+/// Emit attestation events for changes in the registry since the last sync
+///
+/// An agent gets `AttestationVerified` when their attestation has been
+/// refreshed, and `AttestationExpired` once when it goes stale, including
+/// when it's already stale the first time they're seen. The state seen is
+/// kept on the tracker and saved with it, so running this on a timer never
+/// repeats an event.
+pub fn sync_attestation_reputation(
+    registry: &AgentRegistry,
+    tracker: &mut ReputationTracker,
+) -> Result<AttestationSyncReport, ReputationError> {
+    let mut registrations = registry.list_all();
+    registrations.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+
+    let mut report = AttestationSyncReport::default();
+    for registration in registrations {
+        let current = AttestationSyncState {
+            last_attestation: registration.last_attestation,
+            fresh: registration.is_attestation_fresh(),
+        };
+        let previous = tracker.attestation_sync_state(&registration.agent_id);
+        let refreshed = previous.is_some_and(|p| current.last_attestation > p.last_attestation);
+        let expired = !current.fresh && previous.map_or(true, |p| p.fresh);
+
+        if refreshed && current.fresh {
+            tracker.apply_event_full(&registration.agent_id, ReputationEvent::AttestationVerified)?;
+            report.verified.push(registration.agent_id.clone());
+        } else if expired {
+            tracker.apply_event_full(&registration.agent_id, ReputationEvent::AttestationExpired)?;
+            report.expired.push(registration.agent_id.clone());
+        }
+        tracker.set_attestation_sync_state(&registration.agent_id, current);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sdkey_manager::{AgentMetadata, AgentPermissions, AgentSDKey};
+    use crate::clock::{Clock, SystemClock};
+    use crate::registry::{AgentRegistration, AttestationVerifier};
+    use crate::reputation::ReputationEventKind;
+
+    /// Accepts any quote
+    struct AcceptAll;
+
+    impl AttestationVerifier for AcceptAll {
+        fn verify(&self, _registration: &AgentRegistration) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    fn register(registry: &mut AgentRegistry, age_secs: u64) -> String {
+        let sdkey = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let mut registration = AgentRegistration::from_sdkey(&sdkey, "Agent".into(), String::new());
+        registration.last_attestation = SystemClock.now() - age_secs;
+        registry.register(registration).unwrap()
+    }
+
+    #[test]
+    fn test_sync_attestation_reputation() {
+        let mut registry = AgentRegistry::new().with_verifier(AcceptAll);
+        let fresh = register(&mut registry, 60);
+        let stale = register(&mut registry, 2 * 86_400);
+        let refreshed = register(&mut registry, 2 * 86_400);
+        let mut tracker = ReputationTracker::new();
+
+        let report = sync_attestation_reputation(&registry, &mut tracker).unwrap();
+        let mut expected = vec![stale.clone(), refreshed.clone()];
+        expected.sort();
+        assert_eq!(report.expired, expected);
+        assert!(report.verified.is_empty());
+        assert!(tracker.get(&fresh).is_none());
+
+        // Nothing changed, nothing repeated
+        assert!(sync_attestation_reputation(&registry, &mut tracker).unwrap().is_empty());
+
+        registry.refresh_attestation(&refreshed, vec![1], Default::default()).unwrap();
+        let report = sync_attestation_reputation(&registry, &mut tracker).unwrap();
+        assert_eq!(report.verified, [refreshed.as_str()]);
+        assert!(report.expired.is_empty());
+        assert!(sync_attestation_reputation(&registry, &mut tracker).unwrap().is_empty());
+
+        assert_eq!(tracker.event_count(&stale, ReputationEventKind::AttestationExpired), 1);
+        assert_eq!(tracker.event_count(&refreshed, ReputationEventKind::AttestationExpired), 1);
+        assert_eq!(tracker.event_count(&refreshed, ReputationEventKind::AttestationVerified), 1);
+
+        // The state seen survives a save, so a restart doesn't penalize again
+        let mut saved = Vec::new();
+        tracker.save_to(&mut saved).unwrap();
+        let mut loaded = ReputationTracker::load_from(saved.as_slice()).unwrap();
+        assert!(sync_attestation_reputation(&registry, &mut loaded).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//! Syndicate Directory
+//!
+//! Lets agents discover syndicates by tag, visibility, and entry requirements.
+
+use std::collections::HashMap;
+
+use crate::syndicate::{Syndicate, SyndicateVisibility};
+
+/// Collection of syndicates available for discovery
+#[derive(Default)]
+pub struct SyndicateDirectory {
+    /// Syndicates by ID
+    syndicates: HashMap<String, Syndicate>,
+}
+
+impl SyndicateDirectory {
+    /// Create empty directory
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a syndicate to the directory
+    pub fn add(&mut self, syndicate: Syndicate) -> Result<String, DirectoryError> {
+        let id = syndicate.id().to_string();
+        if self.syndicates.contains_key(&id) {
+            return Err(DirectoryError::AlreadyListed(id));
+        }
+        self.syndicates.insert(id.clone(), syndicate);
+        Ok(id)
+    }
+
+    /// Remove a syndicate from the directory
+    pub fn remove(&mut self, syndicate_id: &str) -> Option<Syndicate> {
+        self.syndicates.remove(syndicate_id)
+    }
+
+    /// Get syndicate by ID
+    pub fn get(&self, syndicate_id: &str) -> Option<&Syndicate> {
+        self.syndicates.get(syndicate_id)
+    }
+
+    /// Get mutable syndicate by ID
+    pub fn get_mut(&mut self, syndicate_id: &str) -> Option<&mut Syndicate> {
+        self.syndicates.get_mut(syndicate_id)
+    }
+
+    /// Public syndicates carrying a tag (case-insensitive)
+    pub fn find_by_tag(&self, tag: &str) -> Vec<&Syndicate> {
+        self.sorted(|s| {
+            s.config().visibility == SyndicateVisibility::Public
+                && s.config().tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+        })
+    }
+
+    /// Public syndicates with room that an agent with `score` reputation can join
+    pub fn find_open_with_min_reputation(&self, score: u32) -> Vec<&Syndicate> {
+        self.sorted(|s| {
+            s.config().visibility == SyndicateVisibility::Public
+                && s.config().min_reputation <= score
+                && s.has_open_slots()
+        })
+    }
+
+    /// All public syndicates
+    pub fn list_public(&self) -> Vec<&Syndicate> {
+        self.sorted(|s| s.config().visibility == SyndicateVisibility::Public)
+    }
+
+    /// Get total listed count
+    pub fn count(&self) -> usize {
+        self.syndicates.len()
+    }
+
+    /// Matching syndicates ordered by ID
+    fn sorted(&self, predicate: impl Fn(&Syndicate) -> bool) -> Vec<&Syndicate> {
+        let mut matching: Vec<&Syndicate> = self.syndicates
+            .values()
+            .filter(|s| predicate(s))
+            .collect();
+        matching.sort_by(|a, b| a.id().cmp(b.id()));
+        matching
+    }
+}
+
+/// Directory errors
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DirectoryError {
+    #[error("Syndicate already listed: {0}")]
+    AlreadyListed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syndicate::SyndicateConfig;
+
+    fn syndicate(id: &str, visibility: SyndicateVisibility, tags: &[&str], min_reputation: u32) -> Syndicate {
+        let config = SyndicateConfig {
+            id: id.into(),
+            visibility,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            min_reputation,
+            max_members: 2,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap();
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate
+    }
+
+    #[test]
+    fn test_directory_queries() {
+        let mut directory = SyndicateDirectory::new();
+        directory.add(syndicate("defi", SyndicateVisibility::Public, &["DeFi", "arbitrage"], 300)).unwrap();
+        directory.add(syndicate("elite", SyndicateVisibility::Public, &["defi"], 900)).unwrap();
+        directory.add(syndicate("secret", SyndicateVisibility::Private, &["defi"], 0)).unwrap();
+        directory.add(syndicate("club", SyndicateVisibility::InviteOnly, &["nft"], 0)).unwrap();
+        assert!(matches!(
+            directory.add(syndicate("defi", SyndicateVisibility::Public, &[], 0)),
+            Err(DirectoryError::AlreadyListed(_))
+        ));
+
+        let ids = |found: Vec<&Syndicate>| found.iter().map(|s| s.id().to_string()).collect::<Vec<_>>();
+        assert_eq!(ids(directory.find_by_tag("defi")), ["defi", "elite"]);
+        assert_eq!(ids(directory.list_public()), ["defi", "elite"]);
+        assert_eq!(ids(directory.find_open_with_min_reputation(500)), ["defi"]);
+
+        // Full syndicates are no longer open
+        directory.get_mut("defi").unwrap().add_member("alice".into(), 500).unwrap();
+        assert!(directory.find_open_with_min_reputation(500).is_empty());
+    }
+}
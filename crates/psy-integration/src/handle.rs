@@ -0,0 +1,286 @@
+//! Shared Syndicate Handle
+//!
+//! Lets concurrent services share one syndicate without serializing reads
+//! behind writes.
+
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use zk_proofs::PerformanceProof;
+
+use crate::syndicate::{
+    MemberRole, Proposal, ProposalStatus, ProposalType, RemovedMember, Syndicate,
+    SyndicateConfig, SyndicateError, SyndicateInvite, SyndicateMember, SyndicateSnapshot, Treasury,
+    VoteChoice, VoteDelegation,
+};
+
+/// Cloneable, thread-safe handle to a `Syndicate`
+///
+/// Reads take a shared lock and mutations an exclusive one. Accessors
+/// return owned clones since references can't outlive the lock. Anything
+/// not wrapped here is reachable through `read` and `write`.
+#[derive(Clone)]
+pub struct SyndicateHandle {
+    inner: Arc<RwLock<Syndicate>>,
+}
+
+impl SyndicateHandle {
+    /// Wrap a syndicate
+    pub fn new(syndicate: Syndicate) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(syndicate)),
+        }
+    }
+
+    /// Run a closure with shared access
+    pub fn read<T>(&self, f: impl FnOnce(&Syndicate) -> T) -> T {
+        f(&self.read_lock())
+    }
+
+    /// Run a closure with exclusive access
+    pub fn write<T>(&self, f: impl FnOnce(&mut Syndicate) -> T) -> T {
+        f(&mut self.write_lock())
+    }
+
+    /// Get syndicate ID
+    pub fn id(&self) -> String {
+        self.read_lock().id().to_string()
+    }
+
+    /// Get configuration
+    pub fn config(&self) -> SyndicateConfig {
+        self.read_lock().config().clone()
+    }
+
+    /// Capture the syndicate's state
+    pub fn snapshot(&self) -> SyndicateSnapshot {
+        self.read_lock().snapshot()
+    }
+
+    /// Get member
+    pub fn get_member(&self, agent_id: &str) -> Option<SyndicateMember> {
+        self.read_lock().get_member(agent_id).cloned()
+    }
+
+    /// Get active members
+    pub fn active_members(&self) -> Vec<SyndicateMember> {
+        self.read_lock().active_members().into_iter().cloned().collect()
+    }
+
+    /// Get member count
+    pub fn member_count(&self) -> usize {
+        self.read_lock().member_count()
+    }
+
+    /// Get proposal
+    pub fn get_proposal(&self, proposal_id: &str) -> Option<Proposal> {
+        self.read_lock().get_proposal(proposal_id).cloned()
+    }
+
+    /// List active proposals
+    pub fn active_proposals(&self) -> Vec<Proposal> {
+        self.read_lock().active_proposals().into_iter().cloned().collect()
+    }
+
+    /// Voting power of a member including power delegated to them
+    pub fn effective_voting_power(&self, agent_id: &str) -> u64 {
+        self.read_lock().effective_voting_power(agent_id)
+    }
+
+    /// Get treasury
+    pub fn treasury(&self) -> Treasury {
+        self.read_lock().treasury().clone()
+    }
+
+    /// Add founder
+    pub fn add_founder(&self, agent_id: String) -> Result<(), SyndicateError> {
+        self.write_lock().add_founder(agent_id)
+    }
+
+    /// Request membership
+    pub fn request_membership(
+        &self,
+        agent_id: String,
+        reputation: u32,
+        proof: Option<&PerformanceProof>,
+        invite: Option<&SyndicateInvite>,
+    ) -> Result<String, SyndicateError> {
+        self.write_lock().request_membership(agent_id, reputation, proof, invite)
+    }
+
+    /// Invite an agent
+    pub fn invite(&self, actor_id: &str, agent_id: &str) -> Result<(), SyndicateError> {
+        self.write_lock().invite(actor_id, agent_id)
+    }
+
+    /// Add member directly
+    pub fn add_member(&self, agent_id: String, reputation: u32) -> Result<(), SyndicateError> {
+        self.write_lock().add_member(agent_id, reputation)
+    }
+
+    /// Remove member
+    pub fn remove_member(&self, agent_id: &str, reason: &str) -> Result<RemovedMember, SyndicateError> {
+        self.write_lock().remove_member(agent_id, reason)
+    }
+
+    /// Leave the syndicate voluntarily
+    pub fn leave(&self, agent_id: &str) -> Result<(), SyndicateError> {
+        self.write_lock().leave(agent_id)
+    }
+
+    /// Change a member's role
+    pub fn set_role(
+        &self,
+        actor_id: &str,
+        target_id: &str,
+        new_role: MemberRole,
+    ) -> Result<MemberRole, SyndicateError> {
+        self.write_lock().set_role(actor_id, target_id, new_role)
+    }
+
+    /// Create proposal
+    pub fn create_proposal(
+        &self,
+        proposer: String,
+        proposal_type: ProposalType,
+    ) -> Result<Proposal, SyndicateError> {
+        self.write_lock().create_proposal(proposer, proposal_type).cloned()
+    }
+
+    /// Vote on a proposal
+    pub fn vote(&self, proposal_id: &str, voter: &str, choice: VoteChoice) -> Result<(), SyndicateError> {
+        self.write_lock().vote(proposal_id, voter, choice)
+    }
+
+    /// Change an existing vote
+    pub fn change_vote(
+        &self,
+        proposal_id: &str,
+        voter: &str,
+        new_choice: VoteChoice,
+    ) -> Result<(), SyndicateError> {
+        self.write_lock().change_vote(proposal_id, voter, new_choice)
+    }
+
+    /// Delegate a member's voting power to another member
+    pub fn delegate_vote(&self, from: &str, to: &str, expires_at: u64) -> Result<(), SyndicateError> {
+        self.write_lock().delegate_vote(from, to, expires_at)
+    }
+
+    /// Withdraw a member's vote delegation
+    pub fn revoke_vote_delegation(&self, from: &str) -> Result<VoteDelegation, SyndicateError> {
+        self.write_lock().revoke_vote_delegation(from)
+    }
+
+    /// Cancel a proposal
+    pub fn cancel_proposal(&self, actor: &str, proposal_id: &str) -> Result<(), SyndicateError> {
+        self.write_lock().cancel_proposal(actor, proposal_id)
+    }
+
+    /// Veto a proposal
+    pub fn veto(&self, actor: &str, proposal_id: &str, reason: &str) -> Result<(), SyndicateError> {
+        self.write_lock().veto(actor, proposal_id, reason)
+    }
+
+    /// Finalize a proposal
+    pub fn finalize_proposal(&self, proposal_id: &str) -> Result<ProposalStatus, SyndicateError> {
+        self.write_lock().finalize_proposal(proposal_id)
+    }
+
+    /// Finalize all active proposals whose deadline has passed
+    pub fn sweep_expired(&self, now: u64) -> Vec<(String, ProposalStatus)> {
+        self.write_lock().sweep_expired(now)
+    }
+
+    /// Execute a passed proposal
+    pub fn execute_proposal(&self, proposal_id: &str) -> Result<String, SyndicateError> {
+        self.write_lock().execute_proposal(proposal_id)
+    }
+
+    /// Update member reputation
+    pub fn update_reputation(&self, agent_id: &str, delta: i32) -> Result<u32, SyndicateError> {
+        self.write_lock().update_reputation(agent_id, delta)
+    }
+
+    /// Record contribution
+    pub fn record_contribution(&self, agent_id: &str, amount: u64) -> Result<(), SyndicateError> {
+        self.write_lock().record_contribution(agent_id, amount)
+    }
+
+    /// Deposit into the treasury
+    pub fn deposit(&self, agent_id: &str, amount: u64) -> Result<(), SyndicateError> {
+        self.write_lock().deposit(agent_id, amount)
+    }
+
+    /// Withdraw from the treasury
+    pub fn withdraw(&self, agent_id: &str, amount: u64) -> Result<u64, SyndicateError> {
+        self.write_lock().withdraw(agent_id, amount)
+    }
+
+    /// Shared lock, recovering from poisoning
+    ///
+    /// Syndicate methods validate before mutating, so a panic in another
+    /// holder doesn't leave the state half-updated.
+    fn read_lock(&self) -> RwLockReadGuard<'_, Syndicate> {
+        self.inner.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Exclusive lock, recovering from poisoning
+    fn write_lock(&self) -> RwLockWriteGuard<'_, Syndicate> {
+        self.inner.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<Syndicate> for SyndicateHandle {
+    fn from(syndicate: Syndicate) -> Self {
+        Self::new(syndicate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_concurrent_votes() {
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            max_members: 200,
+            ..Default::default()
+        };
+        let handle = SyndicateHandle::new(Syndicate::new(config).unwrap());
+        handle.add_founder("founder".into()).unwrap();
+        for i in 0..64 {
+            handle.add_member(format!("agent-{}", i), 300 + i).unwrap();
+        }
+        let id = handle.create_proposal("founder".into(), ProposalType::Custom {
+            title: "Load".into(),
+            description: String::new(),
+        }).unwrap().id;
+
+        let workers: Vec<_> = (0..8)
+            .map(|worker| {
+                let handle = handle.clone();
+                let id = id.clone();
+                thread::spawn(move || {
+                    for i in (worker..64).step_by(8) {
+                        let choice = if i % 2 == 0 { VoteChoice::For } else { VoteChoice::Against };
+                        handle.vote(&id, &format!("agent-{}", i), choice).unwrap();
+                        // Readers interleave with writers
+                        assert!(handle.get_proposal(&id).is_some());
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        let proposal = handle.get_proposal(&id).unwrap();
+        let expected_for: u64 = (0..64).step_by(2).map(|i| 300 + i).sum();
+        let expected_against: u64 = (1..64).step_by(2).map(|i| 300 + i).sum();
+        assert_eq!(proposal.votes.len(), 64);
+        assert_eq!(proposal.votes_for, expected_for);
+        assert_eq!(proposal.votes_against, expected_against);
+    }
+}
@@ -0,0 +1,334 @@
+//! Governance History
+//!
+//! Flat, line-oriented record of a syndicate's proposals and membership
+//! changes for offline analysis.
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::syndicate::{
+    MemberRole, Proposal, ProposalKind, ProposalStatus, ProposalType, SyndicateError,
+    SyndicateEvent, SyndicateEventRecord, SyndicateMember, VoteRecord,
+};
+
+/// One line of an exported history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HistoryEntry {
+    /// Member admitted
+    MemberJoined {
+        timestamp: u64,
+        agent_id: String,
+        /// Role held on joining
+        role: MemberRole,
+        reputation: u32,
+    },
+    /// Member role changed
+    RoleChanged {
+        timestamp: u64,
+        agent_id: String,
+        from: MemberRole,
+        to: MemberRole,
+    },
+    /// Proposal and its outcome so far
+    Proposal(ProposalHistory),
+}
+
+impl HistoryEntry {
+    /// When the entry happened
+    pub fn timestamp(&self) -> u64 {
+        match self {
+            Self::MemberJoined { timestamp, .. } | Self::RoleChanged { timestamp, .. } => *timestamp,
+            Self::Proposal(p) => p.created_at,
+        }
+    }
+
+    /// Agent or proposal the entry is about
+    pub fn id(&self) -> &str {
+        match self {
+            Self::MemberJoined { agent_id, .. } | Self::RoleChanged { agent_id, .. } => agent_id,
+            Self::Proposal(p) => &p.id,
+        }
+    }
+
+    /// Tiebreak so a join sorts before role changes at the same instant
+    fn rank(&self) -> u8 {
+        match self {
+            Self::MemberJoined { .. } => 0,
+            Self::RoleChanged { .. } => 1,
+            Self::Proposal(_) => 2,
+        }
+    }
+}
+
+/// Exported view of a proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalHistory {
+    pub id: String,
+    pub proposer: String,
+    pub kind: ProposalKind,
+    pub proposal_type: ProposalType,
+    pub created_at: u64,
+    pub deadline: u64,
+    pub status: ProposalStatus,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub votes_abstain: u64,
+    /// Individual votes, oldest first
+    pub votes: Vec<VoteRecord>,
+    pub execution_result: Option<String>,
+}
+
+impl From<&Proposal> for ProposalHistory {
+    fn from(proposal: &Proposal) -> Self {
+        let mut votes = proposal.votes.clone();
+        votes.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then_with(|| a.voter.cmp(&b.voter)));
+        Self {
+            id: proposal.id.clone(),
+            proposer: proposal.proposer.clone(),
+            kind: proposal.proposal_type.kind(),
+            proposal_type: proposal.proposal_type.clone(),
+            created_at: proposal.created_at,
+            deadline: proposal.deadline,
+            status: proposal.status,
+            votes_for: proposal.votes_for,
+            votes_against: proposal.votes_against,
+            votes_abstain: proposal.votes_abstain,
+            votes,
+            execution_result: proposal.execution_result.clone(),
+        }
+    }
+}
+
+/// Build the ordered entries for a syndicate's state
+///
+/// Joins come from member records; role changes from the retained audit
+/// log. A member's joining role is the `from` of their earliest logged
+/// role change, or their current role if none was logged.
+pub(crate) fn collect<'a>(
+    members: impl Iterator<Item = &'a SyndicateMember>,
+    proposals: impl Iterator<Item = &'a Proposal>,
+    events: &VecDeque<SyndicateEventRecord>,
+) -> Vec<HistoryEntry> {
+    let mut entries: Vec<HistoryEntry> = events.iter()
+        .filter_map(|record| match &record.event {
+            SyndicateEvent::RoleChanged { agent_id, from, to } => Some(HistoryEntry::RoleChanged {
+                timestamp: record.timestamp,
+                agent_id: agent_id.clone(),
+                from: *from,
+                to: *to,
+            }),
+            _ => None,
+        })
+        .collect();
+
+    let mut initial_roles: HashMap<&str, MemberRole> = HashMap::new();
+    for entry in &entries {
+        if let HistoryEntry::RoleChanged { agent_id, from, .. } = entry {
+            initial_roles.entry(agent_id).or_insert(*from);
+        }
+    }
+    let joins: Vec<HistoryEntry> = members
+        .map(|m| HistoryEntry::MemberJoined {
+            timestamp: m.joined_at,
+            agent_id: m.agent_id.clone(),
+            role: initial_roles.get(m.agent_id.as_str()).copied().unwrap_or(m.role),
+            reputation: m.reputation,
+        })
+        .collect();
+    entries.extend(joins);
+    entries.extend(proposals.map(|p| HistoryEntry::Proposal(p.into())));
+
+    // Stable, so same-instant role changes keep their log order
+    entries.sort_by(|a, b| {
+        a.timestamp().cmp(&b.timestamp())
+            .then_with(|| a.id().cmp(b.id()))
+            .then_with(|| a.rank().cmp(&b.rank()))
+    });
+    entries
+}
+
+/// Write entries as JSON Lines
+pub(crate) fn write_entries<W: Write>(mut writer: W, entries: &[HistoryEntry]) -> Result<(), SyndicateError> {
+    for entry in entries {
+        serde_json::to_writer(&mut writer, entry)
+            .map_err(|e| SyndicateError::HistoryWrite(e.to_string()))?;
+        writer.write_all(b"\n")
+            .map_err(|e| SyndicateError::HistoryWrite(e.to_string()))?;
+    }
+    writer.flush().map_err(|e| SyndicateError::HistoryWrite(e.to_string()))
+}
+
+/// Read-only state rebuilt from an exported history
+#[derive(Debug, Clone, Default)]
+pub struct GovernanceHistory {
+    /// Entries in file order
+    entries: Vec<HistoryEntry>,
+    /// Latest entry per proposal, by ID
+    proposals: HashMap<String, ProposalHistory>,
+    /// Role each member held as of the last entry
+    roles: HashMap<String, MemberRole>,
+}
+
+impl GovernanceHistory {
+    /// Parse JSON Lines, skipping blank lines
+    ///
+    /// Appended exports may repeat a proposal; the last occurrence wins.
+    pub fn read<R: BufRead>(reader: R) -> Result<Self, SyndicateError> {
+        let mut history = Self::default();
+        for (index, line) in reader.lines().enumerate() {
+            let malformed = |reason: String| SyndicateError::MalformedHistory { line: index + 1, reason };
+            let line = line.map_err(|e| malformed(e.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: HistoryEntry = serde_json::from_str(&line).map_err(|e| malformed(e.to_string()))?;
+            match &entry {
+                HistoryEntry::MemberJoined { agent_id, role, .. } => {
+                    history.roles.entry(agent_id.clone()).or_insert(*role);
+                }
+                HistoryEntry::RoleChanged { agent_id, to, .. } => {
+                    history.roles.insert(agent_id.clone(), *to);
+                }
+                HistoryEntry::Proposal(proposal) => {
+                    history.proposals.insert(proposal.id.clone(), proposal.clone());
+                }
+            }
+            history.entries.push(entry);
+        }
+        Ok(history)
+    }
+
+    /// All entries in file order
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Get proposal by ID
+    pub fn get_proposal(&self, proposal_id: &str) -> Option<&ProposalHistory> {
+        self.proposals.get(proposal_id)
+    }
+
+    /// Proposals ordered by creation time then ID
+    pub fn proposals(&self) -> Vec<&ProposalHistory> {
+        let mut proposals: Vec<&ProposalHistory> = self.proposals.values().collect();
+        proposals.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.id.cmp(&b.id)));
+        proposals
+    }
+
+    /// Last known role of a member
+    pub fn role(&self, agent_id: &str) -> Option<MemberRole> {
+        self.roles.get(agent_id).copied()
+    }
+
+    /// Number of members that ever joined
+    pub fn member_count(&self) -> usize {
+        self.roles.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::syndicate::{Syndicate, SyndicateConfig, VoteChoice};
+
+    fn syndicate(clock: &MockClock) -> Syndicate {
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            proposal_cooldown_secs: 0,
+            max_active_proposals_per_member: None,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap().with_clock(clock.clone());
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate
+    }
+
+    fn custom(title: &str) -> ProposalType {
+        ProposalType::Custom { title: title.into(), description: String::new() }
+    }
+
+    fn export(syndicate: &Syndicate) -> Vec<u8> {
+        let mut out = Vec::new();
+        syndicate.export_history(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let clock = MockClock::new(1_000);
+        let mut syndicate = syndicate(&clock);
+        clock.advance(10);
+        syndicate.add_member("alice".into(), 500).unwrap();
+        syndicate.add_member("bob".into(), 400).unwrap();
+        clock.advance(10);
+        syndicate.set_role("founder", "alice", MemberRole::Admin).unwrap();
+        let id = syndicate.create_proposal("alice".into(), custom("Ship it")).unwrap().id.clone();
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+        clock.advance(5);
+        syndicate.vote(&id, "bob", VoteChoice::Against).unwrap();
+
+        let out = export(&syndicate);
+        // Identical state exports identically
+        assert_eq!(out, export(&syndicate));
+
+        let history = Syndicate::import_history(out.as_slice()).unwrap();
+        let kinds: Vec<(&str, u64)> = history.entries().iter().map(|e| (e.id(), e.timestamp())).collect();
+        assert_eq!(kinds, [("founder", 1_000), ("alice", 1_010), ("bob", 1_010), ("alice", 1_020), (id.as_str(), 1_020)]);
+        assert!(matches!(
+            &history.entries()[1],
+            HistoryEntry::MemberJoined { role: MemberRole::Member, reputation: 500, .. }
+        ));
+        assert_eq!(history.role("alice"), Some(MemberRole::Admin));
+        assert_eq!(history.member_count(), 3);
+
+        let original = syndicate.get_proposal(&id).unwrap();
+        let proposal = history.get_proposal(&id).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Active);
+        assert_eq!((proposal.votes_for, proposal.votes_against), (original.votes_for, original.votes_against));
+        let voters: Vec<&str> = proposal.votes.iter().map(|v| v.voter.as_str()).collect();
+        assert_eq!(voters, ["founder", "bob"]);
+
+        // Appending a later export supersedes the earlier proposal state
+        clock.set(syndicate.get_proposal(&id).unwrap().deadline + 1);
+        syndicate.finalize_proposal(&id).unwrap();
+        let mut appended = out.clone();
+        appended.extend(export(&syndicate));
+        let history = Syndicate::import_history(appended.as_slice()).unwrap();
+        assert_eq!(history.get_proposal(&id).unwrap().status, ProposalStatus::Passed);
+
+        assert!(matches!(
+            Syndicate::import_history(&b"{\"type\":\"member_joined\"}\n"[..]),
+            Err(SyndicateError::MalformedHistory { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_large_history_export() {
+        let clock = MockClock::new(1_000);
+        let mut syndicate = syndicate(&clock);
+        syndicate.add_member("alice".into(), 500).unwrap();
+        for i in 0..1_000 {
+            // Several proposals share each timestamp
+            if i % 3 == 0 {
+                clock.advance(1);
+            }
+            let id = syndicate.create_proposal("founder".into(), custom(&format!("P{}", i))).unwrap().id.clone();
+            syndicate.vote(&id, "alice", VoteChoice::For).unwrap();
+        }
+
+        let out = export(&syndicate);
+        let lines: Vec<&[u8]> = out.split(|b| *b == b'\n').filter(|l| !l.is_empty()).collect();
+        assert_eq!(lines.len(), 1_002);
+        assert!(out.ends_with(b"\n"));
+
+        let history = Syndicate::import_history(out.as_slice()).unwrap();
+        assert_eq!(history.proposals().len(), 1_000);
+        let keys: Vec<(u64, &str)> = history.entries().iter().map(|e| (e.timestamp(), e.id())).collect();
+        assert!(keys.windows(2).all(|w| w[0] <= w[1]));
+        assert!(history.proposals().iter().all(|p| p.votes_for > 0 && p.votes.len() == 1));
+    }
+}
@@ -3,13 +3,50 @@
 //! Provides integration with Psy Protocol for agent syndicate management,
 //! reputation systems, and decentralized coordination.
 
+mod attestation_sync;
+mod clock;
+mod directory;
+mod handle;
+mod history;
+mod manager;
 mod registry;
+mod registry_store;
 mod syndicate;
 mod reputation;
+mod reputation_proof;
+mod shared_tracker;
 
-pub use registry::{AgentRegistry, AgentRegistration, RegistrationStatus};
-pub use syndicate::{Syndicate, SyndicateMember, SyndicateConfig, ProposalType, Proposal};
-pub use reputation::{ReputationTracker, ReputationLevel, ReputationEvent};
+pub use attestation_sync::{sync_attestation_reputation, AttestationSyncReport};
+pub use clock::{Clock, MockClock, SystemClock};
+pub use directory::{DirectoryError, SyndicateDirectory};
+pub use handle::SyndicateHandle;
+pub use history::{GovernanceHistory, HistoryEntry, ProposalHistory};
+pub use manager::{ManagerError, SyndicateManager};
+pub use registry::{
+    AgentRegistry, AgentRegistration, AttestationVerifier, RegistrationStatus, RegistryError, TdxQuoteVerifier,
+};
+pub use registry_store::{JsonFileStore, RegistryStore};
+pub use syndicate::{
+    ActionSignature, BanRecord, ConfigChange, ContributionReport, ConfigResolution, DuplicateMemberResolution, EvidenceItem, Invitation, Syndicate, SyndicateVisibility, SyndicateMember, SyndicateConfig, SyndicateError, SyndicateInvite, SyndicateSnapshot,
+    MemberRole, MergePolicy, MergeReport, PendingMembership, RemovedMember, ProposalType, Proposal, ProposalStatus,
+    ProposalFilter, ProposalKind, ProposalPage, ProposalSort,
+    SignedVote, SyndicateEvent, SyndicateEventRecord, SyndicatePerformanceSummary, Treasury, VetoRecord, VoteChoice, VoteDelegation, VoteRecord,
+    VotingModel, VotingPowerFormula,
+};
+pub use reputation::{
+    ReputationError, ReputationEvent, ReputationEventKind, ReputationLevel, ReputationRecord, ReputationTracker,
+    GLOBAL_CONTEXT,
+    ContextAggregation, DecayPolicy, EventValidator, ProbationPolicy, RateLimit, RecencyWeighting, ReputationWeights,
+    ScoreBounds, TrackerConfig,
+    Dimension, DimensionWeights, Subscores,
+    AppliedEvent, ApplyOutcome, BatchError, BatchResult, LevelChange, MergeStrategy, RemovedAgent,
+    Endorsement, RecoveryPlan, ReputationAttestation, ScoreCheckpoint,
+    AgentPage, AgentQuery, AgentSummary, AgentTrend, LeaderboardEntry, ReputationReport, SortBy, SortOrder,
+    HistogramBucket, ReputationDistribution, TrackerMetrics, TrendBucket, Windowed,
+    CsvImportMode, CsvImportReport, CsvRowError,
+};
+pub use reputation_proof::{ReputationProof, ReputationProofVerifier};
+pub use shared_tracker::SharedReputationTracker;
 
 /// Re-export sdkey-manager types
 pub use sdkey_manager::{AgentSDKey, AgentPermissions, DelegationChain, PermissionLevel};
@@ -21,7 +58,7 @@ pub use zk_proofs::{PerformanceMetrics, PerformanceProof, ProofVerifier};
 pub mod prelude {
     pub use crate::{
         AgentRegistry, AgentRegistration, RegistrationStatus,
-        Syndicate, SyndicateMember, SyndicateConfig, ProposalType, Proposal,
+        Syndicate, SyndicateMember, SyndicateConfig, ProposalType, Proposal, ProposalStatus,
         ReputationTracker, ReputationLevel, ReputationEvent,
         AgentSDKey, AgentPermissions, DelegationChain, PermissionLevel,
         PerformanceMetrics, PerformanceProof, ProofVerifier,
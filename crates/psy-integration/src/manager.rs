@@ -0,0 +1,184 @@
+//! Syndicate Manager
+//!
+//! Owns every syndicate an application hosts and runs maintenance across
+//! all of them.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::clock::{Clock, SystemClock};
+use crate::syndicate::{ProposalStatus, Syndicate, SyndicateConfig, SyndicateError};
+
+/// Collection of hosted syndicates keyed by ID
+pub struct SyndicateManager {
+    /// Syndicates by ID
+    syndicates: HashMap<String, Syndicate>,
+    /// Time source shared with syndicates created here
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for SyndicateManager {
+    fn default() -> Self {
+        Self {
+            syndicates: HashMap::new(),
+            clock: Arc::new(SystemClock),
+        }
+    }
+}
+
+impl SyndicateManager {
+    /// Create empty manager
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use a different time source for sweeps and new syndicates
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Create a syndicate
+    pub fn create(&mut self, config: SyndicateConfig) -> Result<&mut Syndicate, ManagerError> {
+        if self.syndicates.contains_key(&config.id) {
+            return Err(ManagerError::DuplicateSyndicate(config.id));
+        }
+        let syndicate = Syndicate::new(config)?.with_clock(self.clock.clone());
+        Ok(self.insert_unchecked(syndicate))
+    }
+
+    /// Take ownership of an existing syndicate, e.g. one restored from a snapshot
+    ///
+    /// The syndicate keeps its own clock.
+    pub fn insert(&mut self, syndicate: Syndicate) -> Result<&mut Syndicate, ManagerError> {
+        if self.syndicates.contains_key(syndicate.id()) {
+            return Err(ManagerError::DuplicateSyndicate(syndicate.id().to_string()));
+        }
+        Ok(self.insert_unchecked(syndicate))
+    }
+
+    /// Get syndicate by ID
+    pub fn get(&self, syndicate_id: &str) -> Option<&Syndicate> {
+        self.syndicates.get(syndicate_id)
+    }
+
+    /// Get mutable syndicate by ID
+    pub fn get_mut(&mut self, syndicate_id: &str) -> Option<&mut Syndicate> {
+        self.syndicates.get_mut(syndicate_id)
+    }
+
+    /// Remove a syndicate, handing it back to the caller
+    pub fn remove(&mut self, syndicate_id: &str) -> Option<Syndicate> {
+        self.syndicates.remove(syndicate_id)
+    }
+
+    /// Get total syndicate count
+    pub fn count(&self) -> usize {
+        self.syndicates.len()
+    }
+
+    /// Syndicates an agent currently belongs to, ordered by ID
+    pub fn syndicates_for_agent(&self, agent_id: &str) -> Vec<&Syndicate> {
+        let mut found: Vec<&Syndicate> = self.syndicates
+            .values()
+            .filter(|s| s.get_member(agent_id).is_some_and(|m| m.left_at.is_none()))
+            .collect();
+        found.sort_by(|a, b| a.id().cmp(b.id()));
+        found
+    }
+
+    /// Finalize overdue proposals in every syndicate
+    ///
+    /// Returns `(syndicate ID, proposal ID, status)` ordered by syndicate
+    /// ID, then as each syndicate's own sweep reports them.
+    pub fn sweep_expired(&mut self) -> Vec<(String, String, ProposalStatus)> {
+        let now = self.clock.now();
+        let mut ids: Vec<String> = self.syndicates.keys().cloned().collect();
+        ids.sort();
+
+        ids.into_iter()
+            .flat_map(|id| {
+                let swept = self.syndicates.get_mut(&id).unwrap().sweep_expired(now);
+                swept.into_iter().map(move |(proposal_id, status)| (id.clone(), proposal_id, status))
+            })
+            .collect()
+    }
+
+    /// Store a syndicate whose ID is known to be free
+    fn insert_unchecked(&mut self, syndicate: Syndicate) -> &mut Syndicate {
+        self.syndicates.entry(syndicate.id().to_string()).or_insert(syndicate)
+    }
+}
+
+/// Manager errors
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ManagerError {
+    #[error("Syndicate already exists: {0}")]
+    DuplicateSyndicate(String),
+
+    #[error(transparent)]
+    Syndicate(#[from] SyndicateError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::syndicate::{ProposalType, VoteChoice};
+
+    fn config(id: &str) -> SyndicateConfig {
+        SyndicateConfig {
+            id: id.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_manager() {
+        let clock = MockClock::new(1_000);
+        let mut manager = SyndicateManager::new().with_clock(clock.clone());
+        for id in ["beta", "alpha"] {
+            let syndicate = manager.create(config(id)).unwrap();
+            syndicate.add_founder("founder".into()).unwrap();
+        }
+        assert!(matches!(manager.create(config("alpha")), Err(ManagerError::DuplicateSyndicate(id)) if id == "alpha"));
+        assert!(matches!(
+            manager.insert(Syndicate::new(config("beta")).unwrap()),
+            Err(ManagerError::DuplicateSyndicate(_))
+        ));
+        assert_eq!(manager.count(), 2);
+
+        manager.get_mut("beta").unwrap().add_member("alice".into(), 500).unwrap();
+        let ids = |found: Vec<&Syndicate>| found.iter().map(|s| s.id().to_string()).collect::<Vec<_>>();
+        assert_eq!(ids(manager.syndicates_for_agent("founder")), ["alpha", "beta"]);
+        assert_eq!(ids(manager.syndicates_for_agent("alice")), ["beta"]);
+        assert!(manager.syndicates_for_agent("mallory").is_empty());
+
+        let mut open = |id: &str| {
+            let syndicate = manager.get_mut(id).unwrap();
+            let proposal_id = syndicate.create_proposal("founder".into(), ProposalType::Custom {
+                title: "T".into(),
+                description: String::new(),
+            }).unwrap().id.clone();
+            // Beta gets no votes and fails quorum
+            if id == "alpha" {
+                syndicate.vote(&proposal_id, "founder", VoteChoice::For).unwrap();
+            }
+            let deadline = syndicate.get_proposal(&proposal_id).unwrap().deadline;
+            (proposal_id, deadline)
+        };
+        let (beta_proposal, deadline) = open("beta");
+        let (alpha_proposal, _) = open("alpha");
+
+        assert!(manager.sweep_expired().is_empty());
+        clock.set(deadline + 1);
+        assert_eq!(manager.sweep_expired(), [
+            ("alpha".to_string(), alpha_proposal, ProposalStatus::Passed),
+            ("beta".to_string(), beta_proposal, ProposalStatus::Rejected),
+        ]);
+        assert!(manager.sweep_expired().is_empty());
+
+        assert!(manager.remove("alpha").is_some());
+        assert!(manager.get("alpha").is_none());
+    }
+}
@@ -4,8 +4,21 @@
 
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
-use sdkey_manager::{AgentSDKey, AgentPermissions};
+use sdkey_manager::{AgentSDKey, AgentPermissions, SDKeyError, SDKeyId};
+use crate::clock::{Clock, SystemClock};
+use crate::registry_store::RegistryStore;
+
+/// How long an attestation stays fresh
+const ATTESTATION_TTL_SECS: u64 = 86_400;
+
+/// Domain tag at the start of registration signing messages
+const REGISTRATION_TAG: &[u8] = b"agent-registration:v2";
+
+/// How far a signed registration's `registered_at` can be from the
+/// registry's clock
+const REGISTRATION_WINDOW_SECS: u64 = 600;
 
 /// Agent registration status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -51,6 +64,9 @@ pub struct AgentRegistration {
     pub permissions: AgentPermissions,
     /// Metadata (JSON)
     pub metadata: HashMap<String, String>,
+    /// Whether the agent was suspended by `sweep_stale_attestations`
+    #[serde(default)]
+    pub suspended_for_staleness: bool,
 }
 
 impl AgentRegistration {
@@ -76,6 +92,7 @@ impl AgentRegistration {
             owner_address: None,
             permissions: sdkey.permissions().clone(),
             metadata: HashMap::new(),
+            suspended_for_staleness: false,
         }
     }
 
@@ -114,13 +131,46 @@ impl AgentRegistration {
         self
     }
 
+    /// Canonical encoding of the fields the agent signs
+    ///
+    /// Covers the agent ID, name, public key, owner, permissions,
+    /// capabilities and registration time. The description, metadata and
+    /// contract address aren't covered.
+    pub fn signing_message(&self) -> Vec<u8> {
+        let mut message = REGISTRATION_TAG.to_vec();
+        let owner = self.owner_address.as_deref().unwrap_or_default();
+        let permissions = serde_json::to_vec(&self.permissions).unwrap_or_default();
+        for field in [self.agent_id.as_bytes(), self.name.as_bytes(), &self.public_key, owner.as_bytes(), &permissions] {
+            message.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            message.extend_from_slice(field);
+        }
+        message.push(self.owner_address.is_some() as u8);
+        message.extend_from_slice(&(self.capabilities.len() as u32).to_le_bytes());
+        for capability in &self.capabilities {
+            message.extend_from_slice(&(capability.len() as u32).to_le_bytes());
+            message.extend_from_slice(capability.as_bytes());
+        }
+        message.extend_from_slice(&self.registered_at.to_le_bytes());
+        message
+    }
+
+    /// Sign the registration with the agent's key, for `register_signed`
+    pub fn sign(&self, sdkey: &AgentSDKey) -> Result<[u8; 64], SDKeyError> {
+        sdkey.sign(&self.signing_message())
+    }
+
     /// Check if attestation is fresh (within 24 hours)
     pub fn is_attestation_fresh(&self) -> bool {
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
             .as_secs();
-        now - self.last_attestation < 86_400
+        self.attestation_age_at(now) < ATTESTATION_TTL_SECS
+    }
+
+    /// Seconds since the last attestation refresh, as of `now`
+    pub fn attestation_age_at(&self, now: u64) -> u64 {
+        now.saturating_sub(self.last_attestation)
     }
 
     /// Check if agent is active
@@ -129,6 +179,74 @@ impl AgentRegistration {
     }
 }
 
+/// Checks a registration's TEE attestation
+///
+/// Swap in an implementation doing full DCAP verification with
+/// `AgentRegistry::with_verifier`.
+pub trait AttestationVerifier: Send + Sync {
+    /// Verify the registration's quote and RTMRs, or say why they fail
+    fn verify(&self, registration: &AgentRegistration) -> Result<(), String>;
+}
+
+/// TDX quote header length
+const QUOTE_HEADER_LEN: usize = 48;
+/// TD report body length
+const TD_REPORT_LEN: usize = 584;
+/// Offset of RTMR0 in the quote
+const RTMR_OFFSET: usize = QUOTE_HEADER_LEN + 328;
+/// RTMR length
+const RTMR_LEN: usize = 48;
+/// Offset of the report data in the quote
+const REPORT_DATA_OFFSET: usize = QUOTE_HEADER_LEN + 520;
+/// Supported quote version
+const QUOTE_VERSION: u16 = 4;
+/// TEE type of TDX quotes
+const TDX_TEE_TYPE: u32 = 0x81;
+
+/// Structural checks of a TDX v4 quote
+///
+/// Checks the header, that the RTMRs are 48-byte hex matching the
+/// quote's, and that the report data starts with the SHA-256 of the
+/// agent's public key. The quote's signature is NOT verified.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TdxQuoteVerifier;
+
+impl AttestationVerifier for TdxQuoteVerifier {
+    fn verify(&self, registration: &AgentRegistration) -> Result<(), String> {
+        let quote = registration.attestation_quote.as_deref().ok_or("missing quote")?;
+        let rtmrs = registration.rtmr_values.as_ref().ok_or("missing RTMR values")?;
+
+        if quote.len() < QUOTE_HEADER_LEN + TD_REPORT_LEN {
+            return Err(format!("quote is {} bytes, expected at least {}", quote.len(), QUOTE_HEADER_LEN + TD_REPORT_LEN));
+        }
+        let version = u16::from_le_bytes([quote[0], quote[1]]);
+        if version != QUOTE_VERSION {
+            return Err(format!("unsupported quote version {}", version));
+        }
+        let tee_type = u32::from_le_bytes([quote[4], quote[5], quote[6], quote[7]]);
+        if tee_type != TDX_TEE_TYPE {
+            return Err(format!("unsupported TEE type {:#x}", tee_type));
+        }
+
+        for (index, rtmr) in rtmrs.iter().enumerate() {
+            let bytes = hex::decode(rtmr)
+                .ok()
+                .filter(|b| b.len() == RTMR_LEN)
+                .ok_or_else(|| format!("RTMR{} is not {} hex characters", index, RTMR_LEN * 2))?;
+            let start = RTMR_OFFSET + index * RTMR_LEN;
+            if quote[start..start + RTMR_LEN] != bytes[..] {
+                return Err(format!("RTMR{} does not match the quote", index));
+            }
+        }
+
+        let key_hash = Sha256::digest(&registration.public_key);
+        if quote[REPORT_DATA_OFFSET..REPORT_DATA_OFFSET + key_hash.len()] != key_hash[..] {
+            return Err("report data is not bound to the agent's public key".into());
+        }
+        Ok(())
+    }
+}
+
 /// Agent Registry for managing registrations
 pub struct AgentRegistry {
     /// Registered agents by ID
@@ -137,8 +255,18 @@ pub struct AgentRegistry {
     by_public_key: HashMap<Vec<u8>, String>,
     /// Index by owner address
     by_owner: HashMap<String, Vec<String>>,
+    /// Index by metadata key, then value
+    by_metadata: HashMap<String, HashMap<String, Vec<String>>>,
     /// Registry contract address
     contract_address: Option<String>,
+    /// Attestation checks
+    verifier: Box<dyn AttestationVerifier>,
+    /// Time source
+    clock: Box<dyn Clock>,
+    /// Storage written through to, if any
+    store: Option<Box<dyn RegistryStore>>,
+    /// Whether `register` accepts registrations without a signature
+    allow_unsigned: bool,
 }
 
 impl AgentRegistry {
@@ -148,8 +276,24 @@ impl AgentRegistry {
             agents: HashMap::new(),
             by_public_key: HashMap::new(),
             by_owner: HashMap::new(),
+            by_metadata: HashMap::new(),
             contract_address: None,
+            verifier: Box::new(TdxQuoteVerifier),
+            clock: Box::new(SystemClock),
+            store: None,
+            allow_unsigned: true,
+        }
+    }
+
+    /// Rebuild a registry from the registrations in `store`, writing
+    /// further changes through to it
+    pub fn load(store: impl RegistryStore + 'static) -> Result<Self, RegistryError> {
+        let mut registry = Self::new();
+        for registration in store.scan()? {
+            registry.insert(registration);
         }
+        registry.store = Some(Box::new(store));
+        Ok(registry)
     }
 
     /// Create registry with contract address
@@ -160,8 +304,78 @@ impl AgentRegistry {
         }
     }
 
+    /// Use a different attestation verifier
+    pub fn with_verifier(mut self, verifier: impl AttestationVerifier + 'static) -> Self {
+        self.verifier = Box::new(verifier);
+        self
+    }
+
+    /// Use a different time source (e.g. `MockClock` in tests)
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Write every change through to `store` before applying it
+    ///
+    /// Agents already registered aren't written; use `load` to start from
+    /// the store's contents.
+    pub fn with_store(mut self, store: impl RegistryStore + 'static) -> Self {
+        self.store = Some(Box::new(store));
+        self
+    }
+
+    /// Set whether `register` is allowed; when it isn't, agents must
+    /// prove they hold their key through `register_signed`
+    pub fn with_allow_unsigned(mut self, allow: bool) -> Self {
+        self.allow_unsigned = allow;
+        self
+    }
+
+    /// Get registry contract address
+    pub fn contract_address(&self) -> Option<&str> {
+        self.contract_address.as_deref()
+    }
+
     /// Register a new agent
-    pub fn register(&mut self, mut registration: AgentRegistration) -> Result<String, RegistryError> {
+    ///
+    /// An agent with an attestation is active once it's verified; one
+    /// failing verification isn't registered. Any other agent starts
+    /// pending, whatever status it claims. Like every change, it's
+    /// written to the store first and only applied if that succeeds.
+    /// Denied if the registry doesn't allow unsigned registrations.
+    pub fn register(&mut self, registration: AgentRegistration) -> Result<String, RegistryError> {
+        if !self.allow_unsigned {
+            return Err(RegistryError::PermissionDenied);
+        }
+        self.add(registration)
+    }
+
+    /// Register an agent that signed its registration with its own key
+    ///
+    /// The signature must verify against the registration's public key,
+    /// the agent ID must be the one derived from that key, and
+    /// `registered_at` must be within ten minutes of the registry's clock,
+    /// so old signed registrations can't be replayed.
+    pub fn register_signed(&mut self, registration: AgentRegistration, signature: &[u8; 64]) -> Result<String, RegistryError> {
+        let now = self.clock.now();
+        if registration.registered_at.abs_diff(now) > REGISTRATION_WINDOW_SECS {
+            return Err(RegistryError::RegistrationOutOfWindow { registered_at: registration.registered_at, now });
+        }
+        let public_key: [u8; 32] = registration.public_key.as_slice()
+            .try_into()
+            .map_err(|_| RegistryError::InvalidSignature)?;
+        let derived = SDKeyId::from_bytes(Sha256::digest(public_key).into()).to_hex();
+        if registration.agent_id != derived {
+            return Err(RegistryError::AgentIdMismatch { agent_id: registration.agent_id, derived });
+        }
+        sdkey_manager::verify_signature(&public_key, &registration.signing_message(), signature)
+            .map_err(|_| RegistryError::InvalidSignature)?;
+        self.add(registration)
+    }
+
+    /// Validate, store and index a new registration
+    fn add(&mut self, mut registration: AgentRegistration) -> Result<String, RegistryError> {
         // Check for duplicate ID
         if self.agents.contains_key(&registration.agent_id) {
             return Err(RegistryError::AlreadyRegistered(registration.agent_id));
@@ -174,11 +388,29 @@ impl AgentRegistry {
 
         let agent_id = registration.agent_id.clone();
 
+        // Status is the registry's to grant, not the caller's
+        registration.status = RegistrationStatus::Pending;
+        registration.suspended_for_staleness = false;
+
         // Validate attestation if present
         if registration.attestation_quote.is_some() {
+            self.verifier.verify(&registration).map_err(RegistryError::InvalidAttestation)?;
             registration.status = RegistrationStatus::Active;
+            registration.last_attestation = self.clock.now();
         }
 
+        if let Some(store) = &mut self.store {
+            store.put_registration(&registration)?;
+        }
+        self.insert(registration);
+
+        Ok(agent_id)
+    }
+
+    /// Store a registration in memory and index it
+    fn insert(&mut self, registration: AgentRegistration) {
+        let agent_id = registration.agent_id.clone();
+
         // Index by public key
         self.by_public_key.insert(
             registration.public_key.clone(),
@@ -189,14 +421,64 @@ impl AgentRegistry {
         if let Some(ref owner) = registration.owner_address {
             self.by_owner
                 .entry(owner.clone())
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(agent_id.clone());
         }
 
+        // Index by metadata
+        for (key, value) in &registration.metadata {
+            Self::index_metadata(&mut self.by_metadata, &agent_id, key, value);
+        }
+
         // Store registration
-        self.agents.insert(agent_id.clone(), registration);
+        self.agents.insert(agent_id, registration);
+    }
 
-        Ok(agent_id)
+    /// Write an updated registration through, then replace it in memory
+    ///
+    /// Returns the registration it replaced.
+    fn replace(&mut self, updated: AgentRegistration) -> Result<AgentRegistration, RegistryError> {
+        if let Some(store) = &mut self.store {
+            store.put_registration(&updated)?;
+        }
+        let agent = self.agents.get_mut(&updated.agent_id)
+            .ok_or_else(|| RegistryError::NotFound(updated.agent_id.clone()))?;
+        Ok(std::mem::replace(agent, updated))
+    }
+
+    /// Copy of an agent's registration, to update and `replace`
+    fn current(&self, agent_id: &str) -> Result<AgentRegistration, RegistryError> {
+        self.agents.get(agent_id)
+            .cloned()
+            .ok_or_else(|| RegistryError::NotFound(agent_id.to_string()))
+    }
+
+    /// Remove an agent and its index entries
+    pub fn unregister(&mut self, agent_id: &str) -> Result<AgentRegistration, RegistryError> {
+        if !self.agents.contains_key(agent_id) {
+            return Err(RegistryError::NotFound(agent_id.to_string()));
+        }
+        if let Some(store) = &mut self.store {
+            store.delete(agent_id)?;
+        }
+        let registration = self.agents.remove(agent_id).expect("agent was just checked");
+
+        self.by_public_key.remove(&registration.public_key);
+
+        if let Some(ref owner) = registration.owner_address {
+            if let Some(ids) = self.by_owner.get_mut(owner) {
+                ids.retain(|id| id != agent_id);
+                if ids.is_empty() {
+                    self.by_owner.remove(owner);
+                }
+            }
+        }
+
+        for (key, value) in &registration.metadata {
+            Self::unindex_metadata(&mut self.by_metadata, agent_id, key, value);
+        }
+
+        Ok(registration)
     }
 
     /// Get agent by ID
@@ -225,36 +507,141 @@ impl AgentRegistry {
 
     /// Update agent status
     pub fn update_status(&mut self, agent_id: &str, status: RegistrationStatus) -> Result<(), RegistryError> {
-        let agent = self.agents.get_mut(agent_id)
-            .ok_or_else(|| RegistryError::NotFound(agent_id.to_string()))?;
+        let mut agent = self.current(agent_id)?;
         agent.status = status;
+        agent.suspended_for_staleness = false;
+        self.replace(agent)?;
         Ok(())
     }
 
+    /// Set a metadata entry, returning the previous value
+    pub fn set_metadata(&mut self, agent_id: &str, key: &str, value: &str) -> Result<Option<String>, RegistryError> {
+        let mut agent = self.current(agent_id)?;
+        let previous = agent.metadata.insert(key.to_string(), value.to_string());
+        self.replace(agent)?;
+        if let Some(ref old) = previous {
+            Self::unindex_metadata(&mut self.by_metadata, agent_id, key, old);
+        }
+        Self::index_metadata(&mut self.by_metadata, agent_id, key, value);
+        Ok(previous)
+    }
+
+    /// List active agents whose metadata has `key` set to `value`
+    pub fn find_by_metadata(&self, key: &str, value: &str) -> Vec<&AgentRegistration> {
+        self.find_by_metadata_predicate(key, |v| v == value)
+    }
+
+    /// List active agents whose metadata value for `key` satisfies `f`
+    ///
+    /// `f` is called once per distinct value of the key, not per agent.
+    pub fn find_by_metadata_predicate(&self, key: &str, f: impl Fn(&str) -> bool) -> Vec<&AgentRegistration> {
+        self.by_metadata
+            .get(key)
+            .map(|values| {
+                values.iter()
+                    .filter(|(value, _)| f(value))
+                    .flat_map(|(_, ids)| ids)
+                    .filter_map(|id| self.agents.get(id))
+                    .filter(|a| a.is_active())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Add an agent to the metadata index
+    fn index_metadata(index: &mut HashMap<String, HashMap<String, Vec<String>>>, agent_id: &str, key: &str, value: &str) {
+        index
+            .entry(key.to_string())
+            .or_default()
+            .entry(value.to_string())
+            .or_default()
+            .push(agent_id.to_string());
+    }
+
+    /// Remove an agent from the metadata index, dropping emptied entries
+    fn unindex_metadata(index: &mut HashMap<String, HashMap<String, Vec<String>>>, agent_id: &str, key: &str, value: &str) {
+        let Some(values) = index.get_mut(key) else {
+            return;
+        };
+        if let Some(ids) = values.get_mut(value) {
+            ids.retain(|id| id != agent_id);
+            if ids.is_empty() {
+                values.remove(value);
+            }
+        }
+        if values.is_empty() {
+            index.remove(key);
+        }
+    }
+
+    /// Suspend active agents whose attestation is older than `max_age_secs`
+    ///
+    /// Returns the suspended agent IDs, sorted. Refreshing the attestation
+    /// makes them active again. If the store fails, agents before the one
+    /// it failed on stay suspended.
+    pub fn sweep_stale_attestations(&mut self, max_age_secs: u64) -> Result<Vec<String>, RegistryError> {
+        let now = self.clock.now();
+        let mut stale: Vec<String> = self.agents
+            .values()
+            .filter(|a| a.is_active() && a.attestation_age_at(now) > max_age_secs)
+            .map(|a| a.agent_id.clone())
+            .collect();
+        stale.sort();
+        for agent_id in &stale {
+            let mut agent = self.current(agent_id)?;
+            agent.status = RegistrationStatus::Suspended;
+            agent.suspended_for_staleness = true;
+            self.replace(agent)?;
+        }
+        Ok(stale)
+    }
+
+    /// Active agents that `sweep_stale_attestations(max_age_secs)` will
+    /// suspend within `warning_window_secs`, or would now, oldest
+    /// attestation first
+    pub fn agents_needing_refresh(&self, max_age_secs: u64, warning_window_secs: u64) -> Vec<&AgentRegistration> {
+        let now = self.clock.now();
+        let threshold = max_age_secs.saturating_sub(warning_window_secs);
+        let mut agents: Vec<&AgentRegistration> = self.agents
+            .values()
+            .filter(|a| a.is_active() && a.attestation_age_at(now) > threshold)
+            .collect();
+        agents.sort_by(|a, b| a.last_attestation.cmp(&b.last_attestation).then_with(|| a.agent_id.cmp(&b.agent_id)));
+        agents
+    }
+
     /// Refresh attestation
+    ///
+    /// A quote failing verification leaves the registration as it was.
+    /// Pending agents and ones suspended for a stale attestation become
+    /// active; revoked ones stay revoked.
     pub fn refresh_attestation(
         &mut self,
         agent_id: &str,
         quote: Vec<u8>,
         rtmrs: [String; 4],
     ) -> Result<(), RegistryError> {
-        let agent = self.agents.get_mut(agent_id)
-            .ok_or_else(|| RegistryError::NotFound(agent_id.to_string()))?;
-
+        let mut agent = self.current(agent_id)?;
         agent.attestation_quote = Some(quote);
         agent.rtmr_values = Some(rtmrs);
-        agent.last_attestation = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        self.verifier.verify(&agent).map_err(RegistryError::InvalidAttestation)?;
+        agent.last_attestation = self.clock.now();
 
-        if agent.status == RegistrationStatus::Pending {
+        let stale = agent.status == RegistrationStatus::Suspended && agent.suspended_for_staleness;
+        if agent.status == RegistrationStatus::Pending || stale {
             agent.status = RegistrationStatus::Active;
+            agent.suspended_for_staleness = false;
         }
+        self.replace(agent)?;
 
         Ok(())
     }
 
+    /// List all agents, whatever their status
+    pub fn list_all(&self) -> Vec<&AgentRegistration> {
+        self.agents.values().collect()
+    }
+
     /// List all active agents
     pub fn list_active(&self) -> Vec<&AgentRegistration> {
         self.agents
@@ -300,17 +687,47 @@ pub enum RegistryError {
     #[error("Duplicate public key")]
     DuplicatePublicKey,
 
-    #[error("Invalid attestation")]
-    InvalidAttestation,
+    #[error("Invalid attestation: {0}")]
+    InvalidAttestation(String),
 
     #[error("Permission denied")]
     PermissionDenied,
+
+    #[error("Registry storage failed: {0}")]
+    StorageError(String),
+
+    #[error("Invalid registration signature")]
+    InvalidSignature,
+
+    #[error("Agent ID {agent_id} does not match its public key, expected {derived}")]
+    AgentIdMismatch { agent_id: String, derived: String },
+
+    #[error("Registration signed at {registered_at} is too far from the current time {now}")]
+    RegistrationOutOfWindow { registered_at: u64, now: u64 },
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use sdkey_manager::{AgentSDKey, AgentMetadata, AgentPermissions};
+    use crate::clock::MockClock;
+
+    fn rtmrs() -> [String; 4] {
+        [0u8, 1, 2, 3].map(|i| format!("{:02x}", i).repeat(RTMR_LEN))
+    }
+
+    /// TDX quote for `public_key` with `rtmrs`, unsigned
+    fn tdx_quote(public_key: &[u8], rtmrs: &[String; 4]) -> Vec<u8> {
+        let mut quote = vec![0u8; QUOTE_HEADER_LEN + TD_REPORT_LEN + 64];
+        quote[0..2].copy_from_slice(&QUOTE_VERSION.to_le_bytes());
+        quote[4..8].copy_from_slice(&TDX_TEE_TYPE.to_le_bytes());
+        for (index, rtmr) in rtmrs.iter().enumerate() {
+            let start = RTMR_OFFSET + index * RTMR_LEN;
+            quote[start..start + RTMR_LEN].copy_from_slice(&hex::decode(rtmr).unwrap());
+        }
+        quote[REPORT_DATA_OFFSET..REPORT_DATA_OFFSET + 32].copy_from_slice(&Sha256::digest(public_key));
+        quote
+    }
 
     #[test]
     fn test_agent_registration() {
@@ -350,9 +767,9 @@ mod tests {
         };
         let sdkey1 = AgentSDKey::generate(metadata1, AgentPermissions::default());
         let trader_id = sdkey1.agent_id();
-        let mut reg1 = AgentRegistration::from_sdkey(&sdkey1, "Trader".into(), "".into())
-            .with_capability("trading");
-        reg1.status = RegistrationStatus::Active;
+        let reg1 = AgentRegistration::from_sdkey(&sdkey1, "Trader".into(), "".into())
+            .with_capability("trading")
+            .with_attestation(tdx_quote(&sdkey1.public_key_bytes(), &rtmrs()), rtmrs());
         registry.register(reg1).unwrap();
 
         // Register governance agent
@@ -362,13 +779,243 @@ mod tests {
             ..Default::default()
         };
         let sdkey2 = AgentSDKey::generate(metadata2, AgentPermissions::default());
-        let mut reg2 = AgentRegistration::from_sdkey(&sdkey2, "Governor".into(), "".into())
-            .with_capability("governance");
-        reg2.status = RegistrationStatus::Active;
+        let reg2 = AgentRegistration::from_sdkey(&sdkey2, "Governor".into(), "".into())
+            .with_capability("governance")
+            .with_attestation(tdx_quote(&sdkey2.public_key_bytes(), &rtmrs()), rtmrs());
         registry.register(reg2).unwrap();
 
         let traders = registry.list_by_capability("trading");
         assert_eq!(traders.len(), 1);
         assert_eq!(traders[0].agent_id, trader_id);
     }
+
+    #[test]
+    fn test_register_ignores_claimed_status() {
+        let mut registry = AgentRegistry::new();
+
+        // Claiming to be active without an attestation doesn't make it so
+        let sdkey = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let mut registration = AgentRegistration::from_sdkey(&sdkey, "Unattested".into(), "".into())
+            .with_capability("trading");
+        registration.status = RegistrationStatus::Active;
+        registration.suspended_for_staleness = true;
+        let agent_id = registry.register(registration).unwrap();
+
+        let agent = registry.get(&agent_id).unwrap();
+        assert_eq!(agent.status, RegistrationStatus::Pending);
+        assert!(!agent.suspended_for_staleness);
+        assert!(registry.list_by_capability("trading").is_empty());
+    }
+
+    #[test]
+    fn test_attestation_verification() {
+        let sdkey = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let public_key = sdkey.public_key_bytes();
+        let attested = |quote: Vec<u8>, rtmrs: [String; 4]| {
+            AgentRegistration::from_sdkey(&sdkey, "Agent".into(), "".into()).with_attestation(quote, rtmrs)
+        };
+        let rejected = |registration: AgentRegistration| {
+            match AgentRegistry::new().register(registration) {
+                Err(RegistryError::InvalidAttestation(reason)) => reason,
+                other => panic!("expected invalid attestation, got {:?}", other),
+            }
+        };
+
+        assert!(rejected(attested(vec![0u8], rtmrs())).contains("bytes"));
+        let mut quote = tdx_quote(&public_key, &rtmrs());
+        quote[4] = 0x00;
+        assert!(rejected(attested(quote, rtmrs())).contains("TEE type"));
+
+        let mut bad_rtmrs = rtmrs();
+        bad_rtmrs[2] = "zz".repeat(RTMR_LEN);
+        assert!(rejected(attested(tdx_quote(&public_key, &rtmrs()), bad_rtmrs)).contains("RTMR2"));
+        let mut other_rtmrs = rtmrs();
+        other_rtmrs[1] = "ff".repeat(RTMR_LEN);
+        assert!(rejected(attested(tdx_quote(&public_key, &rtmrs()), other_rtmrs)).contains("match"));
+        assert!(rejected(attested(tdx_quote(b"someone else", &rtmrs()), rtmrs())).contains("public key"));
+
+        let mut registry = AgentRegistry::new();
+        let agent_id = registry.register(attested(tdx_quote(&public_key, &rtmrs()), rtmrs())).unwrap();
+        assert!(registry.get(&agent_id).unwrap().is_active());
+
+        // A bad refresh leaves a pending agent pending
+        let sdkey = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let pending = registry.register(AgentRegistration::from_sdkey(&sdkey, "Pending".into(), "".into())).unwrap();
+        let result = registry.refresh_attestation(&pending, tdx_quote(&public_key, &rtmrs()), rtmrs());
+        assert!(matches!(result, Err(RegistryError::InvalidAttestation(_))));
+        let agent = registry.get(&pending).unwrap();
+        assert_eq!(agent.status, RegistrationStatus::Pending);
+        assert!(agent.attestation_quote.is_none());
+        registry.refresh_attestation(&pending, tdx_quote(&sdkey.public_key_bytes(), &rtmrs()), rtmrs()).unwrap();
+        assert!(registry.get(&pending).unwrap().is_active());
+    }
+
+    #[test]
+    fn test_sweep_stale_attestations() {
+        let clock = MockClock::new(1_000_000);
+        let mut registry = AgentRegistry::new().with_clock(clock.clone());
+        let mut register = |age_secs: u64| {
+            let sdkey = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+            let mut registration = AgentRegistration::from_sdkey(&sdkey, "Agent".into(), "".into())
+                .with_attestation(tdx_quote(&sdkey.public_key_bytes(), &rtmrs()), rtmrs());
+            // The registry's clock decides when the attestation was verified
+            registration.last_attestation = 0;
+            clock.set(1_000_000 - age_secs);
+            (registry.register(registration).unwrap(), sdkey)
+        };
+        let (stale, stale_key) = register(200_000);
+        let (revoked, revoked_key) = register(200_000);
+        let (aging, _) = register(80_000);
+        let (fresh, fresh_key) = register(3_600);
+        clock.set(1_000_000);
+        registry.update_status(&revoked, RegistrationStatus::Revoked).unwrap();
+        assert_eq!(registry.get(&fresh).unwrap().last_attestation, 1_000_000 - 3_600);
+
+        let warned = |registry: &AgentRegistry, max_age_secs: u64| -> Vec<String> {
+            registry.agents_needing_refresh(max_age_secs, 7_200).iter().map(|a| a.agent_id.clone()).collect()
+        };
+        assert_eq!(warned(&registry, 86_400), [stale.clone(), aging.clone()]);
+        // Warnings follow the sweep's threshold, not a fixed TTL
+        assert_eq!(warned(&registry, 300_000), Vec::<String>::new());
+        assert_eq!(warned(&registry, 10_000), [stale.clone(), aging.clone(), fresh.clone()]);
+
+        assert_eq!(registry.sweep_stale_attestations(86_400).unwrap(), [stale.as_str()]);
+        assert_eq!(registry.get(&stale).unwrap().status, RegistrationStatus::Suspended);
+        assert!(registry.get(&fresh).unwrap().is_active());
+        assert!(registry.get(&aging).unwrap().is_active());
+        assert!(registry.sweep_stale_attestations(86_400).unwrap().is_empty());
+
+        clock.advance(60);
+        registry.refresh_attestation(&stale, tdx_quote(&stale_key.public_key_bytes(), &rtmrs()), rtmrs()).unwrap();
+        let agent = registry.get(&stale).unwrap();
+        assert!(agent.is_active());
+        assert_eq!(agent.last_attestation, clock.now());
+        registry.refresh_attestation(&revoked, tdx_quote(&revoked_key.public_key_bytes(), &rtmrs()), rtmrs()).unwrap();
+        assert_eq!(registry.get(&revoked).unwrap().status, RegistrationStatus::Revoked);
+
+        // Suspended by hand stays suspended
+        registry.update_status(&fresh, RegistrationStatus::Suspended).unwrap();
+        registry.refresh_attestation(&fresh, tdx_quote(&fresh_key.public_key_bytes(), &rtmrs()), rtmrs()).unwrap();
+        assert_eq!(registry.get(&fresh).unwrap().status, RegistrationStatus::Suspended);
+    }
+
+    #[test]
+    fn test_find_by_metadata() {
+        let mut registry = AgentRegistry::new();
+        let mut register = |region: &str, tier: &str| {
+            let sdkey = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+            let registration = AgentRegistration::from_sdkey(&sdkey, "Agent".into(), "".into())
+                .with_metadata("region", region)
+                .with_metadata("tier", tier)
+                .with_attestation(tdx_quote(&sdkey.public_key_bytes(), &rtmrs()), rtmrs());
+            registry.register(registration).unwrap()
+        };
+        let eu_gold = register("eu-west", "gold");
+        let eu_silver = register("eu-west", "silver");
+        let us_gold = register("us-east", "gold");
+        let ids = |agents: Vec<&AgentRegistration>| {
+            let mut ids: Vec<String> = agents.into_iter().map(|a| a.agent_id.clone()).collect();
+            ids.sort();
+            ids
+        };
+        let sorted = |mut ids: Vec<String>| {
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(ids(registry.find_by_metadata("region", "eu-west")), sorted(vec![eu_gold.clone(), eu_silver.clone()]));
+        assert_eq!(ids(registry.find_by_metadata("tier", "gold")), sorted(vec![eu_gold.clone(), us_gold.clone()]));
+        assert_eq!(ids(registry.find_by_metadata_predicate("region", |v| v.starts_with("eu-"))).len(), 2);
+        assert!(registry.find_by_metadata("gold", "tier").is_empty());
+
+        // Updates move the agent between values
+        assert_eq!(registry.set_metadata(&eu_silver, "tier", "gold").unwrap().as_deref(), Some("silver"));
+        assert_eq!(ids(registry.find_by_metadata("tier", "gold")).len(), 3);
+        assert!(registry.find_by_metadata("tier", "silver").is_empty());
+        assert!(!registry.by_metadata["tier"].contains_key("silver"));
+        assert_eq!(registry.get(&eu_silver).unwrap().metadata["tier"], "gold");
+        assert!(matches!(registry.set_metadata("missing", "tier", "gold"), Err(RegistryError::NotFound(_))));
+
+        // Inactive agents are indexed but not returned
+        registry.update_status(&us_gold, RegistrationStatus::Suspended).unwrap();
+        assert_eq!(ids(registry.find_by_metadata("tier", "gold")), sorted(vec![eu_gold.clone(), eu_silver.clone()]));
+
+        registry.unregister(&eu_gold).unwrap();
+        registry.unregister(&eu_silver).unwrap();
+        assert!(!registry.by_metadata["region"].contains_key("eu-west"));
+        registry.unregister(&us_gold).unwrap();
+        assert!(registry.by_metadata.is_empty());
+    }
+
+    #[test]
+    fn test_register_signed() {
+        let sdkey = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let registration = AgentRegistration::from_sdkey(&sdkey, "Agent".into(), "".into())
+            .with_capability("trading");
+        let signature = registration.sign(&sdkey).unwrap();
+        let clock = MockClock::new(registration.registered_at);
+        let mut registry = AgentRegistry::new().with_allow_unsigned(false).with_clock(clock.clone());
+        assert!(matches!(registry.register(registration.clone()), Err(RegistryError::PermissionDenied)));
+
+        // Signed by someone else
+        let forger = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let forged = registration.sign(&forger).unwrap();
+        assert!(matches!(registry.register_signed(registration.clone(), &forged), Err(RegistryError::InvalidSignature)));
+
+        // Changed after signing
+        let tampered = registration.clone().with_capability("governance");
+        assert!(matches!(registry.register_signed(tampered, &signature), Err(RegistryError::InvalidSignature)));
+        let tampered = registration.clone().with_owner("0xattacker".into());
+        assert!(matches!(registry.register_signed(tampered, &signature), Err(RegistryError::InvalidSignature)));
+        let mut tampered = registration.clone();
+        tampered.permissions.level = sdkey_manager::PermissionLevel::Full;
+        assert!(matches!(registry.register_signed(tampered, &signature), Err(RegistryError::InvalidSignature)));
+
+        // Signed too long ago, or dated ahead
+        clock.advance(REGISTRATION_WINDOW_SECS + 1);
+        assert!(matches!(
+            registry.register_signed(registration.clone(), &signature),
+            Err(RegistryError::RegistrationOutOfWindow { .. })
+        ));
+        clock.set(registration.registered_at - REGISTRATION_WINDOW_SECS - 1);
+        assert!(matches!(
+            registry.register_signed(registration.clone(), &signature),
+            Err(RegistryError::RegistrationOutOfWindow { .. })
+        ));
+        clock.set(registration.registered_at + 60);
+
+        // Claims another agent's ID, signed with its own key
+        let mut impostor = registration.clone();
+        impostor.agent_id = forger.agent_id();
+        let impostor_signature = impostor.sign(&sdkey).unwrap();
+        assert!(matches!(
+            registry.register_signed(impostor, &impostor_signature),
+            Err(RegistryError::AgentIdMismatch { derived, .. }) if derived == sdkey.agent_id()
+        ));
+        assert_eq!(registry.count(), 0);
+
+        assert_eq!(registry.register_signed(registration, &signature).unwrap(), sdkey.agent_id());
+        assert!(registry.get(&sdkey.agent_id()).is_some());
+    }
+
+    #[test]
+    fn test_unregister() {
+        let sdkey = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let registration = || AgentRegistration::from_sdkey(&sdkey, "Agent".into(), "".into())
+            .with_owner("0xowner".into());
+
+        let mut registry = AgentRegistry::new();
+        let agent_id = registry.register(registration()).unwrap();
+        let removed = registry.unregister(&agent_id).unwrap();
+        assert_eq!(removed.agent_id, agent_id);
+        assert!(registry.get(&agent_id).is_none());
+        assert!(registry.get_by_public_key(&removed.public_key).is_none());
+        assert!(registry.get_by_owner("0xowner").is_empty());
+        assert!(!registry.by_owner.contains_key("0xowner"));
+        assert!(matches!(registry.unregister(&agent_id), Err(RegistryError::NotFound(_))));
+
+        // The public key is free to register again
+        registry.register(registration()).unwrap();
+        assert_eq!(registry.get_by_owner("0xowner").len(), 1);
+    }
 }
@@ -0,0 +1,222 @@
+//! Agent Registry Persistence
+//!
+//! Storage the registry writes registrations through to, so they survive
+//! a restart.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use crate::registry::{AgentRegistration, RegistryError};
+
+/// Durable storage for registrations, keyed by agent ID
+pub trait RegistryStore: Send + Sync {
+    /// Insert or replace a registration
+    fn put_registration(&mut self, registration: &AgentRegistration) -> Result<(), RegistryError>;
+
+    /// Get a registration by agent ID
+    fn get_registration(&self, agent_id: &str) -> Result<Option<AgentRegistration>, RegistryError>;
+
+    /// Remove a registration; removing a missing one is not an error
+    fn delete(&mut self, agent_id: &str) -> Result<(), RegistryError>;
+
+    /// Every stored registration
+    fn scan(&self) -> Result<Vec<AgentRegistration>, RegistryError>;
+}
+
+/// Registrations kept in one JSON file
+///
+/// The whole file is rewritten on every change, through a temporary file
+/// renamed over it, so a crash leaves either the old or the new contents.
+/// Suited to registries of up to a few thousand agents.
+pub struct JsonFileStore {
+    /// File path
+    path: PathBuf,
+    /// Contents of the file
+    registrations: BTreeMap<String, AgentRegistration>,
+}
+
+impl JsonFileStore {
+    /// Open the store at `path`; a missing file is an empty store
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, RegistryError> {
+        let path = path.into();
+        let registrations = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| storage_error(&path, e))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(storage_error(&path, e)),
+        };
+        Ok(Self { path, registrations })
+    }
+
+    /// Write the registrations to the file
+    fn flush(&self) -> Result<(), RegistryError> {
+        let json = serde_json::to_vec(&self.registrations).map_err(|e| storage_error(&self.path, e))?;
+        let temp = self.path.with_extension("tmp");
+        fs::write(&temp, json).map_err(|e| storage_error(&temp, e))?;
+        fs::rename(&temp, &self.path).map_err(|e| storage_error(&self.path, e))
+    }
+}
+
+impl RegistryStore for JsonFileStore {
+    fn put_registration(&mut self, registration: &AgentRegistration) -> Result<(), RegistryError> {
+        let agent_id = registration.agent_id.clone();
+        let previous = self.registrations.insert(agent_id.clone(), registration.clone());
+        if let Err(e) = self.flush() {
+            match previous {
+                Some(previous) => self.registrations.insert(agent_id, previous),
+                None => self.registrations.remove(&agent_id),
+            };
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn get_registration(&self, agent_id: &str) -> Result<Option<AgentRegistration>, RegistryError> {
+        Ok(self.registrations.get(agent_id).cloned())
+    }
+
+    fn delete(&mut self, agent_id: &str) -> Result<(), RegistryError> {
+        let Some(previous) = self.registrations.remove(agent_id) else {
+            return Ok(());
+        };
+        if let Err(e) = self.flush() {
+            self.registrations.insert(agent_id.to_string(), previous);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn scan(&self) -> Result<Vec<AgentRegistration>, RegistryError> {
+        Ok(self.registrations.values().cloned().collect())
+    }
+}
+
+/// Storage error naming the file involved
+fn storage_error(path: &std::path::Path, error: impl std::fmt::Display) -> RegistryError {
+    RegistryError::StorageError(format!("{}: {}", path.display(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use sdkey_manager::{AgentMetadata, AgentPermissions, AgentSDKey};
+    use crate::registry::{AgentRegistry, RegistrationStatus};
+
+    /// In-memory store whose writes fail while `failing` is set
+    #[derive(Default)]
+    struct FlakyStore {
+        registrations: BTreeMap<String, AgentRegistration>,
+        failing: Arc<AtomicBool>,
+    }
+
+    impl FlakyStore {
+        fn check(&self) -> Result<(), RegistryError> {
+            match self.failing.load(Ordering::SeqCst) {
+                true => Err(RegistryError::StorageError("disk full".into())),
+                false => Ok(()),
+            }
+        }
+    }
+
+    impl RegistryStore for FlakyStore {
+        fn put_registration(&mut self, registration: &AgentRegistration) -> Result<(), RegistryError> {
+            self.check()?;
+            self.registrations.insert(registration.agent_id.clone(), registration.clone());
+            Ok(())
+        }
+
+        fn get_registration(&self, agent_id: &str) -> Result<Option<AgentRegistration>, RegistryError> {
+            Ok(self.registrations.get(agent_id).cloned())
+        }
+
+        fn delete(&mut self, agent_id: &str) -> Result<(), RegistryError> {
+            self.check()?;
+            self.registrations.remove(agent_id);
+            Ok(())
+        }
+
+        fn scan(&self) -> Result<Vec<AgentRegistration>, RegistryError> {
+            Ok(self.registrations.values().cloned().collect())
+        }
+    }
+
+    fn registration(owner: &str) -> AgentRegistration {
+        let sdkey = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        AgentRegistration::from_sdkey(&sdkey, "Agent".into(), "".into())
+            .with_owner(owner.into())
+            .with_metadata("region", "eu-west")
+    }
+
+    /// Register an agent and activate it
+    fn register_active(registry: &mut AgentRegistry, owner: &str) -> String {
+        let agent_id = registry.register(registration(owner)).unwrap();
+        registry.update_status(&agent_id, RegistrationStatus::Active).unwrap();
+        agent_id
+    }
+
+    #[test]
+    fn test_json_file_store_recovery() {
+        let path = std::env::temp_dir().join(format!("registry-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut registry = AgentRegistry::new().with_store(JsonFileStore::open(&path).unwrap());
+        let ids: Vec<String> = (0..100)
+            .map(|i| register_active(&mut registry, &format!("owner-{}", i % 7)))
+            .collect();
+        registry.update_status(&ids[0], RegistrationStatus::Suspended).unwrap();
+        registry.set_metadata(&ids[1], "region", "us-east").unwrap();
+        let removed = registry.unregister(&ids[2]).unwrap();
+
+        // Rebuild from the file alone, as after a crash
+        let store = JsonFileStore::open(&path).unwrap();
+        assert_eq!(store.get_registration(&ids[3]).unwrap().unwrap().agent_id, ids[3]);
+        let loaded = AgentRegistry::load(store).unwrap();
+        assert_eq!(loaded.count(), 99);
+        assert!(loaded.get(&ids[2]).is_none());
+        assert!(loaded.get_by_public_key(&removed.public_key).is_none());
+        for id in &ids[3..] {
+            let agent = registry.get(id).unwrap();
+            assert_eq!(loaded.get_by_public_key(&agent.public_key).unwrap().agent_id, *id);
+        }
+        for owner in 0..7 {
+            let owner = format!("owner-{}", owner);
+            let mut expected: Vec<&str> = registry.get_by_owner(&owner).iter().map(|a| a.agent_id.as_str()).collect();
+            let mut actual: Vec<&str> = loaded.get_by_owner(&owner).iter().map(|a| a.agent_id.as_str()).collect();
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected);
+        }
+        assert_eq!(loaded.get(&ids[0]).unwrap().status, RegistrationStatus::Suspended);
+        assert_eq!(loaded.get(&ids[1]).unwrap().metadata["region"], "us-east");
+        assert_eq!(loaded.find_by_metadata("region", "us-east").len(), 1);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_storage_failure_leaves_memory_unchanged() {
+        let store = FlakyStore::default();
+        let failing = store.failing.clone();
+        let mut registry = AgentRegistry::new().with_store(store);
+        let agent_id = register_active(&mut registry, "owner");
+
+        failing.store(true, Ordering::SeqCst);
+        let storage_error = |error: RegistryError| matches!(error, RegistryError::StorageError(_));
+        assert!(storage_error(registry.register(registration("other")).unwrap_err()));
+        assert!(storage_error(registry.update_status(&agent_id, RegistrationStatus::Revoked).unwrap_err()));
+        assert!(storage_error(registry.set_metadata(&agent_id, "region", "us-east").unwrap_err()));
+        assert!(storage_error(registry.unregister(&agent_id).unwrap_err()));
+
+        assert_eq!(registry.count(), 1);
+        assert!(registry.get_by_owner("other").is_empty());
+        let agent = registry.get(&agent_id).unwrap();
+        assert!(agent.is_active());
+        assert_eq!(agent.metadata["region"], "eu-west");
+        assert!(registry.find_by_metadata("region", "us-east").is_empty());
+        assert_eq!(registry.find_by_metadata("region", "eu-west").len(), 1);
+        assert_eq!(registry.get_by_owner("owner").len(), 1);
+    }
+}
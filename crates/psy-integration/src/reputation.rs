@@ -2,11 +2,18 @@
 //!
 //! Tracks and manages agent reputation based on performance and behavior.
 
-use std::collections::HashMap;
+use std::collections::{vec_deque, BTreeMap, HashMap, HashSet, VecDeque};
+use std::io::{Read, Write};
+use std::sync::mpsc::{self, Receiver, Sender};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use sdkey_manager::{AgentSDKey, SDKeyError};
+use crate::attestation_sync::AttestationSyncState;
+use crate::clock::{Clock, SystemClock};
 
 /// Reputation level thresholds
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ReputationLevel {
     /// New agent, unverified
     Newcomer,    // 0-199
@@ -23,6 +30,16 @@ pub enum ReputationLevel {
 }
 
 impl ReputationLevel {
+    /// Every level, lowest first
+    pub const ALL: [ReputationLevel; 6] = [
+        Self::Newcomer,
+        Self::Verified,
+        Self::Established,
+        Self::Trusted,
+        Self::Elite,
+        Self::Legendary,
+    ];
+
     /// Get level from score
     pub fn from_score(score: u32) -> Self {
         match score {
@@ -35,6 +52,19 @@ impl ReputationLevel {
         }
     }
 
+    /// Get level from a score out of `max_score`
+    ///
+    /// Thresholds are the same fractions of the maximum as on the default
+    /// 0-1000 scale.
+    pub fn from_score_scaled(score: u32, max_score: u32) -> Self {
+        Self::from_score((score as u64 * MAX_SCORE as u64 / max_score.max(1) as u64) as u32)
+    }
+
+    /// Get minimum score for level out of `max_score`
+    pub fn min_score_scaled(&self, max_score: u32) -> u32 {
+        (self.min_score() as u64 * max_score as u64).div_ceil(MAX_SCORE as u64) as u32
+    }
+
     /// Get minimum score for level
     pub fn min_score(&self) -> u32 {
         match self {
@@ -102,56 +132,508 @@ pub enum ReputationEvent {
         reason: String,
         amount: i32,
     },
+    /// Inactivity decay applied by `ReputationTracker::apply_decay`
+    Decay {
+        idle_secs: u64,
+        amount: i32,
+    },
+    /// Vouched for by another agent
+    Endorsed {
+        endorser: String,
+        points: i32,
+    },
+    /// Probation lifted early by `ReputationTracker::clear_probation`
+    ProbationCleared {
+        reason: String,
+    },
+    /// Installment of a recovery plan, applied in full even on probation
+    ScheduledRecovery {
+        amount: i32,
+    },
+    /// Undoes the event with `event_id`, see `ReputationTracker::reverse_event`
+    Reversal {
+        event_id: u64,
+        reason: String,
+        amount: i32,
+    },
+}
+
+/// Points awarded or deducted per event family
+///
+/// A zero divisor disables the reward or penalty it scales.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReputationWeights {
+    /// PnL (bps) per point for successful trades
+    pub trade_pnl_divisor: i64,
+    /// Most points from PnL on one trade
+    pub trade_pnl_cap: u32,
+    /// Volume (USD) per point for successful trades
+    pub trade_volume_divisor: u64,
+    /// Most points from volume on one trade
+    pub trade_volume_cap: u32,
+    /// Loss (bps) per point deducted for failed trades
+    pub trade_loss_divisor: i64,
+    /// Most points deducted for one failed trade
+    pub trade_loss_cap: u32,
+    /// Points per governance vote
+    pub governance_vote_points: i32,
+    /// Points for creating a proposal that passed
+    pub proposal_passed_bonus: i32,
+    /// Points deducted for creating a proposal that failed
+    pub proposal_rejected_penalty: i32,
+    /// Points for a verified attestation
+    pub attestation_bonus: i32,
+    /// Points deducted for an expired attestation
+    pub attestation_penalty: i32,
+    /// Points per submitted ZK proof
+    pub proof_points: i32,
+    /// Contribution amount per point
+    pub contribution_divisor: u64,
+    /// Most points from one contribution
+    pub contribution_cap: u32,
+    /// Uptime hours per point
+    pub uptime_hours_per_point: u32,
+    /// Most points from one uptime reward
+    pub uptime_cap: u32,
+    /// Scale applied to slashed amounts (basis points, 10000 = as given)
+    pub slash_multiplier_bps: u32,
+}
+
+impl Default for ReputationWeights {
+    fn default() -> Self {
+        Self {
+            trade_pnl_divisor: 100,
+            trade_pnl_cap: 10,
+            trade_volume_divisor: 10_000,
+            trade_volume_cap: 5,
+            trade_loss_divisor: 200,
+            trade_loss_cap: 20,
+            governance_vote_points: 1,
+            proposal_passed_bonus: 5,
+            proposal_rejected_penalty: 2,
+            attestation_bonus: 10,
+            attestation_penalty: 20,
+            proof_points: 5,
+            contribution_divisor: 1000,
+            contribution_cap: 20,
+            uptime_hours_per_point: 24,
+            uptime_cap: 7,
+            slash_multiplier_bps: 10_000,
+        }
+    }
+}
+
+impl ReputationWeights {
+    /// Slower gains and harsher penalties
+    pub fn conservative() -> Self {
+        Self {
+            trade_pnl_divisor: 200,
+            trade_pnl_cap: 5,
+            trade_volume_divisor: 20_000,
+            trade_volume_cap: 3,
+            trade_loss_divisor: 100,
+            trade_loss_cap: 40,
+            governance_vote_points: 1,
+            proposal_passed_bonus: 3,
+            proposal_rejected_penalty: 3,
+            attestation_bonus: 5,
+            attestation_penalty: 30,
+            proof_points: 3,
+            contribution_divisor: 2000,
+            contribution_cap: 10,
+            uptime_hours_per_point: 48,
+            uptime_cap: 3,
+            slash_multiplier_bps: 15_000,
+        }
+    }
+}
+
+/// Reputation event type without its payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ReputationEventKind {
+    TradeSuccess,
+    TradeFailed,
+    GovernanceVote,
+    ProposalCreated,
+    AttestationVerified,
+    AttestationExpired,
+    ProofSubmitted,
+    SyndicateContribution,
+    UptimeReward,
+    Slashed,
+    ManualAdjustment,
+    Decay,
+    Endorsed,
+    ProbationCleared,
+    ScheduledRecovery,
+    Reversal,
 }
 
 impl ReputationEvent {
-    /// Calculate reputation delta for event
+    /// Event type
+    pub fn kind(&self) -> ReputationEventKind {
+        match self {
+            Self::TradeSuccess { .. } => ReputationEventKind::TradeSuccess,
+            Self::TradeFailed { .. } => ReputationEventKind::TradeFailed,
+            Self::GovernanceVote => ReputationEventKind::GovernanceVote,
+            Self::ProposalCreated { .. } => ReputationEventKind::ProposalCreated,
+            Self::AttestationVerified => ReputationEventKind::AttestationVerified,
+            Self::AttestationExpired => ReputationEventKind::AttestationExpired,
+            Self::ProofSubmitted => ReputationEventKind::ProofSubmitted,
+            Self::SyndicateContribution { .. } => ReputationEventKind::SyndicateContribution,
+            Self::UptimeReward { .. } => ReputationEventKind::UptimeReward,
+            Self::Slashed { .. } => ReputationEventKind::Slashed,
+            Self::ManualAdjustment { .. } => ReputationEventKind::ManualAdjustment,
+            Self::Decay { .. } => ReputationEventKind::Decay,
+            Self::Endorsed { .. } => ReputationEventKind::Endorsed,
+            Self::ProbationCleared { .. } => ReputationEventKind::ProbationCleared,
+            Self::ScheduledRecovery { .. } => ReputationEventKind::ScheduledRecovery,
+            Self::Reversal { .. } => ReputationEventKind::Reversal,
+        }
+    }
+
+    /// Calculate reputation delta for event under the default weights
     pub fn reputation_delta(&self) -> i32 {
+        self.reputation_delta_with(&ReputationWeights::default())
+    }
+
+    /// Calculate reputation delta for event
+    ///
+    /// Manual adjustments, decay, endorsements, recoveries and reversals
+    /// are applied as given.
+    pub fn reputation_delta_with(&self, weights: &ReputationWeights) -> i32 {
         match self {
             Self::TradeSuccess { pnl_bps, volume_usd } => {
                 // Reward based on PnL and volume
-                let pnl_reward = (*pnl_bps / 100).clamp(0, 10) as i32;
-                let volume_reward = ((*volume_usd / 10000).min(5)) as i32;
+                let pnl_reward = pnl_bps.checked_div(weights.trade_pnl_divisor).unwrap_or(0)
+                    .clamp(0, weights.trade_pnl_cap as i64) as i32;
+                let volume_reward = volume_usd.checked_div(weights.trade_volume_divisor).unwrap_or(0)
+                    .min(weights.trade_volume_cap as u64) as i32;
                 pnl_reward + volume_reward
             }
             Self::TradeFailed { loss_bps } => {
                 // Penalty for losses
-                (-loss_bps / 200).clamp(-20, 0) as i32
+                (-loss_bps).checked_div(weights.trade_loss_divisor).unwrap_or(0)
+                    .clamp(-(weights.trade_loss_cap as i64), 0) as i32
             }
-            Self::GovernanceVote => 1,
+            Self::GovernanceVote => weights.governance_vote_points,
             Self::ProposalCreated { passed } => {
-                if *passed { 5 } else { -2 }
+                if *passed { weights.proposal_passed_bonus } else { -weights.proposal_rejected_penalty }
             }
-            Self::AttestationVerified => 10,
-            Self::AttestationExpired => -20,
-            Self::ProofSubmitted => 5,
+            Self::AttestationVerified => weights.attestation_bonus,
+            Self::AttestationExpired => -weights.attestation_penalty,
+            Self::ProofSubmitted => weights.proof_points,
             Self::SyndicateContribution { amount } => {
-                ((*amount / 1000).min(20)) as i32
+                amount.checked_div(weights.contribution_divisor).unwrap_or(0)
+                    .min(weights.contribution_cap as u64) as i32
             }
             Self::UptimeReward { hours } => {
-                ((*hours / 24).min(7)) as i32
+                hours.checked_div(weights.uptime_hours_per_point).unwrap_or(0)
+                    .min(weights.uptime_cap) as i32
+            }
+            Self::Slashed { amount, .. } => {
+                let scaled = -(*amount as i64) * weights.slash_multiplier_bps as i64 / 10_000;
+                scaled.clamp(i32::MIN as i64, i32::MAX as i64) as i32
             }
-            Self::Slashed { amount, .. } => -*amount,
             Self::ManualAdjustment { amount, .. } => *amount,
+            Self::Decay { amount, .. } => -*amount,
+            Self::Endorsed { points, .. } => *points,
+            Self::ProbationCleared { .. } => 0,
+            Self::ScheduledRecovery { amount } | Self::Reversal { amount, .. } => *amount,
+        }
+    }
+
+    /// Subscores the event moves
+    ///
+    /// Slashes, manual adjustments, decay, endorsements and reversals
+    /// reflect on the agent as a whole and move all of them.
+    pub fn dimensions(&self) -> &'static [Dimension] {
+        match self {
+            Self::TradeSuccess { .. } | Self::TradeFailed { .. } => &[Dimension::Trading],
+            Self::GovernanceVote
+            | Self::ProposalCreated { .. }
+            | Self::SyndicateContribution { .. } => &[Dimension::Governance],
+            Self::AttestationVerified
+            | Self::AttestationExpired
+            | Self::ProofSubmitted
+            | Self::UptimeReward { .. } => &[Dimension::Reliability],
+            Self::Slashed { .. }
+            | Self::ManualAdjustment { .. }
+            | Self::Decay { .. }
+            | Self::Endorsed { .. }
+            | Self::ScheduledRecovery { .. }
+            | Self::Reversal { .. } => &Dimension::ALL,
+            Self::ProbationCleared { .. } => &[],
+        }
+    }
+}
+
+/// Aspect of an agent's reputation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Dimension {
+    /// Trading results
+    Trading,
+    /// Governance participation
+    Governance,
+    /// Attestations, proofs and uptime
+    Reliability,
+}
+
+impl Dimension {
+    /// Every dimension
+    pub const ALL: [Dimension; 3] = [Self::Trading, Self::Governance, Self::Reliability];
+}
+
+/// Per-dimension scores (0-1000 each)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Subscores {
+    /// Trading score
+    pub trading: u32,
+    /// Governance score
+    pub governance: u32,
+    /// Reliability score
+    pub reliability: u32,
+}
+
+impl Subscores {
+    /// The same score in every dimension
+    pub fn uniform(score: u32) -> Self {
+        Self {
+            trading: score,
+            governance: score,
+            reliability: score,
+        }
+    }
+
+    /// Score in a dimension
+    pub fn get(&self, dimension: Dimension) -> u32 {
+        match dimension {
+            Dimension::Trading => self.trading,
+            Dimension::Governance => self.governance,
+            Dimension::Reliability => self.reliability,
+        }
+    }
+
+    fn get_mut(&mut self, dimension: Dimension) -> &mut u32 {
+        match dimension {
+            Dimension::Trading => &mut self.trading,
+            Dimension::Governance => &mut self.governance,
+            Dimension::Reliability => &mut self.reliability,
+        }
+    }
+}
+
+/// Relative weight of each subscore in the headline score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DimensionWeights {
+    /// Weight of the trading score
+    pub trading: u32,
+    /// Weight of the governance score
+    pub governance: u32,
+    /// Weight of the reliability score
+    pub reliability: u32,
+}
+
+impl Default for DimensionWeights {
+    fn default() -> Self {
+        Self {
+            trading: 1,
+            governance: 1,
+            reliability: 1,
         }
     }
 }
 
+impl DimensionWeights {
+    /// Weighted average of the subscores, rounded
+    ///
+    /// All-zero weights count every dimension equally.
+    pub fn combine(&self, subscores: &Subscores) -> u32 {
+        let weights = if self.trading + self.governance + self.reliability == 0 {
+            Self::default()
+        } else {
+            *self
+        };
+        let total = (weights.trading + weights.governance + weights.reliability) as u64;
+        let weighted = weights.trading as u64 * subscores.trading as u64
+            + weights.governance as u64 * subscores.governance as u64
+            + weights.reliability as u64 * subscores.reliability as u64;
+        ((weighted + total / 2) / total) as u32
+    }
+}
+
 /// Reputation record for an agent
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "StoredRecord")]
 pub struct ReputationRecord {
     /// Agent ID
     pub agent_id: String,
-    /// Current score (0-1000)
+    /// Current score, within `bounds`
     pub score: u32,
     /// Current level
     pub level: ReputationLevel,
-    /// Event history (last N events)
-    pub history: Vec<ReputationEventRecord>,
+    /// Event history (last N events), oldest first
+    pub history: VecDeque<ReputationEventRecord>,
     /// Total events processed
     pub total_events: u64,
     /// Last update timestamp
     pub last_updated: u64,
+    /// Scores from events no longer in `history`, oldest first
+    #[serde(default)]
+    pub checkpoints: Vec<ScoreCheckpoint>,
+    /// End of the agent's probation, if they've been put on it
+    #[serde(default)]
+    pub on_probation_until: Option<u64>,
+    /// Per-dimension scores
+    ///
+    /// Moved by events only; setting `score` directly leaves them alone.
+    pub subscores: Subscores,
+    /// Highest level the agent has reached through events
+    pub highest_level_achieved: ReputationLevel,
+    /// Events processed, by kind
+    ///
+    /// Kept in full however much history is retained, and ordered so the
+    /// serialized record is stable.
+    pub event_counts: BTreeMap<ReputationEventKind, u64>,
+    /// Score range and starting score
+    pub bounds: ScoreBounds,
+    /// Recent gains from rate-limited event kinds, as (timestamp, points),
+    /// oldest first
+    ///
+    /// Kept apart from `history`, so trimming it doesn't loosen the limits.
+    #[serde(default)]
+    pub rate_limited_gains: BTreeMap<ReputationEventKind, VecDeque<(u64, i32)>>,
+    /// `event_id` the next event gets
+    ///
+    /// Not derived from `total_events`, which merges can leave below the
+    /// IDs already in use.
+    pub next_event_id: u64,
+}
+
+/// Serialized form of `ReputationRecord`
+///
+/// Records saved before subscores existed start every subscore at the
+/// headline score, and ones saved before `highest_level_achieved` at their
+/// current level. Ones saved before `event_counts` count their retained
+/// history, and events saved before `event_id` are numbered from
+/// `total_events`. Ones saved before `next_event_id` continue after the
+/// highest ID in use.
+#[derive(Deserialize)]
+struct StoredRecord {
+    agent_id: String,
+    score: u32,
+    level: ReputationLevel,
+    history: VecDeque<ReputationEventRecord>,
+    total_events: u64,
+    last_updated: u64,
+    #[serde(default)]
+    checkpoints: Vec<ScoreCheckpoint>,
+    #[serde(default)]
+    on_probation_until: Option<u64>,
+    #[serde(default)]
+    subscores: Option<Subscores>,
+    #[serde(default)]
+    highest_level_achieved: Option<ReputationLevel>,
+    #[serde(default)]
+    event_counts: Option<BTreeMap<ReputationEventKind, u64>>,
+    #[serde(default)]
+    bounds: ScoreBounds,
+    #[serde(default)]
+    rate_limited_gains: BTreeMap<ReputationEventKind, VecDeque<(u64, i32)>>,
+    #[serde(default)]
+    next_event_id: Option<u64>,
+}
+
+impl From<StoredRecord> for ReputationRecord {
+    fn from(stored: StoredRecord) -> Self {
+        let event_counts = stored.event_counts.unwrap_or_else(|| {
+            let mut counts = BTreeMap::new();
+            for event in &stored.history {
+                *counts.entry(event.event.kind()).or_default() += 1;
+            }
+            counts
+        });
+        let mut history = stored.history;
+        let first_id = (stored.total_events + 1).saturating_sub(history.len() as u64);
+        for (id, event) in (first_id..).zip(history.iter_mut()) {
+            if event.event_id == 0 {
+                event.event_id = id;
+            }
+        }
+        let next_event_id = stored.next_event_id.unwrap_or_else(|| {
+            history.iter().map(|e| e.event_id).max().unwrap_or(0).max(stored.total_events) + 1
+        });
+        Self {
+            next_event_id,
+            event_counts,
+            history,
+            bounds: stored.bounds,
+            subscores: stored.subscores.unwrap_or(Subscores::uniform(stored.score)),
+            highest_level_achieved: stored.highest_level_achieved.unwrap_or(stored.level).max(stored.level),
+            agent_id: stored.agent_id,
+            score: stored.score,
+            level: stored.level,
+            total_events: stored.total_events,
+            last_updated: stored.last_updated,
+            checkpoints: stored.checkpoints,
+            on_probation_until: stored.on_probation_until,
+            rate_limited_gains: stored.rate_limited_gains,
+        }
+    }
+}
+
+/// Score of a new record
+const STARTING_SCORE: u32 = 100;
+
+/// Range a record's scores are kept in, and where they start
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreBounds {
+    /// Lowest score
+    pub min: u32,
+    /// Highest score
+    pub max: u32,
+    /// Score of a new record
+    pub start: u32,
+}
+
+impl Default for ScoreBounds {
+    fn default() -> Self {
+        Self {
+            min: 0,
+            max: MAX_SCORE,
+            start: STARTING_SCORE,
+        }
+    }
+}
+
+impl ScoreBounds {
+    /// Move a score by `delta`, staying in range
+    pub fn apply(&self, score: u32, delta: i32) -> u32 {
+        (score as i64 + delta as i64).clamp(self.min as i64, self.max as i64) as u32
+    }
+
+    /// Level of a score in this range
+    pub fn level(&self, score: u32) -> ReputationLevel {
+        ReputationLevel::from_score_scaled(score, self.max)
+    }
+}
+
+/// Events kept in a record's history unless the tracker says otherwise
+const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// Resolution of score checkpoints
+const CHECKPOINT_INTERVAL_SECS: u64 = 86_400;
+
+/// Score as of an event dropped from history
+///
+/// At most one is kept per day, holding the score after the last dropped
+/// event of that day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreCheckpoint {
+    /// Time of the event
+    pub timestamp: u64,
+    /// Score after the event
+    pub score: u32,
 }
 
 /// Recorded reputation event
@@ -165,35 +647,287 @@ pub struct ReputationEventRecord {
     pub score_after: u32,
     /// Timestamp
     pub timestamp: u64,
+    /// Delta the event would have applied had probation, a rate limit or
+    /// a level floor not cut it
+    #[serde(default)]
+    pub clamped_from: Option<i32>,
+    /// Delta before recency weighting scaled it, if it did
+    #[serde(default)]
+    pub weighted_from: Option<i32>,
+    /// Key the event was applied under, if any
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+    /// Number of the event in the agent's history, starting at 1
+    #[serde(default)]
+    pub event_id: u64,
+    /// `event_id` of the `Reversal` that undid this event, if any
+    #[serde(default)]
+    pub reversed_by: Option<u64>,
+}
+
+/// Cap on the points an agent can gain from one event type
+///
+/// Only gains are limited; penalties always apply in full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// Sliding window the caps apply to
+    pub window_secs: u64,
+    /// Most points gained within the window
+    pub max_points: Option<u32>,
+    /// Most events that gain points within the window
+    pub max_events: Option<u32>,
+}
+
+impl RateLimit {
+    /// At most `max_points` per day
+    pub fn points_per_day(max_points: u32) -> Self {
+        Self { window_secs: 86_400, max_points: Some(max_points), max_events: None }
+    }
+
+    /// At most `max_events` rewarded events per day
+    pub fn events_per_day(max_events: u32) -> Self {
+        Self { window_secs: 86_400, max_points: None, max_events: Some(max_events) }
+    }
+}
+
+/// How idle reputation fades over time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecayPolicy {
+    /// Time for the score above `floor` to halve
+    pub half_life_secs: u64,
+    /// Score that decay never goes below
+    pub floor: u32,
+}
+
+/// Probation for agents after a heavy slash
+///
+/// While on probation, an agent's gains are scaled down so they can't
+/// climb straight back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProbationPolicy {
+    /// Smallest slash, in points the event asks for, that starts probation
+    ///
+    /// Judged on the slash as requested, before weights scale it.
+    pub min_slash: u32,
+    /// Probation added per qualifying slash
+    pub duration_secs: u64,
+    /// Scale applied to gains on probation (basis points, 10000 = unchanged)
+    pub gain_multiplier_bps: u32,
+}
+
+impl Default for ProbationPolicy {
+    fn default() -> Self {
+        Self {
+            min_slash: 50,
+            duration_secs: 30 * 86_400,
+            gain_multiplier_bps: 5_000,
+        }
+    }
+}
+
+/// Scaling of deltas by event age, for backfilled events
+///
+/// Age is measured when the event is applied. The weight falls linearly
+/// from full at age zero to `floor_bps` at `horizon_secs` and stays there
+/// for older events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecencyWeighting {
+    /// Age at which the weight bottoms out
+    pub horizon_secs: u64,
+    /// Weight of events at or past the horizon (basis points, 10000 = unchanged)
+    pub floor_bps: u32,
+}
+
+impl Default for RecencyWeighting {
+    fn default() -> Self {
+        Self {
+            horizon_secs: 90 * 86_400,
+            floor_bps: 5_000,
+        }
+    }
+}
+
+impl RecencyWeighting {
+    /// Weight of an event `age_secs` old (basis points)
+    pub fn weight_bps(&self, age_secs: u64) -> u32 {
+        if self.horizon_secs == 0 || age_secs >= self.horizon_secs {
+            return self.floor_bps;
+        }
+        let falloff = 10_000i64 - self.floor_bps as i64;
+        (10_000 - falloff * age_secs as i64 / self.horizon_secs as i64) as u32
+    }
+}
+
+/// Points an agent earns back gradually, e.g. after a slash
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecoveryPlan {
+    /// Points to restore in total
+    pub total_points: u32,
+    /// Points restored so far
+    pub granted: u32,
+    /// When the plan was scheduled
+    pub started_at: u64,
+    /// Time over which the points are restored, not counting pauses
+    pub duration_secs: u64,
+    /// When the plan was paused, if it is
+    pub paused_at: Option<u64>,
+    /// Time spent paused before `paused_at`
+    pub paused_secs: u64,
+}
+
+impl RecoveryPlan {
+    /// Whether the plan is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Points still to restore
+    pub fn remaining(&self) -> u32 {
+        self.total_points - self.granted
+    }
+
+    /// Points earned by `now` but not yet granted
+    fn due_at(&self, now: u64) -> u32 {
+        if self.is_paused() {
+            return 0;
+        }
+        let elapsed = now.saturating_sub(self.started_at)
+            .saturating_sub(self.paused_secs)
+            .min(self.duration_secs);
+        let earned = self.total_points as u64 * elapsed / self.duration_secs;
+        (earned as u32).saturating_sub(self.granted)
+    }
+}
+
+impl DecayPolicy {
+    /// Score after `idle_secs` of inactivity
+    ///
+    /// Scores at or below the floor are left alone.
+    pub fn decayed_score(&self, score: u32, idle_secs: u64) -> u32 {
+        if score <= self.floor || self.half_life_secs == 0 {
+            return score;
+        }
+        let above_floor = (score - self.floor) as f64;
+        let factor = 0.5_f64.powf(idle_secs as f64 / self.half_life_secs as f64);
+        self.floor + (above_floor * factor).round() as u32
+    }
 }
 
 impl ReputationRecord {
     /// Create new record for agent
     pub fn new(agent_id: String) -> Self {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        Self::new_at(agent_id, SystemClock.now())
+    }
+
+    /// Create new record for agent as of `now`
+    pub fn new_at(agent_id: String, now: u64) -> Self {
+        Self::new_bounded(agent_id, ScoreBounds::default(), now)
+    }
 
+    /// Create new record for agent as of `now`, scored within `bounds`
+    pub fn new_bounded(agent_id: String, bounds: ScoreBounds, now: u64) -> Self {
+        let level = bounds.level(bounds.start);
         Self {
             agent_id,
-            score: 100, // Start with newcomer baseline
-            level: ReputationLevel::Newcomer,
-            history: Vec::new(),
+            score: bounds.start,
+            level,
+            history: VecDeque::new(),
             total_events: 0,
             last_updated: now,
+            checkpoints: Vec::new(),
+            on_probation_until: None,
+            subscores: Subscores::uniform(bounds.start),
+            highest_level_achieved: level,
+            event_counts: BTreeMap::new(),
+            bounds,
+            rate_limited_gains: BTreeMap::new(),
+            next_event_id: 1,
         }
     }
 
-    /// Apply a reputation event
+    /// Events of a kind processed
+    pub fn event_count(&self, kind: ReputationEventKind) -> u64 {
+        self.event_counts.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Score in a dimension
+    pub fn subscore(&self, dimension: Dimension) -> u32 {
+        self.subscores.get(dimension)
+    }
+
+    /// Whether the agent is on probation at `now`
+    pub fn is_on_probation_at(&self, now: u64) -> bool {
+        self.on_probation_until.is_some_and(|until| now < until)
+    }
+
+    /// Apply a reputation event as of the current time
+    ///
+    /// Stamped with `last_updated` instead if the record was last updated
+    /// ahead of the current time, so this never fails.
     pub fn apply_event(&mut self, event: ReputationEvent) -> i32 {
-        let delta = event.reputation_delta();
-        let new_score = (self.score as i32 + delta).clamp(0, 1000) as u32;
-        
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let now = SystemClock.now().max(self.last_updated);
+        let delta = self.push_event(event, &ReputationWeights::default(), now);
+        self.trim_history(DEFAULT_HISTORY_LIMIT);
+        delta
+    }
+
+    /// Apply a reputation event as of `now`, e.g. to backfill history
+    ///
+    /// Events must come in time order: one older than `last_updated` is
+    /// rejected, so history stays sorted and trends stay meaningful.
+    pub fn apply_event_at(&mut self, event: ReputationEvent, now: u64) -> Result<i32, ReputationError> {
+        self.apply_event_with(event, &ReputationWeights::default(), now)
+    }
+
+    /// Apply a reputation event as of `now` under the given weights
+    pub fn apply_event_with(&mut self, event: ReputationEvent, weights: &ReputationWeights, now: u64) -> Result<i32, ReputationError> {
+        self.check_order(now)?;
+        let delta = self.push_event(event, weights, now);
+        self.trim_history(DEFAULT_HISTORY_LIMIT);
+        Ok(delta)
+    }
+
+    /// Reject an event older than the last update
+    fn check_order(&self, timestamp: u64) -> Result<(), ReputationError> {
+        if timestamp < self.last_updated {
+            return Err(ReputationError::OutOfOrderEvent {
+                agent_id: self.agent_id.clone(),
+                timestamp,
+                last_updated: self.last_updated,
+            });
+        }
+        Ok(())
+    }
+
+    /// Apply an event without trimming history
+    fn push_event(&mut self, event: ReputationEvent, weights: &ReputationWeights, now: u64) -> i32 {
+        let delta = event.reputation_delta_with(weights);
+        self.push_delta(event, delta, None, None, None, now)
+    }
+
+    /// Record an event with an already computed delta
+    ///
+    /// The delta moves the event's subscores. The headline score moves by
+    /// it too, or is recombined from the subscores under `composition`.
+    fn push_delta(
+        &mut self,
+        event: ReputationEvent,
+        delta: i32,
+        clamped_from: Option<i32>,
+        weighted_from: Option<i32>,
+        composition: Option<&DimensionWeights>,
+        now: u64,
+    ) -> i32 {
+        for &dimension in event.dimensions() {
+            let subscore = self.subscores.get_mut(dimension);
+            *subscore = self.bounds.apply(*subscore, delta);
+        }
+        let new_score = match composition {
+            Some(weights) => weights.combine(&self.subscores),
+            None => self.bounds.apply(self.score, delta),
+        };
+
+        *self.event_counts.entry(event.kind()).or_default() += 1;
 
         // Record event
         let record = ReputationEventRecord {
@@ -201,140 +935,2883 @@ impl ReputationRecord {
             delta,
             score_after: new_score,
             timestamp: now,
+            clamped_from,
+            weighted_from,
+            idempotency_key: None,
+            event_id: self.next_event_id,
+            reversed_by: None,
         };
 
-        // Keep last 100 events
-        if self.history.len() >= 100 {
-            self.history.remove(0);
-        }
-        self.history.push(record);
+        self.history.push_back(record);
+        self.next_event_id += 1;
 
         self.score = new_score;
-        self.level = ReputationLevel::from_score(new_score);
+        self.level = self.bounds.level(new_score);
+        self.highest_level_achieved = self.highest_level_achieved.max(self.level);
         self.total_events += 1;
-        self.last_updated = now;
+        self.last_updated = self.last_updated.max(now);
 
         delta
     }
 
-    /// Get recent history
-    pub fn recent_history(&self, count: usize) -> &[ReputationEventRecord] {
-        let start = self.history.len().saturating_sub(count);
-        &self.history[start..]
+    /// Decay the score for the time since the last update
+    ///
+    /// Records a `Decay` event and returns its (negative) delta, or zero if
+    /// the score didn't change. Nothing is recorded in that case, so idle
+    /// time keeps accumulating until it amounts to a whole point.
+    pub fn apply_decay(&mut self, policy: &DecayPolicy, now: u64) -> i32 {
+        let delta = self.push_decay(policy, 0, None, now);
+        self.trim_history(DEFAULT_HISTORY_LIMIT);
+        delta
     }
 
-    /// Calculate 7-day trend
-    pub fn weekly_trend(&self) -> i32 {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let week_ago = now - 604_800;
+    /// Apply decay without trimming history, stopping at `floor`
+    fn push_decay(&mut self, policy: &DecayPolicy, floor: u32, composition: Option<&DimensionWeights>, now: u64) -> i32 {
+        let idle_secs = now.saturating_sub(self.last_updated);
+        let decayed = policy.decayed_score(self.score, idle_secs).max(floor);
+        if decayed >= self.score {
+            return 0;
+        }
+        let amount = (self.score - decayed) as i32;
+        self.push_delta(ReputationEvent::Decay { idle_secs, amount }, -amount, None, None, composition, now)
+    }
 
-        self.history
-            .iter()
-            .filter(|r| r.timestamp >= week_ago)
-            .map(|r| r.delta)
-            .sum()
+    /// Leaderboard order: score descending, then `total_events` descending,
+    /// then agent ID ascending
+    ///
+    /// A total order, so the leaderboard is the same on every call and in
+    /// every process holding the same records.
+    pub(crate) fn leaderboard_cmp(&self, other: &ReputationRecord) -> std::cmp::Ordering {
+        other.score.cmp(&self.score)
+            .then_with(|| other.total_events.cmp(&self.total_events))
+            .then_with(|| self.agent_id.cmp(&other.agent_id))
     }
-}
 
-/// Reputation tracker for multiple agents
-pub struct ReputationTracker {
-    /// Agent records
-    records: HashMap<String, ReputationRecord>,
-    /// Leaderboard cache
-    leaderboard_cache: Vec<String>,
-    /// Last leaderboard update
-    leaderboard_updated: u64,
-}
+    /// Whether this record should replace `other` for the same agent
+    ///
+    /// The later update wins. Ties go to the higher score, then to the
+    /// record with more events, and otherwise keep `other`.
+    fn supersedes(&self, other: &ReputationRecord) -> bool {
+        (self.last_updated, self.score, self.total_events) > (other.last_updated, other.score, other.total_events)
+    }
 
-impl ReputationTracker {
-    /// Create new tracker
-    pub fn new() -> Self {
-        Self {
-            records: HashMap::new(),
-            leaderboard_cache: Vec::new(),
-            leaderboard_updated: 0,
+    /// Drop the oldest events beyond `limit`, returning them oldest first
+    fn trim_history(&mut self, limit: usize) -> Vec<ReputationEventRecord> {
+        let excess = self.history.len().saturating_sub(limit);
+        let evicted: Vec<ReputationEventRecord> = self.history.drain(..excess).collect();
+        for record in &evicted {
+            self.checkpoint(record);
         }
+        evicted
     }
 
-    /// Get or create record for agent
-    pub fn get_or_create(&mut self, agent_id: &str) -> &mut ReputationRecord {
-        self.records.entry(agent_id.to_string())
-            .or_insert_with(|| ReputationRecord::new(agent_id.to_string()))
+    /// Score as of `timestamp`
+    ///
+    /// Exact within the retained history. Earlier times are answered from
+    /// daily checkpoints, so they may miss changes made later the same day.
+    /// Times before any event get the starting score.
+    pub fn score_at(&self, timestamp: u64) -> u32 {
+        if let Some(record) = self.history.iter().rev().find(|r| r.timestamp <= timestamp) {
+            return record.score_after;
+        }
+        self.checkpoints.iter()
+            .rev()
+            .find(|c| c.timestamp <= timestamp)
+            .map_or(self.bounds.start, |c| c.score)
     }
 
-    /// Get record for agent
-    pub fn get(&self, agent_id: &str) -> Option<&ReputationRecord> {
-        self.records.get(agent_id)
+    /// Largest part of a gain `delta` for `kind` that fits the rate limit
+    ///
+    /// Drops gains that have left the window; `record_rate_limited_gain`
+    /// adds the ones let through.
+    fn rate_limited(&mut self, kind: ReputationEventKind, delta: i32, limit: &RateLimit, now: u64) -> i32 {
+        let start = now.saturating_sub(limit.window_secs);
+        let gains = self.rate_limited_gains.entry(kind).or_default();
+        while gains.front().is_some_and(|&(timestamp, _)| timestamp <= start) {
+            gains.pop_front();
+        }
+        if delta <= 0 {
+            return delta;
+        }
+        if limit.max_events.is_some_and(|max| gains.len() >= max as usize) {
+            return 0;
+        }
+        match limit.max_points {
+            Some(max) => {
+                let gained = gains.iter().fold(0i32, |sum, &(_, points)| sum.saturating_add(points));
+                delta.min(i32::try_from(max).unwrap_or(i32::MAX).saturating_sub(gained).max(0))
+            }
+            None => delta,
+        }
     }
 
-    /// Apply event to agent
-    pub fn apply_event(&mut self, agent_id: &str, event: ReputationEvent) -> i32 {
-        self.get_or_create(agent_id).apply_event(event)
+    /// Count a gain against `kind`'s rate limit
+    fn record_rate_limited_gain(&mut self, kind: ReputationEventKind, delta: i32, now: u64) {
+        if delta > 0 {
+            self.rate_limited_gains.entry(kind).or_default().push_back((now, delta));
+        }
     }
 
-    /// Get agent score
-    pub fn score(&self, agent_id: &str) -> u32 {
-        self.records.get(agent_id).map(|r| r.score).unwrap_or(0)
+    /// Fold an event dropped from history into the checkpoints
+    fn checkpoint(&mut self, evicted: &ReputationEventRecord) {
+        let checkpoint = ScoreCheckpoint {
+            timestamp: evicted.timestamp,
+            score: evicted.score_after,
+        };
+        match self.checkpoints.last_mut() {
+            Some(last) if last.timestamp / CHECKPOINT_INTERVAL_SECS == evicted.timestamp / CHECKPOINT_INTERVAL_SECS => {
+                *last = checkpoint;
+            }
+            _ => self.checkpoints.push(checkpoint),
+        }
     }
 
-    /// Get agent level
-    pub fn level(&self, agent_id: &str) -> ReputationLevel {
-        self.records.get(agent_id)
-            .map(|r| r.level)
-            .unwrap_or(ReputationLevel::Newcomer)
+    /// Check each event's delta and `score_after` follow from the event
+    /// and the one before, under the default weights
+    ///
+    /// Retained history is replayed from the last checkpoint, or from the
+    /// starting score if nothing has been dropped, and the result must be
+    /// the current score. Records imported from CSV start from a
+    /// checkpoint at their imported score. A mismatch means the record was
+    /// edited. Only meaningful for running-total scores, not ones
+    /// recombined from subscores.
+    pub fn verify_consistency(&self) -> Result<(), ReputationError> {
+        self.verify_consistency_with(&ReputationWeights::default())
     }
 
-    /// Get leaderboard (top N agents)
-    pub fn leaderboard(&mut self, count: usize) -> Vec<(&String, &ReputationRecord)> {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        // Refresh cache every 60 seconds
-        if now - self.leaderboard_updated > 60 {
-            let mut sorted: Vec<_> = self.records.iter().collect();
-            sorted.sort_by(|a, b| b.1.score.cmp(&a.1.score));
-            self.leaderboard_cache = sorted.iter().map(|(id, _)| (*id).clone()).collect();
-            self.leaderboard_updated = now;
+    /// Like `verify_consistency`, for events scored under `weights`
+    ///
+    /// An event's delta must be what `weights` give it, or if it was
+    /// scaled or cut, the delta it records it would have had.
+    pub fn verify_consistency_with(&self, weights: &ReputationWeights) -> Result<(), ReputationError> {
+        let mut score = self.checkpoints.last().map_or(self.bounds.start, |c| c.score);
+        for (index, event) in self.history.iter().enumerate() {
+            let expected = event.event.reputation_delta_with(weights);
+            let unadjusted = event.weighted_from.or(event.clamped_from).unwrap_or(event.delta);
+            if unadjusted != expected {
+                return Err(ReputationError::UnexpectedDelta {
+                    agent_id: self.agent_id.clone(),
+                    index,
+                    expected,
+                    recorded: unadjusted,
+                });
+            }
+            score = self.bounds.apply(score, event.delta);
+            if event.score_after != score {
+                return Err(ReputationError::InconsistentHistory {
+                    agent_id: self.agent_id.clone(),
+                    index: Some(index),
+                    expected: score,
+                    recorded: event.score_after,
+                });
+            }
         }
-
-        self.leaderboard_cache
-            .iter()
-            .take(count)
-            .filter_map(|id| self.records.get(id).map(|r| (id, r)))
-            .collect()
+        if self.score != score {
+            return Err(ReputationError::InconsistentHistory {
+                agent_id: self.agent_id.clone(),
+                index: None,
+                expected: score,
+                recorded: self.score,
+            });
+        }
+        Ok(())
     }
 
-    /// Get agents at or above level
-    pub fn agents_at_level(&self, min_level: ReputationLevel) -> Vec<&ReputationRecord> {
-        self.records
-            .values()
-            .filter(|r| r.level >= min_level)
-            .collect()
+    /// Get recent history, oldest first
+    pub fn recent_history(&self, count: usize) -> vec_deque::Iter<'_, ReputationEventRecord> {
+        let start = self.history.len().saturating_sub(count);
+        self.history.range(start..)
     }
 
-    /// Get agents in score range
-    pub fn agents_in_range(&self, min: u32, max: u32) -> Vec<&ReputationRecord> {
-        self.records
-            .values()
-            .filter(|r| r.score >= min && r.score <= max)
-            .collect()
+    /// Calculate 7-day trend
+    pub fn weekly_trend(&self) -> i32 {
+        self.trend(604_800, SystemClock.now()).value
     }
 
-    /// Total tracked agents
-    pub fn agent_count(&self) -> usize {
-        self.records.len()
+    /// Net score change over the `window_secs` up to `now`
+    pub fn trend(&self, window_secs: u64, now: u64) -> Windowed<i32> {
+        let (events, partial) = self.window(window_secs, now);
+        Windowed {
+            value: events.map(|r| r.delta).sum(),
+            partial,
+        }
+    }
+
+    /// When the record reached its current level
+    ///
+    /// A record that never left its starting level has been there since
+    /// its first event. Partial if the level was reached before the
+    /// retained history, in which case this is the oldest retained event.
+    pub fn level_since(&self) -> Windowed<u64> {
+        let mut since = self.last_updated;
+        for event in self.history.iter().rev() {
+            if self.bounds.level(event.score_after) != self.level {
+                return Windowed { value: since, partial: false };
+            }
+            since = event.timestamp;
+        }
+        let partial = self.checkpoints.last().is_some_and(|c| self.bounds.level(c.score) == self.level);
+        Windowed { value: since, partial }
+    }
+
+    /// Score changes over the window, in consecutive `bucket_secs` buckets
+    ///
+    /// The first bucket starts at the beginning of the window; the last
+    /// one also takes events at exactly `now`. Empty if `bucket_secs` is
+    /// zero.
+    pub fn moving_average(&self, window_secs: u64, bucket_secs: u64, now: u64) -> Windowed<Vec<TrendBucket>> {
+        let (events, partial) = self.window(window_secs, now);
+        if bucket_secs == 0 {
+            return Windowed { value: Vec::new(), partial };
+        }
+        let start = now.saturating_sub(window_secs);
+        let count = (now - start).div_ceil(bucket_secs).max(1) as usize;
+        let mut buckets: Vec<TrendBucket> = (0..count)
+            .map(|i| TrendBucket {
+                start: start + i as u64 * bucket_secs,
+                change: 0,
+                events: 0,
+                average_delta: 0.0,
+            })
+            .collect();
+        for record in events {
+            let index = (((record.timestamp - start) / bucket_secs) as usize).min(count - 1);
+            buckets[index].change += record.delta;
+            buckets[index].events += 1;
+        }
+        for bucket in &mut buckets {
+            if bucket.events > 0 {
+                bucket.average_delta = bucket.change as f64 / bucket.events as f64;
+            }
+        }
+        Windowed { value: buckets, partial }
+    }
+
+    /// Standard deviation of event deltas over the window
+    pub fn volatility(&self, window_secs: u64, now: u64) -> Windowed<f64> {
+        let (events, partial) = self.window(window_secs, now);
+        let deltas: Vec<f64> = events.map(|r| r.delta as f64).collect();
+        if deltas.is_empty() {
+            return Windowed { value: 0.0, partial };
+        }
+        let mean = deltas.iter().sum::<f64>() / deltas.len() as f64;
+        let variance = deltas.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / deltas.len() as f64;
+        Windowed { value: variance.sqrt(), partial }
+    }
+
+    /// Retained events in the window, and whether older events it covers were dropped
+    fn window(&self, window_secs: u64, now: u64) -> (impl Iterator<Item = &ReputationEventRecord>, bool) {
+        let start = now.saturating_sub(window_secs);
+        let dropped = self.total_events > self.history.len() as u64;
+        let partial = dropped && self.history.front().map_or(true, |oldest| start < oldest.timestamp);
+        let events = self.history.iter().filter(move |r| r.timestamp >= start && r.timestamp <= now);
+        (events, partial)
+    }
+}
+
+/// Result computed over a time window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Windowed<T> {
+    /// Computed value
+    pub value: T,
+    /// Whether the window reaches past the retained history
+    pub partial: bool,
+}
+
+/// Score changes within one bucket of a time series
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrendBucket {
+    /// Bucket start timestamp
+    pub start: u64,
+    /// Net score change
+    pub change: i32,
+    /// Events in the bucket
+    pub events: usize,
+    /// Mean delta per event (zero without events)
+    pub average_delta: f64,
+}
+
+/// An agent's net score change over a window
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentTrend {
+    /// Agent ID
+    pub agent_id: String,
+    /// Net score change
+    pub change: i32,
+    /// Whether the window reaches past the agent's retained history
+    pub partial: bool,
+}
+
+/// Receives events dropped from an agent's history
+type Archiver = Box<dyn FnMut(&str, ReputationEventRecord) + Send + Sync>;
+
+/// Idempotency keys by agent, with the time each was first used
+type SeenKeys = HashMap<String, HashMap<String, u64>>;
+
+/// Scoped records, by context ID then agent ID
+type Contexts = HashMap<String, HashMap<String, ReputationRecord>>;
+
+/// Context the context-free API works in
+pub const GLOBAL_CONTEXT: &str = "global";
+
+/// How `ReputationTracker::global_score` combines an agent's contexts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContextAggregation {
+    /// Mean score, weighted per context; unlisted contexts weigh 1
+    WeightedMean(HashMap<String, u32>),
+    /// Highest score in any context
+    Max,
+}
+
+impl Default for ContextAggregation {
+    fn default() -> Self {
+        Self::WeightedMean(HashMap::new())
+    }
+}
+
+/// Current version of the saved tracker format
+const FORMAT_VERSION: u32 = 1;
+
+/// Saved tracker state
+#[derive(Serialize, Deserialize)]
+struct SavedTracker {
+    /// Format version
+    version: u32,
+    /// Agent records, ordered by agent ID
+    records: Vec<ReputationRecord>,
+    /// Idempotency keys still inside the dedup window
+    #[serde(default)]
+    seen_keys: SeenKeys,
+    /// Endorsement points granted, by endorsee
+    #[serde(default)]
+    endorsements: HashMap<String, Vec<EndorsementGrant>>,
+    /// Unfinished recovery plans, by agent
+    #[serde(default)]
+    recoveries: HashMap<String, RecoveryPlan>,
+    /// Scoped records, by context, each ordered by agent ID
+    #[serde(default)]
+    contexts: BTreeMap<String, Vec<ReputationRecord>>,
+    /// Attestation state last synced from the registry, by agent
+    #[serde(default)]
+    attestation_sync: HashMap<String, AttestationSyncState>,
+}
+
+/// Default time a cached leaderboard is reused
+const DEFAULT_LEADERBOARD_TTL_SECS: u64 = 60;
+
+/// Default time an idempotency key is remembered
+const DEFAULT_DEDUP_WINDOW_SECS: u64 = 86_400;
+
+/// Result of applying an event under an idempotency key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyOutcome {
+    /// The event was applied with this delta
+    Applied(i32),
+    /// The key was already used within the dedup window; nothing changed
+    AlreadyApplied {
+        /// When the event was first applied
+        applied_at: u64,
+    },
+}
+
+/// Event applied by `ReputationTracker::apply_event_full`
+#[derive(Debug, Clone)]
+pub struct AppliedEvent {
+    /// The event as recorded in the agent's history
+    pub record: ReputationEventRecord,
+    /// Agent's level after the event
+    pub level: ReputationLevel,
+    /// Whether the event moved the agent to a different level
+    pub level_changed: bool,
+}
+
+/// State dropped by `ReputationTracker::remove`
+#[derive(Debug, Clone, Default)]
+pub struct RemovedAgent {
+    /// Global record
+    pub record: Option<ReputationRecord>,
+    /// Records in other contexts, by context
+    pub context_records: HashMap<String, ReputationRecord>,
+    /// Endorsements the agent had received
+    pub endorsements_received: usize,
+    /// Recovery plan the agent was on
+    pub recovery: Option<RecoveryPlan>,
+}
+
+impl RemovedAgent {
+    /// Whether the tracker held no record for the agent in any context
+    pub fn is_empty(&self) -> bool {
+        self.record.is_none() && self.context_records.is_empty()
+    }
+}
+
+/// Window over which endorsements are capped and deduplicated
+const ENDORSEMENT_WINDOW_SECS: u64 = 30 * 86_400;
+
+/// Most points an agent can gain from endorsements per window
+const MAX_ENDORSEMENT_POINTS: i32 = 50;
+
+/// Largest weight an endorser can give
+const MAX_ENDORSEMENT_WEIGHT: u32 = 10;
+
+/// Oldest an endorsement can be when it's applied
+const MAX_ENDORSEMENT_AGE_SECS: u64 = 86_400;
+
+/// How far ahead of the tracker's clock an endorsement can be dated
+const MAX_ENDORSEMENT_CLOCK_SKEW_SECS: u64 = 300;
+
+/// One agent vouching for another, signed by the endorser
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Endorsement {
+    /// Endorsing agent ID
+    pub endorser: String,
+    /// Endorsed agent ID
+    pub endorsee: String,
+    /// Strength of the endorsement (1-10)
+    pub weight: u32,
+    /// When the endorsement was signed
+    pub timestamp: u64,
+    /// Ed25519 signature over `signing_message()` (hex)
+    pub signature: String,
+}
+
+impl Endorsement {
+    /// Sign an endorsement with the endorser's SDKey
+    pub fn sign(sdkey: &AgentSDKey, endorsee: &str, weight: u32, timestamp: u64) -> Result<Self, SDKeyError> {
+        let mut endorsement = Self {
+            endorser: sdkey.agent_id(),
+            endorsee: endorsee.to_string(),
+            weight,
+            timestamp,
+            signature: String::new(),
+        };
+        endorsement.signature = sdkey.sign_hex(&endorsement.signing_message())?;
+        Ok(endorsement)
+    }
+
+    /// Bytes covered by the signature
+    pub fn signing_message(&self) -> Vec<u8> {
+        format!(
+            "reputation-endorsement:{}:{}:{}:{}",
+            self.endorser,
+            self.endorsee,
+            self.weight,
+            self.timestamp
+        )
+        .into_bytes()
+    }
+
+    /// Check the signature against a public key
+    pub fn verify(&self, public_key: &[u8; 32]) -> Result<(), SDKeyError> {
+        let signature: [u8; 64] = hex::decode(&self.signature)
+            .map_err(|_| SDKeyError::InvalidHex)?
+            .try_into()
+            .map_err(|_| SDKeyError::InvalidSignature)?;
+        sdkey_manager::verify_signature(public_key, &self.signing_message(), &signature)
+    }
+}
+
+/// Domain tag at the start of attestation signing messages
+const ATTESTATION_TAG: &[u8] = b"reputation-attestation:v1";
+
+/// A tracker operator's signed statement of an agent's reputation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReputationAttestation {
+    /// Agent ID
+    pub agent_id: String,
+    /// Score when issued
+    pub score: u32,
+    /// Level when issued
+    pub level: ReputationLevel,
+    /// When the attestation was issued
+    pub issued_at: u64,
+    /// When the attestation stops being valid
+    pub expires_at: u64,
+    /// Issuer's public key (hex)
+    pub issuer_pubkey: String,
+    /// Ed25519 signature over `signing_message()` (hex)
+    pub signature: String,
+}
+
+impl ReputationAttestation {
+    /// Bytes covered by the signature
+    ///
+    /// A domain tag followed by each field in order; strings are prefixed
+    /// with their length and integers are little-endian, so no two
+    /// attestations share an encoding.
+    pub fn signing_message(&self) -> Vec<u8> {
+        let mut message = ATTESTATION_TAG.to_vec();
+        for text in [self.agent_id.as_str(), self.level.name()] {
+            message.extend_from_slice(&(text.len() as u32).to_le_bytes());
+            message.extend_from_slice(text.as_bytes());
+        }
+        message.extend_from_slice(&self.score.to_le_bytes());
+        message.extend_from_slice(&self.issued_at.to_le_bytes());
+        message.extend_from_slice(&self.expires_at.to_le_bytes());
+        message.extend_from_slice(&(self.issuer_pubkey.len() as u32).to_le_bytes());
+        message.extend_from_slice(self.issuer_pubkey.as_bytes());
+        message
+    }
+
+    /// Check the attestation was signed by `expected_issuer` and hasn't
+    /// expired
+    pub fn verify(&self, expected_issuer: &[u8; 32]) -> Result<(), ReputationError> {
+        self.verify_at(expected_issuer, SystemClock.now())
+    }
+
+    /// Check the attestation was signed by `expected_issuer` and is valid
+    /// at `now`
+    pub fn verify_at(&self, expected_issuer: &[u8; 32], now: u64) -> Result<(), ReputationError> {
+        if self.issuer_pubkey != hex::encode(expected_issuer) {
+            return Err(ReputationError::AttestationIssuerMismatch);
+        }
+        let signature: [u8; 64] = hex::decode(&self.signature)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ReputationError::InvalidAttestationSignature)?;
+        sdkey_manager::verify_signature(expected_issuer, &self.signing_message(), &signature)
+            .map_err(|_| ReputationError::InvalidAttestationSignature)?;
+        if now >= self.expires_at {
+            return Err(ReputationError::AttestationExpired { expires_at: self.expires_at });
+        }
+        Ok(())
+    }
+}
+
+/// Points granted to an endorsee
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EndorsementGrant {
+    /// Endorsing agent ID
+    endorser: String,
+    /// When the points were granted
+    granted_at: u64,
+    /// Points granted
+    points: i32,
+    /// Signature of the endorsement granting them
+    #[serde(default)]
+    signature: Vec<u8>,
+}
+
+/// Points per unit of endorsement weight for an endorser's level
+fn endorsement_multiplier(level: ReputationLevel) -> i32 {
+    match level {
+        ReputationLevel::Newcomer => 0,
+        ReputationLevel::Verified => 1,
+        ReputationLevel::Established => 2,
+        ReputationLevel::Trusted => 3,
+        ReputationLevel::Elite => 4,
+        ReputationLevel::Legendary => 5,
+    }
+}
+
+/// Highest possible score
+const MAX_SCORE: u32 = 1000;
+
+/// Score range and starting scores of a tracker's agents
+///
+/// The default reproduces the standard 0-1000 range starting at 100.
+/// Levels are fractions of `max_score`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackerConfig {
+    /// Lowest score
+    pub min_score: u32,
+    /// Highest score
+    pub max_score: u32,
+    /// Starting score of agents without a class
+    pub default_start: u32,
+    /// Starting scores, by agent class
+    pub classes: HashMap<String, u32>,
+}
+
+impl Default for TrackerConfig {
+    fn default() -> Self {
+        Self {
+            min_score: 0,
+            max_score: MAX_SCORE,
+            default_start: STARTING_SCORE,
+            classes: HashMap::new(),
+        }
+    }
+}
+
+impl TrackerConfig {
+    /// Add an agent class starting at `start`
+    pub fn with_class(mut self, class: &str, start: u32) -> Self {
+        self.classes.insert(class.to_string(), start);
+        self
+    }
+
+    /// Bounds of a new record starting at `start`
+    fn bounds(&self, start: u32) -> ScoreBounds {
+        ScoreBounds {
+            min: self.min_score,
+            max: self.max_score,
+            start,
+        }
+    }
+
+    /// Check the range is non-empty and every starting score is in it
+    fn validate(&self) -> Result<(), ReputationError> {
+        if self.min_score >= self.max_score {
+            return Err(ReputationError::InvalidConfig(format!(
+                "min_score {} must be below max_score {}",
+                self.min_score, self.max_score
+            )));
+        }
+        let range = self.min_score..=self.max_score;
+        if !range.contains(&self.default_start) {
+            return Err(ReputationError::InvalidConfig(format!("default_start {} is out of range", self.default_start)));
+        }
+        if let Some((class, start)) = self.classes.iter().find(|(_, start)| !range.contains(start)) {
+            return Err(ReputationError::InvalidConfig(format!("start {} of class {} is out of range", start, class)));
+        }
+        Ok(())
+    }
+}
+
+/// Summary of scores across all agents
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReputationDistribution {
+    /// Agents counted
+    pub agent_count: usize,
+    /// Agents per level
+    pub per_level: BTreeMap<ReputationLevel, usize>,
+    /// 10th percentile score
+    pub p10: u32,
+    /// Median score
+    pub p50: u32,
+    /// 90th percentile score
+    pub p90: u32,
+    /// 99th percentile score
+    pub p99: u32,
+    /// Mean score
+    pub mean: f64,
+    /// Lowest score
+    pub min: u32,
+    /// Highest score
+    pub max: u32,
+}
+
+/// Tracker health, for monitoring
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrackerMetrics {
+    /// Agents with a record
+    pub total_agents: usize,
+    /// Agents per level, every level included
+    pub agents_per_level: BTreeMap<ReputationLevel, usize>,
+    /// Retained events applied in the hour before the snapshot
+    pub events_last_hour: u64,
+    /// Mean score (zero with no agents)
+    pub mean_score: f64,
+    /// Time since the leaderboard cache was built, if it has been
+    pub leaderboard_cache_age_secs: Option<u64>,
+    /// Agents on probation
+    pub agents_on_probation: usize,
+    /// Agents slashed at least once
+    pub slashed_agents: usize,
+}
+
+impl TrackerMetrics {
+    /// Render in the Prometheus text exposition format
+    ///
+    /// Metric names are stable. The cache age is left out until the
+    /// leaderboard has been built.
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+        let mut gauge = |name: &str, help: &str, samples: &[(String, String)]| {
+            out.push_str(&format!("# HELP {} {}\n# TYPE {} gauge\n", name, help, name));
+            for (labels, value) in samples {
+                out.push_str(&format!("{}{} {}\n", name, labels, value));
+            }
+        };
+        let plain = |value: String| [(String::new(), value)];
+        gauge("reputation_agents", "Agents with a reputation record.", &plain(self.total_agents.to_string()));
+        let per_level: Vec<(String, String)> = ReputationLevel::ALL.iter()
+            .map(|level| (
+                format!("{{level=\"{}\"}}", level.name().to_lowercase()),
+                self.agents_per_level.get(level).copied().unwrap_or(0).to_string(),
+            ))
+            .collect();
+        gauge("reputation_agents_by_level", "Agents at each reputation level.", &per_level);
+        gauge("reputation_events_last_hour", "Reputation events applied in the last hour.", &plain(self.events_last_hour.to_string()));
+        gauge("reputation_mean_score", "Mean reputation score.", &plain(self.mean_score.to_string()));
+        if let Some(age) = self.leaderboard_cache_age_secs {
+            gauge("reputation_leaderboard_cache_age_seconds", "Age of the cached leaderboard.", &plain(age.to_string()));
+        }
+        gauge("reputation_agents_on_probation", "Agents on probation.", &plain(self.agents_on_probation.to_string()));
+        gauge("reputation_agents_slashed", "Agents slashed at least once.", &plain(self.slashed_agents.to_string()));
+        out
+    }
+}
+
+/// Agents with scores in `min..=max`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistogramBucket {
+    /// Lowest score in the bucket
+    pub min: u32,
+    /// Highest score in the bucket
+    pub max: u32,
+    /// Agents in the bucket
+    pub count: usize,
+}
+
+/// Field agent queries are ordered by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortBy {
+    #[default]
+    Score,
+    LastUpdated,
+    /// Net score change over the last 7 days
+    WeeklyTrend,
+}
+
+/// Direction of agent query ordering
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    #[default]
+    Descending,
+}
+
+/// Ordering and paging of agent queries
+///
+/// Ties are broken by agent ID ascending whatever the order, so paging is
+/// stable between calls. `limit: None` returns all remaining items.
+#[derive(Debug, Clone, Default)]
+pub struct AgentQuery {
+    pub sort_by: SortBy,
+    pub order: SortOrder,
+    pub offset: usize,
+    pub limit: Option<usize>,
+}
+
+/// Owned summary of an agent's record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentSummary {
+    /// Agent ID
+    pub agent_id: String,
+    /// Score
+    pub score: u32,
+    /// Level
+    pub level: ReputationLevel,
+    /// Last update timestamp
+    pub last_updated: u64,
+    /// Net score change over the last 7 days
+    pub weekly_trend: i32,
+    /// Whether the agent is on probation
+    pub on_probation: bool,
+    /// Highest level the agent has reached
+    pub highest_level_achieved: ReputationLevel,
+}
+
+/// One page of agent query results
+#[derive(Debug, Clone)]
+pub struct AgentPage {
+    /// Agents on this page
+    pub items: Vec<AgentSummary>,
+    /// Number of agents matching across all pages
+    pub total: usize,
+    /// Offset of the next page, if there is one
+    pub next_offset: Option<usize>,
+}
+
+/// Everything about one agent, from `ReputationTracker::report`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReputationReport {
+    /// Agent ID
+    pub agent_id: String,
+    /// Score
+    pub score: u32,
+    /// Level
+    pub level: ReputationLevel,
+    /// Leaderboard position (1 is the top)
+    pub rank: usize,
+    /// Percentage of agents scoring below (0-100)
+    pub percentile: f64,
+    /// Net score change over the last 7 days
+    pub weekly_trend: Windowed<i32>,
+    /// Net score change over the last 30 days
+    pub monthly_trend: Windowed<i32>,
+    /// Events processed per type
+    pub event_counts: BTreeMap<ReputationEventKind, u64>,
+    /// End of the agent's probation, if they're on it
+    pub on_probation_until: Option<u64>,
+    /// Last 10 events, oldest first
+    pub recent_events: Vec<ReputationEventRecord>,
+    /// Seconds since the agent reached their current level
+    pub time_at_level_secs: Windowed<u64>,
+    /// Timestamp the report was generated at
+    pub generated_at: u64,
+}
+
+/// Events included in a `ReputationReport`
+const REPORT_RECENT_EVENTS: usize = 10;
+
+/// Owned leaderboard position
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// Agent ID
+    pub agent_id: String,
+    /// Score
+    pub score: u32,
+    /// Level
+    pub level: ReputationLevel,
+    /// Position (1 is the top)
+    pub rank: usize,
+}
+
+/// An agent moving to a different level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelChange {
+    /// Agent ID
+    pub agent_id: String,
+    /// Level before the event
+    pub old_level: ReputationLevel,
+    /// Level after the event
+    pub new_level: ReputationLevel,
+    /// Event that caused the change
+    pub event: ReputationEvent,
+    /// Time of the event
+    pub timestamp: u64,
+}
+
+/// Bounds on event payloads, checked before events are applied
+///
+/// Payloads whose sign is backwards, like a slash with a negative amount,
+/// are always rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventValidator {
+    /// Largest `pnl_bps` or `loss_bps` magnitude
+    pub max_pnl_bps: i64,
+    /// Largest trade volume
+    pub max_volume_usd: u64,
+    /// Most hours in one uptime reward
+    pub max_uptime_hours: u32,
+    /// Largest slash or manual adjustment
+    pub max_amount: u32,
+    /// Whether slashes need a reason
+    pub require_slash_reason: bool,
+}
+
+impl Default for EventValidator {
+    fn default() -> Self {
+        Self::permissive()
+    }
+}
+
+impl EventValidator {
+    /// Only the sign checks, plus amounts within the default score range
+    pub fn permissive() -> Self {
+        Self::permissive_for(&TrackerConfig::default())
+    }
+
+    /// Only the sign checks, plus amounts within `config`'s score range
+    pub fn permissive_for(config: &TrackerConfig) -> Self {
+        Self {
+            max_pnl_bps: i64::MAX,
+            max_volume_usd: u64::MAX,
+            max_uptime_hours: u32::MAX,
+            max_amount: config.max_score - config.min_score,
+            require_slash_reason: false,
+        }
+    }
+
+    /// Bounds for production traffic
+    pub fn strict() -> Self {
+        Self {
+            max_pnl_bps: 100_000,
+            max_volume_usd: 1_000_000_000,
+            max_uptime_hours: 24 * 31,
+            max_amount: 500,
+            require_slash_reason: true,
+        }
+    }
+
+    /// Check an event against the bounds
+    pub fn validate(&self, event: &ReputationEvent) -> Result<(), ReputationError> {
+        let invalid = |field: &'static str, reason: String| {
+            Err(ReputationError::InvalidEvent { kind: event.kind(), field, reason })
+        };
+        let above = |value: u128, max: u128| format!("is {}, above the limit of {}", value, max);
+        let not_positive = |value: i64| format!("must be positive, got {}", value);
+        match event {
+            ReputationEvent::TradeSuccess { pnl_bps, volume_usd } => {
+                if pnl_bps.unsigned_abs() > self.max_pnl_bps.unsigned_abs() {
+                    return invalid("pnl_bps", above(pnl_bps.unsigned_abs() as u128, self.max_pnl_bps as u128));
+                }
+                if *volume_usd > self.max_volume_usd {
+                    return invalid("volume_usd", above(*volume_usd as u128, self.max_volume_usd as u128));
+                }
+            }
+            ReputationEvent::TradeFailed { loss_bps } => {
+                if *loss_bps < 0 {
+                    return invalid("loss_bps", format!("must not be negative, got {}", loss_bps));
+                }
+                if *loss_bps > self.max_pnl_bps {
+                    return invalid("loss_bps", above(*loss_bps as u128, self.max_pnl_bps as u128));
+                }
+            }
+            ReputationEvent::UptimeReward { hours } if *hours > self.max_uptime_hours => {
+                return invalid("hours", above(*hours as u128, self.max_uptime_hours as u128));
+            }
+            ReputationEvent::Slashed { reason, amount } => {
+                if *amount <= 0 {
+                    return invalid("amount", not_positive(*amount as i64));
+                }
+                if amount.unsigned_abs() > self.max_amount {
+                    return invalid("amount", above(*amount as u128, self.max_amount as u128));
+                }
+                if self.require_slash_reason && reason.trim().is_empty() {
+                    return invalid("reason", "must not be empty".into());
+                }
+            }
+            ReputationEvent::ManualAdjustment { amount, .. } if amount.unsigned_abs() > self.max_amount => {
+                return invalid("amount", above(amount.unsigned_abs() as u128, self.max_amount as u128));
+            }
+            ReputationEvent::Decay { amount, .. } if *amount < 0 => {
+                return invalid("amount", format!("must not be negative, got {}", amount));
+            }
+            ReputationEvent::Endorsed { points, .. } if *points <= 0 => {
+                return invalid("points", not_positive(*points as i64));
+            }
+            ReputationEvent::ScheduledRecovery { amount } if *amount <= 0 => {
+                return invalid("amount", not_positive(*amount as i64));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of `ReputationTracker::apply_batch`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchResult {
+    /// Delta applied by each event, in order
+    pub deltas: Vec<i32>,
+    /// Sum of `deltas`
+    pub total_delta: i32,
+    /// Score after the batch
+    pub final_score: u32,
+}
+
+/// How `ReputationTracker::merge` combines records both trackers hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Replay the other tracker's events on top of this one's, through
+    /// this tracker's weights, limits and probation
+    SumDeltas,
+    /// Keep the record updated most recently
+    TakeNewest,
+    /// Keep the record with the higher score
+    MaxScore,
+}
+
+/// Reputation tracker for multiple agents
+pub struct ReputationTracker {
+    /// Agent records
+    records: HashMap<String, ReputationRecord>,
+    /// Leaderboard cache
+    leaderboard_cache: Vec<String>,
+    /// Last leaderboard update
+    leaderboard_updated: u64,
+    /// Whether scores changed since the leaderboard was cached
+    leaderboard_dirty: bool,
+    /// How long a cached leaderboard is reused when nothing changed
+    leaderboard_ttl_secs: u64,
+    /// Inactivity decay applied by `apply_decay`
+    decay_policy: Option<DecayPolicy>,
+    /// Probation after heavy slashes, if enabled
+    probation_policy: Option<ProbationPolicy>,
+    /// Score range and starting scores
+    config: TrackerConfig,
+    /// Scaling of backfilled deltas by age
+    recency_weighting: Option<RecencyWeighting>,
+    /// Score floors, by the level that earns them
+    level_floors: HashMap<ReputationLevel, u32>,
+    /// Headline score composition, if not a running total
+    dimension_weights: Option<DimensionWeights>,
+    /// Bounds on event payloads
+    validator: EventValidator,
+    /// Idempotency keys by agent, with the time each was first used
+    seen_keys: SeenKeys,
+    /// Points per event family
+    weights: ReputationWeights,
+    /// Caps on gains per event type
+    rate_limits: HashMap<ReputationEventKind, RateLimit>,
+    /// Endorsement points granted, by endorsee
+    endorsements: HashMap<String, Vec<EndorsementGrant>>,
+    /// How long an idempotency key is remembered
+    dedup_window_secs: u64,
+    /// Events kept per agent
+    history_limit: usize,
+    /// Receives events dropped from history
+    archiver: Option<Archiver>,
+    /// Level change subscribers
+    subscribers: Vec<Sender<LevelChange>>,
+    /// Recovery plans, by agent
+    recoveries: HashMap<String, RecoveryPlan>,
+    /// Records scoped to contexts other than the global one
+    contexts: Contexts,
+    /// How `global_score` combines contexts
+    context_aggregation: ContextAggregation,
+    /// Attestation state last synced from the registry, by agent
+    attestation_sync: HashMap<String, AttestationSyncState>,
+    /// Time source
+    clock: Box<dyn Clock>,
+}
+
+impl ReputationTracker {
+    /// Create new tracker
+    pub fn new() -> Self {
+        Self {
+            records: HashMap::new(),
+            leaderboard_cache: Vec::new(),
+            leaderboard_updated: 0,
+            leaderboard_dirty: true,
+            leaderboard_ttl_secs: DEFAULT_LEADERBOARD_TTL_SECS,
+            decay_policy: None,
+            probation_policy: None,
+            config: TrackerConfig::default(),
+            recency_weighting: None,
+            level_floors: HashMap::new(),
+            dimension_weights: None,
+            validator: EventValidator::default(),
+            seen_keys: HashMap::new(),
+            weights: ReputationWeights::default(),
+            rate_limits: HashMap::new(),
+            endorsements: HashMap::new(),
+            dedup_window_secs: DEFAULT_DEDUP_WINDOW_SECS,
+            history_limit: DEFAULT_HISTORY_LIMIT,
+            archiver: None,
+            subscribers: Vec::new(),
+            recoveries: HashMap::new(),
+            contexts: HashMap::new(),
+            context_aggregation: ContextAggregation::default(),
+            attestation_sync: HashMap::new(),
+            clock: Box::new(SystemClock),
+        }
+    }
+
+    /// Use a different time source (e.g. `MockClock` in tests)
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Decay idle reputation under a policy
+    pub fn with_decay_policy(mut self, policy: DecayPolicy) -> Self {
+        self.decay_policy = Some(policy);
+        self
+    }
+
+    /// Create a tracker with a different score range or starting scores
+    pub fn with_config(config: TrackerConfig) -> Result<Self, ReputationError> {
+        config.validate()?;
+        Ok(Self {
+            validator: EventValidator::permissive_for(&config),
+            config,
+            ..Self::new()
+        })
+    }
+
+    /// Score range and starting scores
+    pub fn config(&self) -> &TrackerConfig {
+        &self.config
+    }
+
+    /// Scale deltas of events applied with past timestamps by their age
+    ///
+    /// Off by default, so every event counts in full. Slashes and manual
+    /// adjustments count in full regardless.
+    pub fn with_recency_weighting(mut self, weighting: RecencyWeighting) -> Self {
+        self.recency_weighting = Some(weighting);
+        self
+    }
+
+    /// Protect agents who've reached a level from falling below its floor
+    ///
+    /// Once an agent's `highest_level_achieved` is at or above a level in
+    /// `floors`, losses and decay stop at the highest such floor. Slashes
+    /// go through floors. With dimension weights, floors limit the deltas
+    /// but the recombined headline can still land below.
+    pub fn with_level_floors(mut self, floors: HashMap<ReputationLevel, u32>) -> Self {
+        self.level_floors = floors;
+        self
+    }
+
+    /// Score floor an agent has earned
+    fn floor_for(floors: &HashMap<ReputationLevel, u32>, record: &ReputationRecord) -> u32 {
+        floors.iter()
+            .filter(|(level, _)| **level <= record.highest_level_achieved)
+            .map(|(_, floor)| *floor)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Put heavily slashed agents on probation under a policy
+    pub fn with_probation_policy(mut self, policy: ProbationPolicy) -> Self {
+        self.probation_policy = Some(policy);
+        self
+    }
+
+    /// Combine contexts differently in `global_score` (an unweighted mean
+    /// by default)
+    pub fn with_context_aggregation(mut self, aggregation: ContextAggregation) -> Self {
+        self.context_aggregation = aggregation;
+        self
+    }
+
+    /// Check events against different bounds (permissive by default)
+    pub fn with_validator(mut self, validator: EventValidator) -> Self {
+        self.validator = validator;
+        self
+    }
+
+    /// Get event bounds
+    pub fn validator(&self) -> &EventValidator {
+        &self.validator
+    }
+
+    /// Compute headline scores as a weighted average of the subscores
+    ///
+    /// By default the headline score is a running total of event deltas.
+    /// Records keep their score until their next event.
+    pub fn with_dimension_weights(mut self, weights: DimensionWeights) -> Self {
+        self.dimension_weights = Some(weights);
+        self
+    }
+
+    /// Get headline score composition
+    pub fn dimension_weights(&self) -> Option<&DimensionWeights> {
+        self.dimension_weights.as_ref()
+    }
+
+    /// Reuse a cached leaderboard for up to `secs` (60 by default)
+    pub fn with_leaderboard_ttl(mut self, secs: u64) -> Self {
+        self.leaderboard_ttl_secs = secs;
+        self
+    }
+
+    /// Keep the last `limit` events per agent (100 by default)
+    ///
+    /// Older scores stay answerable through `score_at` checkpoints.
+    pub fn with_history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = limit;
+        self
+    }
+
+    /// Hand every event dropped from history to `archiver`, oldest first
+    pub fn with_archiver(mut self, archiver: impl FnMut(&str, ReputationEventRecord) + Send + Sync + 'static) -> Self {
+        self.archiver = Some(Box::new(archiver));
+        self
+    }
+
+    /// Score events with different weights
+    pub fn with_weights(mut self, weights: ReputationWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Cap the points agents can gain from an event type
+    pub fn with_rate_limit(mut self, kind: ReputationEventKind, limit: RateLimit) -> Self {
+        self.rate_limits.insert(kind, limit);
+        self
+    }
+
+    /// Get scoring weights
+    pub fn weights(&self) -> &ReputationWeights {
+        &self.weights
+    }
+
+    /// Remember idempotency keys for `secs` (one day by default)
+    pub fn with_dedup_window(mut self, secs: u64) -> Self {
+        self.dedup_window_secs = secs;
+        self
+    }
+
+    /// Receive a `LevelChange` whenever an event moves an agent to a
+    /// different level, up or down
+    ///
+    /// The channel is unbounded, so a slow subscriber never holds up the
+    /// tracker. Dropping the receiver unsubscribes.
+    pub fn subscribe(&mut self) -> Receiver<LevelChange> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    /// Get decay policy
+    pub fn decay_policy(&self) -> Option<&DecayPolicy> {
+        self.decay_policy.as_ref()
+    }
+
+    /// Get or create record for agent
+    ///
+    /// The record may be modified through the returned reference, so the
+    /// leaderboard is rebuilt on next use.
+    pub fn get_or_create(&mut self, agent_id: &str) -> &mut ReputationRecord {
+        let now = self.clock.now();
+        self.leaderboard_dirty = true;
+        let bounds = self.config.bounds(self.config.default_start);
+        self.records.entry(agent_id.to_string())
+            .or_insert_with(|| ReputationRecord::new_bounded(agent_id.to_string(), bounds, now))
+    }
+
+    /// Get or create record, starting a new one at `class`'s score
+    ///
+    /// Existing records are returned as they are.
+    pub fn get_or_create_with_class(&mut self, agent_id: &str, class: &str) -> Result<&mut ReputationRecord, ReputationError> {
+        let start = *self.config.classes.get(class)
+            .ok_or_else(|| ReputationError::UnknownAgentClass(class.to_string()))?;
+        let bounds = self.config.bounds(start);
+        let now = self.clock.now();
+        self.leaderboard_dirty = true;
+        Ok(self.records.entry(agent_id.to_string())
+            .or_insert_with(|| ReputationRecord::new_bounded(agent_id.to_string(), bounds, now)))
+    }
+
+    /// Get record for agent
+    pub fn get(&self, agent_id: &str) -> Option<&ReputationRecord> {
+        self.records.get(agent_id)
+    }
+
+    /// Forget an agent
+    ///
+    /// Drops their records in every context, idempotency keys, recovery
+    /// plan, attestation sync state and endorsements, both received and
+    /// given, even if they have no global record. Returns what was held
+    /// for the agent.
+    pub fn remove(&mut self, agent_id: &str) -> RemovedAgent {
+        let removed = RemovedAgent {
+            record: self.records.remove(agent_id),
+            context_records: self.contexts.iter_mut()
+                .filter_map(|(context, records)| records.remove(agent_id).map(|r| (context.clone(), r)))
+                .collect(),
+            endorsements_received: self.endorsements.get(agent_id).map_or(0, Vec::len),
+            recovery: self.recoveries.get(agent_id).copied(),
+        };
+        self.scrub(&HashSet::from([agent_id]));
+        removed
+    }
+
+    /// Forget every agent whose global record fails `keep`, as `remove`
+    /// does
+    ///
+    /// Returns the number of agents removed.
+    pub fn retain(&mut self, mut keep: impl FnMut(&ReputationRecord) -> bool) -> usize {
+        let removed: Vec<String> = self.records.values()
+            .filter(|record| !keep(record))
+            .map(|record| record.agent_id.clone())
+            .collect();
+        for agent_id in &removed {
+            self.records.remove(agent_id);
+        }
+        self.scrub(&removed.iter().map(String::as_str).collect());
+        removed.len()
+    }
+
+    /// Drop removed agents from the leaderboard and per-agent state
+    ///
+    /// The remaining leaderboard order is still valid, so it isn't rebuilt.
+    /// Endorsement grants a removed agent gave are kept, so they still count
+    /// toward the endorsee's cap.
+    fn scrub(&mut self, removed: &HashSet<&str>) {
+        self.leaderboard_cache.retain(|id| !removed.contains(id.as_str()));
+        for agent_id in removed {
+            self.seen_keys.remove(*agent_id);
+            self.endorsements.remove(*agent_id);
+            self.recoveries.remove(*agent_id);
+            self.attestation_sync.remove(*agent_id);
+        }
+        for records in self.contexts.values_mut() {
+            records.retain(|agent_id, _| !removed.contains(agent_id.as_str()));
+        }
+        self.contexts.retain(|_, records| !records.is_empty());
+    }
+
+    /// Attestation state last synced for an agent
+    pub(crate) fn attestation_sync_state(&self, agent_id: &str) -> Option<AttestationSyncState> {
+        self.attestation_sync.get(agent_id).copied()
+    }
+
+    /// Remember the attestation state synced for an agent
+    pub(crate) fn set_attestation_sync_state(&mut self, agent_id: &str, state: AttestationSyncState) {
+        self.attestation_sync.insert(agent_id.to_string(), state);
+    }
+
+    /// Apply event to agent, returning the delta
+    #[deprecated(note = "use `apply_event_full`, which also returns the score and level")]
+    pub fn apply_event(&mut self, agent_id: &str, event: ReputationEvent) -> Result<i32, ReputationError> {
+        self.apply_event_full(agent_id, event).map(|applied| applied.record.delta)
+    }
+
+    /// Apply event to agent
+    ///
+    /// Events failing the validator are rejected without being recorded.
+    pub fn apply_event_full(&mut self, agent_id: &str, event: ReputationEvent) -> Result<AppliedEvent, ReputationError> {
+        let now = self.clock.now();
+        self.check_event(agent_id, &event, now)?;
+        Ok(self.apply_recorded(agent_id, event, now))
+    }
+
+    /// Validate an event and check it's no older than the agent's last
+    /// update
+    fn check_event(&self, agent_id: &str, event: &ReputationEvent, timestamp: u64) -> Result<(), ReputationError> {
+        self.validator.validate(event)?;
+        match self.records.get(agent_id) {
+            Some(record) => record.check_order(timestamp),
+            None => Ok(()),
+        }
+    }
+
+    /// Apply event to agent as of `timestamp` rather than the clock's time,
+    /// e.g. to backfill history
+    ///
+    /// Events for an agent must come in time order, as with
+    /// `ReputationRecord::apply_event_at`.
+    pub fn apply_event_at(&mut self, agent_id: &str, event: ReputationEvent, timestamp: u64) -> Result<i32, ReputationError> {
+        self.check_event(agent_id, &event, timestamp)?;
+        Ok(self.apply_unchecked(agent_id, event, timestamp))
+    }
+
+    /// Rebuild reputation state by replaying a timestamped event stream
+    ///
+    /// Uses the default configuration; configure a tracker and `replay`
+    /// into it to rebuild under other weights or limits.
+    pub fn rebuild_from_events(
+        events: impl Iterator<Item = (String, ReputationEvent, u64)>,
+    ) -> Result<Self, ReputationError> {
+        let mut tracker = Self::new();
+        tracker.replay(events)?;
+        Ok(tracker)
+    }
+
+    /// Apply a timestamped event stream, in the order given
+    ///
+    /// Stops at the first invalid event, leaving the ones before it
+    /// applied. Returns the number of events applied.
+    pub fn replay(&mut self, events: impl Iterator<Item = (String, ReputationEvent, u64)>) -> Result<usize, ReputationError> {
+        let mut applied = 0;
+        for (agent_id, event, timestamp) in events {
+            self.apply_event_at(&agent_id, event, timestamp)?;
+            applied += 1;
+        }
+        Ok(applied)
+    }
+
+    /// Apply event to an agent's record in a context, e.g. a syndicate
+    ///
+    /// Each context keeps its own records, so events in one don't move the
+    /// agent's score in any other. `GLOBAL_CONTEXT` is the context-free
+    /// API's. Other contexts apply weights and the validator, but not rate
+    /// limits, probation or recovery plans, and don't notify subscribers.
+    pub fn apply_event_in_context(
+        &mut self,
+        agent_id: &str,
+        context_id: &str,
+        event: ReputationEvent,
+    ) -> Result<i32, ReputationError> {
+        if context_id == GLOBAL_CONTEXT {
+            return self.apply_event_at(agent_id, event, self.clock.now());
+        }
+        self.validator.validate(&event)?;
+        let now = self.clock.now();
+        let bounds = self.config.bounds(self.config.default_start);
+        let record = self.contexts.entry(context_id.to_string()).or_default()
+            .entry(agent_id.to_string())
+            .or_insert_with(|| ReputationRecord::new_bounded(agent_id.to_string(), bounds, now));
+        record.check_order(now)?;
+        let delta = event.reputation_delta_with(&self.weights);
+        let delta = record.push_delta(event, delta, None, None, self.dimension_weights.as_ref(), now);
+        record.trim_history(self.history_limit);
+        Ok(delta)
+    }
+
+    /// Get an agent's record in a context
+    pub fn get_in(&self, agent_id: &str, context_id: &str) -> Option<&ReputationRecord> {
+        if context_id == GLOBAL_CONTEXT {
+            return self.records.get(agent_id);
+        }
+        self.contexts.get(context_id)?.get(agent_id)
+    }
+
+    /// Get an agent's score in a context
+    pub fn score_in(&self, agent_id: &str, context_id: &str) -> u32 {
+        self.get_in(agent_id, context_id).map(|r| r.score).unwrap_or(0)
+    }
+
+    /// Combine an agent's scores across every context they have a record
+    /// in, the global one included
+    ///
+    /// `None` if they have none, or every one has zero weight.
+    pub fn global_score(&self, agent_id: &str) -> Option<u32> {
+        let scores = self.records.get(agent_id)
+            .map(|r| (GLOBAL_CONTEXT, r.score))
+            .into_iter()
+            .chain(self.contexts.iter().filter_map(|(context, records)| {
+                records.get(agent_id).map(|r| (context.as_str(), r.score))
+            }));
+        match &self.context_aggregation {
+            ContextAggregation::Max => scores.map(|(_, score)| score).max(),
+            ContextAggregation::WeightedMean(weights) => {
+                let (weighted, total) = scores.fold((0u64, 0u64), |(weighted, total), (context, score)| {
+                    let weight = weights.get(context).copied().unwrap_or(1) as u64;
+                    (weighted + weight * score as u64, total + weight)
+                });
+                (total > 0).then(|| ((weighted + total / 2) / total) as u32)
+            }
+        }
+    }
+
+    /// Leaderboard positions `offset..offset + limit` within a context, in
+    /// `leaderboard` order
+    pub fn leaderboard_in(&mut self, context_id: &str, offset: usize, limit: usize) -> Vec<LeaderboardEntry> {
+        if context_id == GLOBAL_CONTEXT {
+            return self.leaderboard_page(offset, limit);
+        }
+        let Some(records) = self.contexts.get(context_id) else {
+            return Vec::new();
+        };
+        let mut sorted: Vec<&ReputationRecord> = records.values().collect();
+        sorted.sort_by(|a, b| a.leaderboard_cmp(b));
+        sorted.into_iter()
+            .enumerate()
+            .skip(offset)
+            .take(limit)
+            .map(|(index, r)| LeaderboardEntry {
+                agent_id: r.agent_id.clone(),
+                score: r.score,
+                level: r.level,
+                rank: index + 1,
+            })
+            .collect()
+    }
+
+    /// Apply event to agent as of `now` without validating it
+    fn apply_unchecked(&mut self, agent_id: &str, event: ReputationEvent, now: u64) -> i32 {
+        self.apply_recorded(agent_id, event, now).record.delta
+    }
+
+    /// Apply event to agent as of `now` without validating it, returning
+    /// the event as recorded
+    fn apply_recorded(&mut self, agent_id: &str, event: ReputationEvent, now: u64) -> AppliedEvent {
+        self.leaderboard_dirty = true;
+        let bounds = self.config.bounds(self.config.default_start);
+        let record = self.records.entry(agent_id.to_string())
+            .or_insert_with(|| ReputationRecord::new_bounded(agent_id.to_string(), bounds, now));
+        let mut full = event.reputation_delta_with(&self.weights);
+        let mut weighted_from = None;
+        if let Some(policy) = &self.recency_weighting {
+            let exempt = matches!(event, ReputationEvent::Slashed { .. } | ReputationEvent::ManualAdjustment { .. });
+            let weight = policy.weight_bps(self.clock.now().saturating_sub(now));
+            if !exempt && weight != 10_000 {
+                weighted_from = Some(full);
+                full = (full as i64 * weight as i64 / 10_000) as i32;
+            }
+        }
+        let unadjusted = full;
+        let recovery = matches!(event, ReputationEvent::ScheduledRecovery { .. });
+        if let Some(policy) = &self.probation_policy {
+            if full > 0 && !recovery && record.is_on_probation_at(now) {
+                full = (full as i64 * policy.gain_multiplier_bps as i64 / 10_000) as i32;
+            }
+        }
+        let requested_slash = match &event {
+            ReputationEvent::Slashed { amount, .. } => Some(*amount),
+            _ => None,
+        };
+        let slashed = requested_slash.is_some();
+        let kind = event.kind();
+        let rate_limit = self.rate_limits.get(&kind);
+        let mut delta = match rate_limit {
+            Some(limit) => record.rate_limited(kind, full, limit, now),
+            None => full,
+        };
+        if delta < 0 && !slashed {
+            let floor = Self::floor_for(&self.level_floors, record);
+            delta = delta.max(floor as i32 - record.score as i32).min(0);
+        }
+        let clamped_from = (delta != unadjusted).then_some(unadjusted);
+        let old_level = record.level;
+        let delta = record.push_delta(event, delta, clamped_from, weighted_from, self.dimension_weights.as_ref(), now);
+        if rate_limit.is_some() {
+            record.record_rate_limited_gain(kind, delta, now);
+        }
+        let applied = AppliedEvent {
+            record: record.history.back().cloned().expect("event was just recorded"),
+            level: record.level,
+            level_changed: record.level != old_level,
+        };
+        if let Some(policy) = &self.probation_policy {
+            if requested_slash.is_some_and(|amount| amount > 0 && amount.unsigned_abs() >= policy.min_slash) {
+                // A slash during probation adds to what's left of it
+                let start = record.on_probation_until.map_or(now, |until| until.max(now));
+                record.on_probation_until = Some(start + policy.duration_secs);
+            }
+        }
+        if slashed {
+            if let Some(plan) = self.recoveries.get_mut(agent_id) {
+                plan.paused_at.get_or_insert(now);
+            }
+        }
+        Self::notify(&mut self.subscribers, record, old_level);
+        Self::trim_history(record, self.history_limit, &mut self.archiver);
+        applied
+    }
+
+    /// Apply events to an agent all together or not at all
+    ///
+    /// The agent's record is snapshotted first and restored if any event is
+    /// rejected, by the validator or for being older than the record's last
+    /// update, so a failed batch leaves the tracker as it was and can be
+    /// retried whole. Level changes are sent to subscribers, and trimmed
+    /// history archived, only once the whole batch has applied.
+    pub fn apply_batch(&mut self, agent_id: &str, events: Vec<ReputationEvent>) -> Result<BatchResult, BatchError> {
+        let record = self.records.get(agent_id).cloned();
+        let recovery = self.recoveries.get(agent_id).copied();
+        let leaderboard_dirty = self.leaderboard_dirty;
+        let subscribers = std::mem::take(&mut self.subscribers);
+        let history_limit = std::mem::replace(&mut self.history_limit, usize::MAX);
+
+        let now = self.clock.now();
+        let mut deltas = Vec::with_capacity(events.len());
+        let mut changes = Vec::new();
+        let mut failure = None;
+        for (index, event) in events.into_iter().enumerate() {
+            let checked = self.validator.validate(&event)
+                .and_then(|()| self.records.get(agent_id).map_or(Ok(()), |r| r.check_order(now)));
+            if let Err(error) = checked {
+                failure = Some(BatchError::InvalidEvent { index, error });
+                break;
+            }
+            let bounds = self.config.bounds(self.config.default_start);
+            let old_level = self.records.get(agent_id).map_or(bounds.level(bounds.start), |r| r.level);
+            deltas.push(self.apply_unchecked(agent_id, event, now));
+            changes.extend(Self::level_change(&self.records[agent_id], old_level));
+        }
+
+        self.subscribers = subscribers;
+        self.history_limit = history_limit;
+        if let Some(error) = failure {
+            match record {
+                Some(record) => self.records.insert(agent_id.to_string(), record),
+                None => self.records.remove(agent_id),
+            };
+            if let Some(plan) = recovery {
+                self.recoveries.insert(agent_id.to_string(), plan);
+            }
+            self.leaderboard_dirty = leaderboard_dirty;
+            return Err(error);
+        }
+        for change in changes {
+            self.subscribers.retain(|subscriber| subscriber.send(change.clone()).is_ok());
+        }
+        if let Some(record) = self.records.get_mut(agent_id) {
+            Self::trim_history(record, self.history_limit, &mut self.archiver);
+        }
+        Ok(BatchResult {
+            total_delta: deltas.iter().sum(),
+            deltas,
+            final_score: self.score(agent_id),
+        })
+    }
+
+    /// Events of a kind an agent has had processed
+    pub fn event_count(&self, agent_id: &str, kind: ReputationEventKind) -> u64 {
+        self.records.get(agent_id).map_or(0, |r| r.event_count(kind))
+    }
+
+    /// Highest level an agent has reached
+    pub fn highest_level(&self, agent_id: &str) -> Option<ReputationLevel> {
+        self.records.get(agent_id).map(|r| r.highest_level_achieved)
+    }
+
+    /// Whether an agent is on probation
+    pub fn is_on_probation(&self, agent_id: &str) -> bool {
+        let now = self.clock.now();
+        self.records.get(agent_id).is_some_and(|r| r.is_on_probation_at(now))
+    }
+
+    /// Lift an agent's probation early, recording `reason` in their history
+    pub fn clear_probation(&mut self, agent_id: &str, reason: &str) -> Result<(), ReputationError> {
+        let now = self.clock.now();
+        let record = self.records.get_mut(agent_id)
+            .filter(|r| r.is_on_probation_at(now))
+            .ok_or_else(|| ReputationError::NotOnProbation(agent_id.to_string()))?;
+        record.on_probation_until = None;
+        let event = ReputationEvent::ProbationCleared { reason: reason.to_string() };
+        record.push_delta(event, 0, None, None, self.dimension_weights.as_ref(), now);
+        Self::trim_history(record, self.history_limit, &mut self.archiver);
+        Ok(())
+    }
+
+    /// Undo an event, e.g. a slash overturned on appeal
+    ///
+    /// Records a `Reversal` moving the score back by what the event
+    /// actually changed it by, so a slash cut short at the minimum score
+    /// only gives back what it took. Reversals aren't limited, weighted or
+    /// floored, and leave probation for `clear_probation`. Each event can
+    /// be reversed once, while it's still in the retained history.
+    pub fn reverse_event(&mut self, agent_id: &str, event_id: u64, reason: &str) -> Result<AppliedEvent, ReputationError> {
+        let now = self.clock.now();
+        let record = self.records.get_mut(agent_id)
+            .ok_or_else(|| ReputationError::AgentNotFound(agent_id.to_string()))?;
+        let index = record.history.iter()
+            .position(|e| e.event_id == event_id)
+            .ok_or_else(|| ReputationError::EventNotFound { agent_id: agent_id.to_string(), event_id })?;
+        let original = &record.history[index];
+        if let Some(reversed_by) = original.reversed_by {
+            return Err(ReputationError::AlreadyReversed { event_id, reversed_by });
+        }
+        if original.event.kind() == ReputationEventKind::Reversal {
+            return Err(ReputationError::CannotReverseReversal(event_id));
+        }
+        let score_before = match index {
+            0 => record.checkpoints.last().map_or(record.bounds.start, |c| c.score),
+            _ => record.history[index - 1].score_after,
+        };
+        let amount = score_before as i32 - original.score_after as i32;
+
+        let old_level = record.level;
+        let event = ReputationEvent::Reversal { event_id, reason: reason.to_string(), amount };
+        record.push_delta(event, amount, None, None, self.dimension_weights.as_ref(), now);
+        let reversal = record.history.back().cloned().expect("event was just recorded");
+        record.history[index].reversed_by = Some(reversal.event_id);
+        let applied = AppliedEvent {
+            record: reversal,
+            level: record.level,
+            level_changed: record.level != old_level,
+        };
+        Self::notify(&mut self.subscribers, record, old_level);
+        Self::trim_history(record, self.history_limit, &mut self.archiver);
+        Ok(applied)
+    }
+
+    /// An agent's retained reversals, oldest first
+    pub fn reversals(&self, agent_id: &str) -> Vec<&ReputationEventRecord> {
+        self.records.get(agent_id)
+            .map(|r| r.history.iter().filter(|e| e.event.kind() == ReputationEventKind::Reversal).collect())
+            .unwrap_or_default()
+    }
+
+    /// Get agents currently on probation
+    pub fn agents_on_probation(&self) -> Vec<&ReputationRecord> {
+        let now = self.clock.now();
+        self.records
+            .values()
+            .filter(|r| r.is_on_probation_at(now))
+            .collect()
+    }
+
+    /// Tell subscribers about a level change made by the record's last event
+    fn notify(subscribers: &mut Vec<Sender<LevelChange>>, record: &ReputationRecord, old_level: ReputationLevel) {
+        if subscribers.is_empty() {
+            return;
+        }
+        if let Some(change) = Self::level_change(record, old_level) {
+            subscribers.retain(|subscriber| subscriber.send(change.clone()).is_ok());
+        }
+    }
+
+    /// Level change made by the record's last event, if any
+    fn level_change(record: &ReputationRecord, old_level: ReputationLevel) -> Option<LevelChange> {
+        if record.level == old_level {
+            return None;
+        }
+        let last = record.history.back()?;
+        Some(LevelChange {
+            agent_id: record.agent_id.clone(),
+            old_level,
+            new_level: record.level,
+            event: last.event.clone(),
+            timestamp: last.timestamp,
+        })
+    }
+
+    /// Enforce the history limit on a record, archiving what's dropped
+    fn trim_history(record: &mut ReputationRecord, limit: usize, archiver: &mut Option<Archiver>) {
+        let evicted = record.trim_history(limit);
+        if let Some(archive) = archiver {
+            for event in evicted {
+                archive(&record.agent_id, event);
+            }
+        }
+    }
+
+    /// Apply event to agent unless `idempotency_key` was already used
+    ///
+    /// Keys are remembered per agent for the dedup window, so a retried
+    /// message is applied once.
+    pub fn apply_event_with_key(
+        &mut self,
+        agent_id: &str,
+        event: ReputationEvent,
+        idempotency_key: &str,
+    ) -> Result<ApplyOutcome, ReputationError> {
+        let now = self.clock.now();
+        let window = self.dedup_window_secs;
+        let keys = self.seen_keys.entry(agent_id.to_string()).or_default();
+        keys.retain(|_, applied_at| now.saturating_sub(*applied_at) < window);
+        if let Some(&applied_at) = keys.get(idempotency_key) {
+            return Ok(ApplyOutcome::AlreadyApplied { applied_at });
+        }
+        self.validator.validate(&event)?;
+        self.seen_keys.entry(agent_id.to_string()).or_default().insert(idempotency_key.to_string(), now);
+        let delta = self.apply_unchecked(agent_id, event, now);
+        if let Some(last) = self.records.get_mut(agent_id).and_then(|r| r.history.back_mut()) {
+            last.idempotency_key = Some(idempotency_key.to_string());
+        }
+        Ok(ApplyOutcome::Applied(delta))
+    }
+
+    /// Grant an endorsee points for an endorsement
+    ///
+    /// The points are the endorsement's weight scaled by the endorser's
+    /// level, so Newcomers can't endorse. Each endorser can endorse an
+    /// agent once per 30 days, and an agent gains at most 50 points from
+    /// endorsements in that time; the last grant is cut to fit.
+    /// Endorsements dated more than a day ago or ahead of the clock are
+    /// rejected, as is a signature already used. Returns the points
+    /// granted.
+    pub fn apply_endorsement(
+        &mut self,
+        endorsement: &Endorsement,
+        endorser_public_key: &[u8; 32],
+        endorser_score: u32,
+    ) -> Result<i32, ReputationError> {
+        if endorsement.endorser == endorsement.endorsee {
+            return Err(ReputationError::SelfEndorsement);
+        }
+        if hex::encode(Sha256::digest(endorser_public_key)) != endorsement.endorser {
+            return Err(ReputationError::EndorserKeyMismatch(endorsement.endorser.clone()));
+        }
+        endorsement.verify(endorser_public_key)
+            .map_err(|_| ReputationError::InvalidEndorsementSignature)?;
+        let now = self.clock.now();
+        if endorsement.timestamp > now.saturating_add(MAX_ENDORSEMENT_CLOCK_SKEW_SECS)
+            || now.saturating_sub(endorsement.timestamp) > MAX_ENDORSEMENT_AGE_SECS
+        {
+            return Err(ReputationError::EndorsementOutOfWindow(endorsement.timestamp));
+        }
+        if endorsement.weight == 0 || endorsement.weight > MAX_ENDORSEMENT_WEIGHT {
+            return Err(ReputationError::InvalidEndorsementWeight(endorsement.weight));
+        }
+        let multiplier = endorsement_multiplier(ReputationLevel::from_score_scaled(endorser_score, self.config.max_score));
+        if multiplier == 0 {
+            return Err(ReputationError::EndorserLevelTooLow);
+        }
+
+        let signature = hex::decode(&endorsement.signature).expect("signature was just verified");
+        let grants = self.endorsements.entry(endorsement.endorsee.clone()).or_default();
+        grants.retain(|g| now.saturating_sub(g.granted_at) < ENDORSEMENT_WINDOW_SECS);
+        if grants.iter().any(|g| g.endorser == endorsement.endorser || g.signature == signature) {
+            return Err(ReputationError::DuplicateEndorsement);
+        }
+        let received: i32 = grants.iter().map(|g| g.points).sum();
+        let points = (endorsement.weight as i32 * multiplier).min(MAX_ENDORSEMENT_POINTS - received);
+        if points <= 0 {
+            return Err(ReputationError::EndorsementCapReached);
+        }
+        grants.push(EndorsementGrant {
+            endorser: endorsement.endorser.clone(),
+            granted_at: now,
+            points,
+            signature,
+        });
+
+        self.apply_unchecked(&endorsement.endorsee, ReputationEvent::Endorsed {
+            endorser: endorsement.endorser.clone(),
+            points,
+        }, now);
+        Ok(points)
+    }
+
+    /// Restore `total_points` to an agent gradually over `over_secs`
+    ///
+    /// Points are granted by `process_recoveries`. A slash pauses the plan
+    /// until `resume_recovery`. Replaces any plan the agent already has.
+    pub fn schedule_recovery(&mut self, agent_id: &str, total_points: u32, over_secs: u64) -> Result<(), ReputationError> {
+        if !self.records.contains_key(agent_id) {
+            return Err(ReputationError::AgentNotFound(agent_id.to_string()));
+        }
+        if total_points == 0 || over_secs == 0 {
+            return Err(ReputationError::InvalidRecoveryPlan);
+        }
+        self.recoveries.insert(agent_id.to_string(), RecoveryPlan {
+            total_points,
+            granted: 0,
+            started_at: self.clock.now(),
+            duration_secs: over_secs,
+            paused_at: None,
+            paused_secs: 0,
+        });
+        Ok(())
+    }
+
+    /// Continue a paused recovery plan
+    ///
+    /// The time spent paused doesn't count toward the plan.
+    pub fn resume_recovery(&mut self, agent_id: &str) -> Result<(), ReputationError> {
+        let now = self.clock.now();
+        let plan = self.recoveries.get_mut(agent_id)
+            .ok_or_else(|| ReputationError::RecoveryNotFound(agent_id.to_string()))?;
+        if let Some(paused_at) = plan.paused_at.take() {
+            plan.paused_secs += now.saturating_sub(paused_at);
+        }
+        Ok(())
+    }
+
+    /// Get an agent's recovery plan
+    pub fn recovery(&self, agent_id: &str) -> Option<&RecoveryPlan> {
+        self.recoveries.get(agent_id)
+    }
+
+    /// Grant the points recovery plans have earned by `now`
+    ///
+    /// Each plan's grants add up to exactly its total, after which the plan
+    /// is dropped. Returns the number of agents granted points.
+    pub fn process_recoveries(&mut self, now: u64) -> usize {
+        let mut due = Vec::new();
+        for (agent_id, plan) in &mut self.recoveries {
+            let amount = plan.due_at(now);
+            if amount > 0 {
+                plan.granted += amount;
+                due.push((agent_id.clone(), amount));
+            }
+        }
+        self.recoveries.retain(|_, plan| plan.granted < plan.total_points);
+
+        due.sort();
+        for (agent_id, amount) in &due {
+            self.apply_unchecked(agent_id, ReputationEvent::ScheduledRecovery { amount: *amount as i32 }, now);
+        }
+        due.len()
+    }
+
+    /// Decay every agent's score for the time since their last update
+    ///
+    /// Meant to be run periodically. Calling it again with the same `now`
+    /// changes nothing. Returns the number of agents whose score dropped;
+    /// always zero without a decay policy.
+    pub fn apply_decay(&mut self, now: u64) -> usize {
+        let Some(policy) = self.decay_policy else {
+            return 0;
+        };
+        self.leaderboard_dirty = true;
+        let mut decayed = 0;
+        for record in self.records.values_mut() {
+            let floor = Self::floor_for(&self.level_floors, record);
+            let old_level = record.level;
+            if record.push_decay(&policy, floor, self.dimension_weights.as_ref(), now) != 0 {
+                Self::notify(&mut self.subscribers, record, old_level);
+                Self::trim_history(record, self.history_limit, &mut self.archiver);
+                decayed += 1;
+            }
+        }
+        decayed
+    }
+
+    /// Get agent score
+    pub fn score(&self, agent_id: &str) -> u32 {
+        self.records.get(agent_id).map(|r| r.score).unwrap_or(0)
+    }
+
+    /// Get agent score as of `timestamp` (see `ReputationRecord::score_at`)
+    pub fn score_at(&self, agent_id: &str, timestamp: u64) -> u32 {
+        self.records.get(agent_id).map(|r| r.score_at(timestamp)).unwrap_or(0)
+    }
+
+    /// Get an agent's score in a dimension
+    pub fn subscore(&self, agent_id: &str, dimension: Dimension) -> u32 {
+        self.records.get(agent_id).map(|r| r.subscore(dimension)).unwrap_or(0)
+    }
+
+    /// Sign a statement of an agent's current score, valid for `ttl_secs`
+    pub fn attest(
+        &self,
+        agent_id: &str,
+        signer: &AgentSDKey,
+        ttl_secs: u64,
+    ) -> Result<ReputationAttestation, ReputationError> {
+        let record = self.records.get(agent_id)
+            .ok_or_else(|| ReputationError::AgentNotFound(agent_id.to_string()))?;
+        let now = self.clock.now();
+        let mut attestation = ReputationAttestation {
+            agent_id: record.agent_id.clone(),
+            score: record.score,
+            level: record.level,
+            issued_at: now,
+            expires_at: now.saturating_add(ttl_secs),
+            issuer_pubkey: signer.public_key_hex(),
+            signature: String::new(),
+        };
+        attestation.signature = signer.sign_hex(&attestation.signing_message())
+            .map_err(|e| ReputationError::AttestationSigning(e.to_string()))?;
+        Ok(attestation)
+    }
+
+    /// Get agent level
+    pub fn level(&self, agent_id: &str) -> ReputationLevel {
+        self.records.get(agent_id)
+            .map(|r| r.level)
+            .unwrap_or(ReputationLevel::Newcomer)
+    }
+
+    /// Every agent's net score change over the last `window_secs`, by agent ID
+    pub fn trends(&self, window_secs: u64) -> Vec<AgentTrend> {
+        let now = self.clock.now();
+        let mut trends: Vec<AgentTrend> = self.records.values()
+            .map(|r| {
+                let trend = r.trend(window_secs, now);
+                AgentTrend {
+                    agent_id: r.agent_id.clone(),
+                    change: trend.value,
+                    partial: trend.partial,
+                }
+            })
+            .collect();
+        trends.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+        trends
+    }
+
+    /// Up to `count` agents with the largest gains over the window
+    pub fn most_improved(&self, window_secs: u64, count: usize) -> Vec<AgentTrend> {
+        let mut improved: Vec<AgentTrend> = self.trends(window_secs).into_iter().filter(|t| t.change > 0).collect();
+        improved.sort_by(|a, b| b.change.cmp(&a.change).then_with(|| a.agent_id.cmp(&b.agent_id)));
+        improved.truncate(count);
+        improved
+    }
+
+    /// Up to `count` agents with the largest losses over the window
+    pub fn most_declined(&self, window_secs: u64, count: usize) -> Vec<AgentTrend> {
+        let mut declined: Vec<AgentTrend> = self.trends(window_secs).into_iter().filter(|t| t.change < 0).collect();
+        declined.sort_by(|a, b| a.change.cmp(&b.change).then_with(|| a.agent_id.cmp(&b.agent_id)));
+        declined.truncate(count);
+        declined
+    }
+
+    /// Force the leaderboard to be rebuilt on next use
+    pub fn invalidate_leaderboard(&mut self) {
+        self.leaderboard_dirty = true;
+    }
+
+    /// Get leaderboard (top N agents)
+    ///
+    /// Ordered by score, highest first. Ties go to the agent with more
+    /// events, then to the lower agent ID.
+    pub fn leaderboard(&mut self, count: usize) -> Vec<(&String, &ReputationRecord)> {
+        self.refresh_leaderboard();
+        self.leaderboard_cache
+            .iter()
+            .take(count)
+            .filter_map(|id| self.records.get(id).map(|r| (id, r)))
+            .collect()
+    }
+
+    /// Leaderboard positions `offset..offset + limit`, in `leaderboard` order
+    pub fn leaderboard_page(&mut self, offset: usize, limit: usize) -> Vec<LeaderboardEntry> {
+        self.refresh_leaderboard();
+        self.leaderboard_cache
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(limit)
+            .filter_map(|(index, id)| {
+                self.records.get(id).map(|r| LeaderboardEntry {
+                    agent_id: id.clone(),
+                    score: r.score,
+                    level: r.level,
+                    rank: index + 1,
+                })
+            })
+            .collect()
+    }
+
+    /// Leaderboard position of an agent (1 is the top), in `leaderboard`
+    /// order
+    pub fn rank_of(&self, agent_id: &str) -> Option<usize> {
+        let record = self.records.get(agent_id)?;
+        let ahead = self.records.values()
+            .filter(|r| r.leaderboard_cmp(record).is_lt())
+            .count();
+        Some(ahead + 1)
+    }
+
+    /// Rebuild the leaderboard cache after changes, and at least once per TTL
+    fn refresh_leaderboard(&mut self) {
+        let now = self.clock.now();
+        if !self.leaderboard_dirty && now.saturating_sub(self.leaderboard_updated) <= self.leaderboard_ttl_secs {
+            return;
+        }
+        let mut sorted: Vec<&ReputationRecord> = self.records.values().collect();
+        sorted.sort_by(|a, b| a.leaderboard_cmp(b));
+        self.leaderboard_cache = sorted.iter().map(|r| r.agent_id.clone()).collect();
+        self.leaderboard_updated = now;
+        self.leaderboard_dirty = false;
+    }
+
+    /// Get agents at or above level, highest score first
+    ///
+    /// Agents on probation are included; `query_agents_at_level` flags
+    /// them.
+    pub fn agents_at_level(&self, min_level: ReputationLevel) -> Vec<&ReputationRecord> {
+        self.query_records(&AgentQuery::default(), |r| r.level >= min_level).0
+    }
+
+    /// Get agents whose subscore in `dimension` is at or above level
+    pub fn agents_at_dimension_level(&self, dimension: Dimension, min_level: ReputationLevel) -> Vec<&ReputationRecord> {
+        self.records
+            .values()
+            .filter(|r| r.bounds.level(r.subscore(dimension)) >= min_level)
+            .collect()
+    }
+
+    /// Get agents in score range, highest score first
+    pub fn agents_in_range(&self, min: u32, max: u32) -> Vec<&ReputationRecord> {
+        self.query_records(&AgentQuery::default(), |r| r.score >= min && r.score <= max).0
+    }
+
+    /// One page of the agents at or above level, ordered by `query`
+    pub fn query_agents_at_level(&self, min_level: ReputationLevel, query: &AgentQuery) -> AgentPage {
+        self.query_agents(query, |r| r.level >= min_level)
+    }
+
+    /// One page of the agents in score range, ordered by `query`
+    pub fn query_agents_in_range(&self, min: u32, max: u32, query: &AgentQuery) -> AgentPage {
+        self.query_agents(query, |r| r.score >= min && r.score <= max)
+    }
+
+    /// One page of the agents matching `filter`, ordered by `query`
+    fn query_agents(&self, query: &AgentQuery, filter: impl Fn(&ReputationRecord) -> bool) -> AgentPage {
+        let now = self.clock.now();
+        let (page, total) = self.query_records(query, filter);
+        let items: Vec<AgentSummary> = page.into_iter()
+            .map(|r| AgentSummary {
+                agent_id: r.agent_id.clone(),
+                score: r.score,
+                level: r.level,
+                last_updated: r.last_updated,
+                weekly_trend: r.trend(604_800, now).value,
+                on_probation: r.is_on_probation_at(now),
+                highest_level_achieved: r.highest_level_achieved,
+            })
+            .collect();
+        let end = query.offset + items.len();
+        let next_offset = (end < total).then_some(end);
+
+        AgentPage { items, total, next_offset }
+    }
+
+    /// One page of the records matching `filter`, ordered by `query`, and
+    /// the number matching across all pages
+    fn query_records(&self, query: &AgentQuery, filter: impl Fn(&ReputationRecord) -> bool) -> (Vec<&ReputationRecord>, usize) {
+        let now = self.clock.now();
+        let mut matching: Vec<(&ReputationRecord, i32)> = self.records.values()
+            .filter(|r| filter(r))
+            .map(|r| match query.sort_by {
+                SortBy::WeeklyTrend => (r, r.trend(604_800, now).value),
+                SortBy::Score | SortBy::LastUpdated => (r, 0),
+            })
+            .collect();
+        matching.sort_by(|(a, a_trend), (b, b_trend)| {
+            let key = match query.sort_by {
+                SortBy::Score => a.score.cmp(&b.score),
+                SortBy::LastUpdated => a.last_updated.cmp(&b.last_updated),
+                SortBy::WeeklyTrend => a_trend.cmp(b_trend),
+            };
+            let key = match query.order {
+                SortOrder::Ascending => key,
+                SortOrder::Descending => key.reverse(),
+            };
+            key.then_with(|| a.agent_id.cmp(&b.agent_id))
+        });
+
+        let total = matching.len();
+        let page = matching.into_iter()
+            .skip(query.offset)
+            .take(query.limit.unwrap_or(usize::MAX))
+            .map(|(r, _)| r)
+            .collect();
+        (page, total)
+    }
+
+    /// Total tracked agents
+    pub fn agent_count(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Score statistics across all agents (all zero with no agents)
+    ///
+    /// Takes one pass over the agents; percentiles use the nearest-rank
+    /// method over per-score counts.
+    pub fn distribution(&self) -> ReputationDistribution {
+        let agent_count = self.records.len();
+        if agent_count == 0 {
+            return ReputationDistribution::default();
+        }
+
+        let max = self.config.max_score;
+        let counts = self.score_counts();
+        let mut per_level = BTreeMap::new();
+        let mut total: u64 = 0;
+        for record in self.records.values() {
+            *per_level.entry(record.level).or_insert(0) += 1;
+            total += record.score.min(max) as u64;
+        }
+
+        let percentile = |p: usize| {
+            let rank = (p * agent_count).div_ceil(100).max(1);
+            let mut seen = 0;
+            for (&score, count) in &counts {
+                seen += count;
+                if seen >= rank {
+                    return score;
+                }
+            }
+            max
+        };
+        ReputationDistribution {
+            agent_count,
+            per_level,
+            p10: percentile(10),
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            mean: total as f64 / agent_count as f64,
+            min: counts.keys().next().copied().unwrap_or(0),
+            max: counts.keys().next_back().copied().unwrap_or(0),
+        }
+    }
+
+    /// Snapshot tracker health for monitoring
+    ///
+    /// One pass over the agents. Recent events are counted from the end of
+    /// each retained history, so the cost per agent is bounded by the
+    /// events it had in the last hour.
+    pub fn metrics_snapshot(&self) -> TrackerMetrics {
+        let now = self.clock.now();
+        let mut metrics = TrackerMetrics {
+            total_agents: self.records.len(),
+            agents_per_level: ReputationLevel::ALL.iter().map(|&level| (level, 0)).collect(),
+            leaderboard_cache_age_secs: (self.leaderboard_updated > 0)
+                .then(|| now.saturating_sub(self.leaderboard_updated)),
+            ..Default::default()
+        };
+        let mut total: u64 = 0;
+        for record in self.records.values() {
+            *metrics.agents_per_level.entry(record.level).or_insert(0) += 1;
+            total += record.score as u64;
+            metrics.events_last_hour += record.history.iter().rev()
+                .take_while(|e| now.saturating_sub(e.timestamp) < 3_600)
+                .count() as u64;
+            if record.is_on_probation_at(now) {
+                metrics.agents_on_probation += 1;
+            }
+            if record.event_count(ReputationEventKind::Slashed) > 0 {
+                metrics.slashed_agents += 1;
+            }
+        }
+        if !self.records.is_empty() {
+            metrics.mean_score = total as f64 / self.records.len() as f64;
+        }
+        metrics
+    }
+
+    /// Agents per score range of `bucket_size` points, covering 0 to the
+    /// configured maximum
+    ///
+    /// Empty if `bucket_size` is zero.
+    pub fn histogram(&self, bucket_size: u32) -> Vec<HistogramBucket> {
+        if bucket_size == 0 {
+            return Vec::new();
+        }
+        let counts = self.score_counts();
+        let top = self.config.max_score;
+        (0..=top)
+            .step_by(bucket_size as usize)
+            .map(|min| {
+                let max = min.saturating_add(bucket_size - 1).min(top);
+                HistogramBucket {
+                    min,
+                    max,
+                    count: counts.range(min..=max).map(|(_, count)| count).sum(),
+                }
+            })
+            .collect()
+    }
+
+    /// Percentage of tracked agents scoring below an agent (0-100)
+    ///
+    /// Agents tied with them count as half below, so equal scores get
+    /// equal percentiles and a lone agent sits at 50. An agent at 93 is in
+    /// the top 7%.
+    pub fn percentile(&self, agent_id: &str) -> Option<f64> {
+        let max = self.config.max_score;
+        let score = self.records.get(agent_id)?.score.min(max);
+        Some(Self::percentile_of(&self.score_counts(), score, 0..=max))
+    }
+
+    /// Like `percentile`, but against agents at the same level only
+    pub fn percentile_within_level(&self, agent_id: &str) -> Option<f64> {
+        let max = self.config.max_score;
+        let score = self.records.get(agent_id)?.score.min(max);
+        let level = ReputationLevel::from_score_scaled(score, max);
+        let level_max = ReputationLevel::ALL.iter()
+            .find(|&&next| next > level)
+            .map_or(max, |next| next.min_score_scaled(max) - 1);
+        Some(Self::percentile_of(&self.score_counts(), score, level.min_score_scaled(max)..=level_max))
+    }
+
+    /// Everything about one agent, e.g. for a support API
+    pub fn report(&self, agent_id: &str) -> Option<ReputationReport> {
+        let now = self.clock.now();
+        let record = self.records.get(agent_id)?;
+        let since = record.level_since();
+        Some(ReputationReport {
+            agent_id: record.agent_id.clone(),
+            score: record.score,
+            level: record.level,
+            rank: self.rank_of(agent_id)?,
+            percentile: self.percentile(agent_id)?,
+            weekly_trend: record.trend(604_800, now),
+            monthly_trend: record.trend(2_592_000, now),
+            event_counts: record.event_counts.clone(),
+            on_probation_until: record.on_probation_until.filter(|_| record.is_on_probation_at(now)),
+            recent_events: record.recent_history(REPORT_RECENT_EVENTS).cloned().collect(),
+            time_at_level_secs: Windowed { value: now.saturating_sub(since.value), partial: since.partial },
+            generated_at: now,
+        })
+    }
+
+    /// Midpoint percentile of `score` among the agents scoring in `range`
+    fn percentile_of(counts: &BTreeMap<u32, usize>, score: u32, range: std::ops::RangeInclusive<u32>) -> f64 {
+        let total: usize = counts.range(range.clone()).map(|(_, count)| count).sum();
+        let below: usize = counts.range(*range.start()..score).map(|(_, count)| count).sum();
+        let tied = counts.get(&score).copied().unwrap_or(0);
+        (below as f64 + tied as f64 / 2.0) / total as f64 * 100.0
+    }
+
+    /// Agents per score, for the scores held by at least one agent
+    ///
+    /// Sized by the agents rather than the score range, so wide ranges
+    /// cost nothing extra.
+    fn score_counts(&self) -> BTreeMap<u32, usize> {
+        let max = self.config.max_score;
+        let mut counts = BTreeMap::new();
+        for record in self.records.values() {
+            *counts.entry(record.score.min(max)).or_insert(0) += 1;
+        }
+        counts
     }
 
     /// Export all records
     pub fn export(&self) -> Vec<&ReputationRecord> {
         self.records.values().collect()
     }
+
+    /// Events processed across all agents, by kind
+    pub fn event_totals(&self) -> BTreeMap<ReputationEventKind, u64> {
+        let mut totals = BTreeMap::new();
+        for record in self.records.values() {
+            for (&kind, &count) in &record.event_counts {
+                *totals.entry(kind).or_default() += count;
+            }
+        }
+        totals
+    }
+
+    /// Write one CSV row per agent, ordered by agent ID
+    ///
+    /// Columns: agent_id, score, level, total_events, last_updated,
+    /// weekly_trend.
+    pub fn export_csv<W: Write>(&self, mut writer: W) -> Result<(), ReputationError> {
+        let now = self.clock.now();
+        let mut records: Vec<&ReputationRecord> = self.records.values().collect();
+        records.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        for record in records {
+            out.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                csv_field(&record.agent_id),
+                record.score,
+                record.level.name(),
+                record.total_events,
+                record.last_updated,
+                record.trend(604_800, now).value
+            ));
+        }
+        writer.write_all(out.as_bytes()).map_err(|e| ReputationError::Io(e.to_string()))
+    }
+
+    /// Read rows written by `export_csv`
+    ///
+    /// Bad rows are reported and skipped; the rest are imported. Imported
+    /// records start with an empty history, and `weekly_trend` is ignored.
+    /// Fails outright only if the file can't be read or the header is
+    /// wrong.
+    pub fn import_csv<R: Read>(&mut self, mut reader: R, mode: CsvImportMode) -> Result<CsvImportReport, ReputationError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).map_err(|e| ReputationError::Io(e.to_string()))?;
+
+        let mut rows = parse_csv(&text).into_iter();
+        match rows.next() {
+            Some((_, Ok(header))) if header.join(",") == CSV_HEADER => {}
+            _ => return Err(ReputationError::Corrupt("missing or unexpected CSV header".into())),
+        }
+
+        let mut report = CsvImportReport::default();
+        for (line, row) in rows {
+            let bounds = self.config.bounds(self.config.default_start);
+            let parsed = row.and_then(|fields| csv_record(&fields, bounds));
+            let result = parsed.and_then(|record| {
+                if mode == CsvImportMode::CreateOnly && self.records.contains_key(&record.agent_id) {
+                    return Err(format!("agent {} already exists", record.agent_id));
+                }
+                self.records.insert(record.agent_id.clone(), record);
+                Ok(())
+            });
+            match result {
+                Ok(()) => report.imported += 1,
+                Err(reason) => report.errors.push(CsvRowError { line, reason }),
+            }
+        }
+        self.leaderboard_dirty = true;
+        Ok(report)
+    }
+
+    /// Write all records, with their history, to `writer`
+    pub fn save_to<W: Write>(&self, writer: W) -> Result<(), ReputationError> {
+        let mut records: Vec<ReputationRecord> = self.records.values().cloned().collect();
+        records.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+        let saved = SavedTracker {
+            version: FORMAT_VERSION,
+            records,
+            seen_keys: self.seen_keys.clone(),
+            endorsements: self.endorsements.clone(),
+            recoveries: self.recoveries.clone(),
+            contexts: self.contexts.iter()
+                .map(|(context, records)| {
+                    let mut records: Vec<ReputationRecord> = records.values().cloned().collect();
+                    records.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+                    (context.clone(), records)
+                })
+                .collect(),
+            attestation_sync: self.attestation_sync.clone(),
+        };
+        serde_json::to_writer(writer, &saved).map_err(|e| ReputationError::Io(e.to_string()))
+    }
+
+    /// Build a tracker from records written by `save_to`
+    pub fn load_from<R: Read>(reader: R) -> Result<Self, ReputationError> {
+        let saved = Self::read_saved(reader)?;
+        let mut tracker = Self::new();
+        tracker.records = saved.records.into_iter().map(|r| (r.agent_id.clone(), r)).collect();
+        tracker.seen_keys = saved.seen_keys;
+        tracker.endorsements = saved.endorsements;
+        tracker.recoveries = saved.recoveries;
+        tracker.attestation_sync = saved.attestation_sync;
+        tracker.merge_contexts(saved.contexts.into_iter().map(|(context, records)| {
+            (context, records.into_iter().map(|r| (r.agent_id.clone(), r)).collect())
+        }).collect());
+        Ok(tracker)
+    }
+
+    /// Fold records written by `save_to` into this tracker
+    ///
+    /// For agents present in both, the record updated most recently wins.
+    /// Idempotency keys, endorsement grants, recovery plans and attestation
+    /// sync state from both are kept. Returns the number of records taken
+    /// from the file. Nothing changes if the file can't be read.
+    pub fn merge_loaded<R: Read>(&mut self, reader: R) -> Result<usize, ReputationError> {
+        let saved = Self::read_saved(reader)?;
+        self.merge_state(saved.seen_keys, saved.endorsements, saved.recoveries, saved.attestation_sync);
+        self.merge_contexts(saved.contexts.into_iter().map(|(context, records)| {
+            (context, records.into_iter().map(|r| (r.agent_id.clone(), r)).collect())
+        }).collect());
+
+        let mut taken = 0;
+        for loaded in saved.records {
+            let newer = self.records.get(&loaded.agent_id)
+                .map_or(true, |existing| loaded.supersedes(existing));
+            if newer {
+                self.records.insert(loaded.agent_id.clone(), loaded);
+                taken += 1;
+            }
+        }
+        self.leaderboard_dirty = true;
+        Ok(taken)
+    }
+
+    /// Fold another tracker's records into this one
+    ///
+    /// Agents only the other tracker knows are taken as they are; see
+    /// `MergeStrategy` for the rest. Idempotency keys, endorsement grants
+    /// and recovery plans from both are kept. Returns the number of records added or
+    /// changed.
+    pub fn merge(&mut self, other: ReputationTracker, strategy: MergeStrategy) -> usize {
+        let ReputationTracker { records, seen_keys, endorsements, recoveries, contexts, attestation_sync, .. } = other;
+
+        // Agent order decides the order of archived events
+        let mut theirs: Vec<ReputationRecord> = records.into_values().collect();
+        theirs.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+
+        let mut changed = 0;
+        for record in theirs {
+            let agent_id = record.agent_id.clone();
+            let Some(ours) = self.records.get_mut(&agent_id) else {
+                let mut record = record;
+                Self::trim_history(&mut record, self.history_limit, &mut self.archiver);
+                self.records.insert(agent_id, record);
+                changed += 1;
+                continue;
+            };
+            let replaced = match strategy {
+                MergeStrategy::SumDeltas => self.replay_record(record),
+                MergeStrategy::TakeNewest | MergeStrategy::MaxScore => {
+                    let wins = if strategy == MergeStrategy::MaxScore {
+                        record.score > ours.score || (record.score == ours.score && record.supersedes(ours))
+                    } else {
+                        record.supersedes(ours)
+                    };
+                    if wins {
+                        *ours = record;
+                    }
+                    wins
+                }
+            };
+            if replaced {
+                let ours = self.records.get_mut(&agent_id).expect("merged record exists");
+                Self::trim_history(ours, self.history_limit, &mut self.archiver);
+                changed += 1;
+            }
+        }
+        self.merge_state(seen_keys, endorsements, recoveries, attestation_sync);
+        self.merge_contexts(contexts);
+        self.leaderboard_dirty = true;
+        changed
+    }
+
+    /// Replay another record's history for the same agent on top of this
+    /// tracker's record
+    ///
+    /// Events carrying an idempotency key the agent already used are
+    /// skipped. The rest go through the apply logic at their own
+    /// timestamps, ordered by timestamp then event ID, and are then
+    /// interleaved with the existing history by timestamp (existing events
+    /// first on ties) with scores recomputed along it. Events the other
+    /// record no longer retains can't be replayed but still count toward
+    /// `total_events`. Returns whether anything was taken.
+    fn replay_record(&mut self, other: ReputationRecord) -> bool {
+        let agent_id = other.agent_id.clone();
+        let ours = &self.records[&agent_id];
+        let known: HashSet<String> = ours.history.iter()
+            .filter_map(|e| e.idempotency_key.clone())
+            .chain(self.seen_keys.get(&agent_id).into_iter().flat_map(|keys| keys.keys().cloned()))
+            .collect();
+        let base = ours.checkpoints.last().map_or(ours.bounds.start, |c| c.score);
+
+        let ReputationRecord { history, total_events, mut event_counts, highest_level_achieved, last_updated, on_probation_until, .. } = other;
+        // Counts left over once the retained events are replayed
+        let trimmed = total_events.saturating_sub(history.len() as u64);
+        for event in &history {
+            if let Some(count) = event_counts.get_mut(&event.event.kind()) {
+                *count = count.saturating_sub(1);
+            }
+        }
+        let mut events: Vec<ReputationEventRecord> = history.into_iter()
+            .filter(|e| !e.idempotency_key.as_ref().is_some_and(|key| known.contains(key)))
+            .collect();
+        events.sort_by_key(|e| (e.timestamp, e.event_id));
+        let taken = trimmed > 0 || !events.is_empty();
+
+        // Trimming waits until the histories are interleaved
+        let history_limit = std::mem::replace(&mut self.history_limit, usize::MAX);
+        let mut ids = HashMap::new();
+        let mut reversed = Vec::new();
+        for mut event in events {
+            if let ReputationEvent::Reversal { event_id, .. } = &mut event.event {
+                if let Some(&id) = ids.get(event_id) {
+                    *event_id = id;
+                }
+            }
+            let applied = self.apply_recorded(&agent_id, event.event, event.timestamp);
+            let id = applied.record.event_id;
+            ids.insert(event.event_id, id);
+            if let Some(reversed_by) = event.reversed_by {
+                reversed.push((id, reversed_by));
+            }
+            if let Some(key) = event.idempotency_key {
+                let record = self.records.get_mut(&agent_id).expect("event was just applied");
+                if let Some(last) = record.history.back_mut() {
+                    last.idempotency_key = Some(key);
+                }
+            }
+        }
+        self.history_limit = history_limit;
+
+        let composed = self.dimension_weights.is_some();
+        let record = self.records.get_mut(&agent_id).expect("merged record exists");
+        for (id, reversed_by) in reversed {
+            let by = ids.get(&reversed_by).copied();
+            if let Some(event) = record.history.iter_mut().find(|e| e.event_id == id) {
+                event.reversed_by = by;
+            }
+        }
+        record.history.make_contiguous().sort_by_key(|e| e.timestamp);
+        let mut score = base;
+        for event in &mut record.history {
+            score = record.bounds.apply(score, event.delta);
+            event.score_after = score;
+        }
+        if !composed {
+            record.score = score;
+            record.level = record.bounds.level(score);
+        }
+        record.highest_level_achieved = record.highest_level_achieved.max(highest_level_achieved).max(record.level);
+        record.total_events += trimmed;
+        for (kind, count) in event_counts {
+            *record.event_counts.entry(kind).or_default() += count;
+        }
+        record.last_updated = record.last_updated.max(last_updated);
+        record.on_probation_until = record.on_probation_until.max(on_probation_until);
+        taken
+    }
+
+    /// Take scoped records, keeping the newer where both have one
+    ///
+    /// Strategies apply to global records only.
+    fn merge_contexts(&mut self, contexts: Contexts) {
+        for (context, records) in contexts {
+            let ours = self.contexts.entry(context).or_default();
+            for (agent_id, record) in records {
+                if ours.get(&agent_id).map_or(true, |existing| record.supersedes(existing)) {
+                    ours.insert(agent_id, record);
+                }
+            }
+        }
+    }
+
+    /// Union idempotency keys, endorsement grants, recovery plans and
+    /// attestation sync state into this tracker's, keeping this tracker's
+    /// plan where both have one and the later attestation seen
+    fn merge_state(
+        &mut self,
+        seen_keys: SeenKeys,
+        endorsements: HashMap<String, Vec<EndorsementGrant>>,
+        recoveries: HashMap<String, RecoveryPlan>,
+        attestation_sync: HashMap<String, AttestationSyncState>,
+    ) {
+        for (agent_id, state) in attestation_sync {
+            let ours = self.attestation_sync.entry(agent_id).or_insert(state);
+            if state.last_attestation > ours.last_attestation {
+                *ours = state;
+            }
+        }
+        for (agent_id, plan) in recoveries {
+            self.recoveries.entry(agent_id).or_insert(plan);
+        }
+        for (agent_id, loaded_keys) in seen_keys {
+            let keys = self.seen_keys.entry(agent_id).or_default();
+            for (key, applied_at) in loaded_keys {
+                let first = keys.entry(key).or_insert(applied_at);
+                *first = (*first).min(applied_at);
+            }
+        }
+        for (endorsee, loaded_grants) in endorsements {
+            let grants = self.endorsements.entry(endorsee).or_default();
+            for grant in loaded_grants {
+                if !grants.iter().any(|g| g.endorser == grant.endorser && g.granted_at == grant.granted_at) {
+                    grants.push(grant);
+                }
+            }
+        }
+    }
+
+    /// Parse and check a saved tracker
+    fn read_saved<R: Read>(reader: R) -> Result<SavedTracker, ReputationError> {
+        let value: serde_json::Value = serde_json::from_reader(reader)
+            .map_err(|e| ReputationError::Corrupt(e.to_string()))?;
+
+        // Check the version first so newer files aren't reported as corrupt
+        let version = value.get("version")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| ReputationError::Corrupt("missing format version".into()))?;
+        if version > FORMAT_VERSION as u64 {
+            return Err(ReputationError::UnsupportedVersion { found: version, supported: FORMAT_VERSION });
+        }
+        let saved: SavedTracker = serde_json::from_value(value)
+            .map_err(|e| ReputationError::Corrupt(e.to_string()))?;
+
+        for records in std::iter::once(&saved.records).chain(saved.contexts.values()) {
+            Self::check_saved_records(records)?;
+        }
+        for (agent_id, plan) in &saved.recoveries {
+            if plan.duration_secs == 0 || plan.granted >= plan.total_points {
+                return Err(ReputationError::Corrupt(format!("invalid recovery plan for {}", agent_id)));
+            }
+        }
+        Ok(saved)
+    }
+
+    /// Check saved records have scores in range and one record per agent
+    fn check_saved_records(records: &[ReputationRecord]) -> Result<(), ReputationError> {
+        let mut agents = HashSet::with_capacity(records.len());
+        for record in records {
+            let subscores = Dimension::ALL.map(|d| record.subscore(d));
+            let range = record.bounds.min..=record.bounds.max;
+            if let Some(score) = subscores.into_iter().chain([record.score]).find(|s| !range.contains(s)) {
+                return Err(ReputationError::Corrupt(format!(
+                    "score {} for {} is out of range",
+                    score, record.agent_id
+                )));
+            }
+            if !agents.insert(record.agent_id.as_str()) {
+                return Err(ReputationError::Corrupt(format!("duplicate record for {}", record.agent_id)));
+            }
+            if (record.history.len() as u64) > record.total_events {
+                return Err(ReputationError::Corrupt(format!(
+                    "{} has more events in history than total_events",
+                    record.agent_id
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Header row of reputation CSV files
+const CSV_HEADER: &str = "agent_id,score,level,total_events,last_updated,weekly_trend";
+
+/// How `import_csv` treats agents that are already tracked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvImportMode {
+    /// Reject rows for known agents
+    CreateOnly,
+    /// Replace known agents' records
+    Overwrite,
+}
+
+/// Outcome of a CSV import
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CsvImportReport {
+    /// Rows imported
+    pub imported: usize,
+    /// Rows skipped, in file order
+    pub errors: Vec<CsvRowError>,
+}
+
+/// A CSV row that couldn't be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRowError {
+    /// Line the row starts on (1-based)
+    pub line: usize,
+    /// What was wrong
+    pub reason: String,
+}
+
+/// Quote a CSV field if needed
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Split CSV text into rows of fields, with the line each row starts on
+///
+/// Blank lines are skipped. A malformed row is reported in place without
+/// affecting the rows after it.
+fn parse_csv(text: &str) -> Vec<(usize, Result<Vec<String>, String>)> {
+    let mut rows = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+
+    while chars.peek().is_some() {
+        let start_line = line;
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut error = None;
+        let mut in_quotes = false;
+        let mut quoted = false;
+
+        loop {
+            let Some(c) = chars.next() else {
+                if in_quotes {
+                    error.get_or_insert_with(|| "unterminated quoted field".to_string());
+                }
+                break;
+            };
+            if c == '\n' {
+                line += 1;
+            }
+            if in_quotes {
+                if c == '"' {
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        field.push('"');
+                    } else {
+                        in_quotes = false;
+                    }
+                } else {
+                    field.push(c);
+                }
+                continue;
+            }
+            match c {
+                ',' => {
+                    fields.push(std::mem::take(&mut field));
+                    quoted = false;
+                }
+                '\n' => break,
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '"' if field.is_empty() && !quoted => {
+                    in_quotes = true;
+                    quoted = true;
+                }
+                _ if quoted => {
+                    error.get_or_insert_with(|| "unexpected character after closing quote".to_string());
+                }
+                _ => field.push(c),
+            }
+        }
+        fields.push(field);
+
+        if error.is_none() && fields.len() == 1 && fields[0].is_empty() {
+            continue;
+        }
+        rows.push((start_line, error.map_or(Ok(fields), Err)));
+    }
+    rows
+}
+
+/// Build a record from a CSV row
+fn csv_record(fields: &[String], bounds: ScoreBounds) -> Result<ReputationRecord, String> {
+    let [agent_id, score, level, total_events, last_updated, _weekly_trend] = fields else {
+        return Err(format!("expected 6 fields, got {}", fields.len()));
+    };
+    if agent_id.is_empty() {
+        return Err("empty agent_id".into());
+    }
+    let score: u32 = score.parse().map_err(|_| format!("invalid score {:?}", score))?;
+    if !(bounds.min..=bounds.max).contains(&score) {
+        return Err(format!("score {} is out of range", score));
+    }
+    let expected_level = bounds.level(score);
+    if level != expected_level.name() {
+        return Err(format!("level {:?} does not match score {}", level, score));
+    }
+    let total_events = total_events.parse()
+        .map_err(|_| format!("invalid total_events {:?}", total_events))?;
+    let last_updated = last_updated.parse()
+        .map_err(|_| format!("invalid last_updated {:?}", last_updated))?;
+
+    let mut record = ReputationRecord::new_bounded(agent_id.clone(), bounds, last_updated);
+    record.score = score;
+    record.level = expected_level;
+    record.subscores = Subscores::uniform(score);
+    record.total_events = total_events;
+    record.next_event_id = total_events + 1;
+    // The events behind the score aren't imported; start from it
+    record.checkpoints.push(ScoreCheckpoint { timestamp: last_updated, score });
+    Ok(record)
+}
+
+/// Reputation errors
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReputationError {
+    #[error("Reputation I/O failed: {0}")]
+    Io(String),
+
+    #[error("Corrupt reputation file: {0}")]
+    Corrupt(String),
+
+    #[error("Reputation file version {found} is newer than supported version {supported}")]
+    UnsupportedVersion { found: u64, supported: u32 },
+
+    #[error("Agents cannot endorse themselves")]
+    SelfEndorsement,
+
+    #[error("Public key does not belong to {0}")]
+    EndorserKeyMismatch(String),
+
+    #[error("Invalid endorsement signature")]
+    InvalidEndorsementSignature,
+
+    #[error("Endorsement weight must be between 1 and 10, got {0}")]
+    InvalidEndorsementWeight(u32),
+
+    #[error("Endorser's level is too low to endorse")]
+    EndorserLevelTooLow,
+
+    #[error("Endorser already endorsed this agent within the last 30 days")]
+    DuplicateEndorsement,
+
+    #[error("Endorsement timestamp {0} is too old or in the future")]
+    EndorsementOutOfWindow(u64),
+
+    #[error("Agent has reached the endorsement limit for the last 30 days")]
+    EndorsementCapReached,
+
+    #[error("Agent is not on probation: {0}")]
+    NotOnProbation(String),
+
+    #[error("Agent not found: {0}")]
+    AgentNotFound(String),
+
+    #[error("Event {event_id} not found in the history of {agent_id}")]
+    EventNotFound { agent_id: String, event_id: u64 },
+
+    #[error("Event {event_id} was already reversed by event {reversed_by}")]
+    AlreadyReversed { event_id: u64, reversed_by: u64 },
+
+    #[error("Event {0} is a reversal and can't be reversed")]
+    CannotReverseReversal(u64),
+
+    #[error("Event {index} of {agent_id} is worth {expected}, but recorded {recorded}")]
+    UnexpectedDelta { agent_id: String, index: usize, expected: i32, recorded: i32 },
+
+    #[error("History of {agent_id} is inconsistent at event {index:?}: expected score {expected}, found {recorded}")]
+    InconsistentHistory { agent_id: String, index: Option<usize>, expected: u32, recorded: u32 },
+
+    #[error("Invalid {kind:?} event: {field} {reason}")]
+    InvalidEvent { kind: ReputationEventKind, field: &'static str, reason: String },
+
+    #[error("Event for {agent_id} at {timestamp} is older than its last update at {last_updated}")]
+    OutOfOrderEvent { agent_id: String, timestamp: u64, last_updated: u64 },
+
+    #[error("Invalid tracker config: {0}")]
+    InvalidConfig(String),
+
+    #[error("Unknown agent class: {0}")]
+    UnknownAgentClass(String),
+
+    #[error("Recovery plans need a positive number of points and duration")]
+    InvalidRecoveryPlan,
+
+    #[error("No recovery plan for agent: {0}")]
+    RecoveryNotFound(String),
+
+    #[error("Failed to sign attestation: {0}")]
+    AttestationSigning(String),
+
+    #[error("Attestation was issued by a different key")]
+    AttestationIssuerMismatch,
+
+    #[error("Invalid attestation signature")]
+    InvalidAttestationSignature,
+
+    #[error("Attestation expired at {expires_at}")]
+    AttestationExpired { expires_at: u64 },
+}
+
+/// Batch errors
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BatchError {
+    #[error("Event {index} in batch is invalid: {error}")]
+    InvalidEvent { index: usize, error: ReputationError },
 }
 
 impl Default for ReputationTracker {
@@ -346,6 +3823,7 @@ impl Default for ReputationTracker {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
 
     #[test]
     fn test_reputation_levels() {
@@ -362,19 +3840,158 @@ mod tests {
         let mut tracker = ReputationTracker::new();
 
         // Successful trade
-        let delta = tracker.apply_event(
+        let delta = tracker.apply_event_full(
             "agent-001",
             ReputationEvent::TradeSuccess { pnl_bps: 500, volume_usd: 50000 },
-        );
+        ).unwrap().record.delta;
         assert!(delta > 0);
 
         // Governance vote
-        tracker.apply_event("agent-001", ReputationEvent::GovernanceVote);
+        tracker.apply_event_full("agent-001", ReputationEvent::GovernanceVote).unwrap();
 
         let record = tracker.get("agent-001").unwrap();
         assert!(record.score > 100); // Above baseline
     }
 
+    #[test]
+    fn test_reputation_weights() {
+        let events = [
+            ReputationEvent::TradeSuccess { pnl_bps: 750, volume_usd: 45_000 },
+            ReputationEvent::TradeFailed { loss_bps: 3_000 },
+            ReputationEvent::GovernanceVote,
+            ReputationEvent::ProposalCreated { passed: false },
+            ReputationEvent::AttestationVerified,
+            ReputationEvent::AttestationExpired,
+            ReputationEvent::SyndicateContribution { amount: 15_000 },
+            ReputationEvent::UptimeReward { hours: 100 },
+            ReputationEvent::Slashed { reason: "test".into(), amount: 40 },
+            ReputationEvent::ManualAdjustment { reason: "test".into(), amount: 7 },
+        ];
+        let deltas = |weights: &ReputationWeights| {
+            events.iter().map(|e| e.reputation_delta_with(weights)).collect::<Vec<_>>()
+        };
+        assert_eq!(deltas(&ReputationWeights::default()), [11, -15, 1, -2, 10, -20, 15, 4, -40, 7]);
+        assert_eq!(deltas(&ReputationWeights::conservative()), [5, -30, 1, -3, 5, -30, 7, 2, -60, 7]);
+
+        let mut tracker = ReputationTracker::new().with_weights(ReputationWeights::conservative());
+        assert_eq!(tracker.apply_event_full("agent", ReputationEvent::AttestationVerified).unwrap().record.delta, 5);
+        assert_eq!(tracker.score("agent"), 105);
+    }
+
+    #[test]
+    fn test_leaderboard_reflects_new_events() {
+        let mut tracker = ReputationTracker::new().with_clock(MockClock::new(1_000));
+        tracker.apply_event_full("alice", ReputationEvent::AttestationVerified).unwrap();
+        tracker.apply_event_full("bob", ReputationEvent::GovernanceVote).unwrap();
+        let top = |tracker: &mut ReputationTracker| {
+            tracker.leaderboard(3).iter().map(|(id, _)| id.to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(top(&mut tracker), ["alice", "bob"]);
+
+        tracker.apply_event_full("bob", ReputationEvent::ManualAdjustment { reason: "bonus".into(), amount: 200 }).unwrap();
+        tracker.apply_event_full("carol", ReputationEvent::ProofSubmitted).unwrap();
+        assert_eq!(top(&mut tracker), ["bob", "alice", "carol"]);
+    }
+
+    #[test]
+    fn test_leaderboard_pages_and_ranks() {
+        let mut tracker = ReputationTracker::new().with_clock(MockClock::new(1_000));
+        for i in 0..10_000u32 {
+            tracker.get_or_create(&format!("agent-{:05}", i)).score = (i * 7919) % 1001;
+        }
+        let full: Vec<LeaderboardEntry> = tracker.leaderboard_page(0, usize::MAX);
+        assert_eq!(full.len(), 10_000);
+        for (pair, index) in full.windows(2).zip(1..) {
+            assert!(pair[0].score > pair[1].score || (pair[0].score == pair[1].score && pair[0].agent_id < pair[1].agent_id));
+            assert_eq!(pair[0].rank, index);
+        }
+
+        let page = tracker.leaderboard_page(100, 100);
+        assert_eq!(page.len(), 100);
+        assert_eq!(page.first().unwrap(), &full[100]);
+        assert_eq!(page.last().unwrap(), &full[199]);
+        assert_eq!(tracker.leaderboard_page(9_990, 100).len(), 10);
+        assert!(tracker.leaderboard_page(10_000, 10).is_empty());
+
+        for entry in full.iter().step_by(97) {
+            assert_eq!(tracker.rank_of(&entry.agent_id), Some(entry.rank));
+        }
+        assert_eq!(tracker.rank_of("unknown"), None);
+    }
+
+    #[test]
+    fn test_leaderboard_ties_are_stable() {
+        let mut tracker = ReputationTracker::new().with_clock(MockClock::new(1_000));
+        // Equal scores reached through different numbers of events
+        for i in 0..300u32 {
+            let agent = format!("agent-{:03}", (i * 113) % 300);
+            match i % 3 {
+                0 => { tracker.apply_event_full(&agent, ReputationEvent::AttestationVerified).unwrap(); }
+                1 => {
+                    for _ in 0..5 {
+                        tracker.apply_event_full(&agent, ReputationEvent::ProofSubmitted).unwrap();
+                    }
+                    tracker.apply_event_full(&agent, ReputationEvent::ManualAdjustment { reason: "even".into(), amount: -15 }).unwrap();
+                }
+                _ => {
+                    for _ in 0..10 {
+                        tracker.apply_event_full(&agent, ReputationEvent::GovernanceVote).unwrap();
+                    }
+                }
+            }
+        }
+        assert!(tracker.export().iter().all(|r| r.score == 110));
+
+        let board = tracker.leaderboard_page(0, usize::MAX);
+        for pair in board.windows(2) {
+            let (a, b) = (tracker.get(&pair[0].agent_id).unwrap(), tracker.get(&pair[1].agent_id).unwrap());
+            assert!(a.total_events > b.total_events || (a.total_events == b.total_events && a.agent_id < b.agent_id));
+        }
+        assert_eq!(tracker.get(&board[0].agent_id).unwrap().total_events, 10);
+        assert_eq!(tracker.get(&board[299].agent_id).unwrap().total_events, 1);
+
+        for _ in 0..5 {
+            tracker.invalidate_leaderboard();
+            assert_eq!(tracker.leaderboard_page(0, usize::MAX), board);
+        }
+        let mut saved = Vec::new();
+        tracker.save_to(&mut saved).unwrap();
+        let mut loaded = ReputationTracker::load_from(saved.as_slice()).unwrap();
+        assert_eq!(loaded.leaderboard_page(0, usize::MAX), board);
+        for entry in &board {
+            assert_eq!(loaded.rank_of(&entry.agent_id), Some(entry.rank));
+        }
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let mut tracker = ReputationTracker::new();
+        assert_eq!(tracker.percentile("alone"), None);
+        tracker.get_or_create("alone");
+        assert_eq!(tracker.percentile("alone"), Some(50.0));
+        assert_eq!(tracker.percentile_within_level("alone"), Some(50.0));
+
+        for i in 0..9 {
+            tracker.get_or_create(&format!("tied-{}", i));
+        }
+        assert!(tracker.export().iter().all(|r| tracker.percentile(&r.agent_id) == Some(50.0)));
+
+        let near = |actual: Option<f64>, expected: f64| (actual.unwrap() - expected).abs() < 1e-9;
+        let mut tracker = ReputationTracker::new();
+        for score in 0..1000 {
+            let record = tracker.get_or_create(&format!("agent-{:03}", score));
+            record.score = score;
+            record.level = ReputationLevel::from_score(score);
+        }
+        assert!(near(tracker.percentile("agent-000"), 0.05));
+        assert!(near(tracker.percentile("agent-500"), 50.05));
+        assert!(near(tracker.percentile("agent-999"), 99.95));
+        // Verified covers 200-399
+        assert!(near(tracker.percentile_within_level("agent-200"), 0.25));
+        assert!(near(tracker.percentile_within_level("agent-399"), 99.75));
+        assert!(near(tracker.percentile_within_level("agent-999"), 99.75));
+    }
+
     #[test]
     fn test_reputation_bounds() {
         let mut record = ReputationRecord::new("test".into());
@@ -396,4 +4013,1443 @@ mod tests {
         assert!(delta < 0);
         assert_eq!(record.score, 0);
     }
+
+    #[test]
+    fn test_save_and_load() {
+        let mut tracker = ReputationTracker::new().with_clock(MockClock::new(1_000));
+        for i in 0..1_000 {
+            let agent = format!("agent-{}", i);
+            for j in 0..100 {
+                let event = if (i + j) % 3 == 0 {
+                    ReputationEvent::TradeFailed { loss_bps: 400 }
+                } else {
+                    ReputationEvent::TradeSuccess { pnl_bps: i as i64, volume_usd: 20_000 }
+                };
+                tracker.apply_event_full(&agent, event).unwrap();
+            }
+            tracker.apply_event_full(&agent, ReputationEvent::ProofSubmitted).unwrap();
+        }
+
+        let mut saved = Vec::new();
+        tracker.save_to(&mut saved).unwrap();
+        let loaded = ReputationTracker::load_from(saved.as_slice()).unwrap();
+        assert_eq!(loaded.agent_count(), 1_000);
+        for original in tracker.export() {
+            let record = loaded.get(&original.agent_id).unwrap();
+            assert_eq!(record.score, original.score);
+            assert_eq!(record.level, original.level);
+            assert_eq!(record.total_events, 101);
+            assert_eq!(record.history.len(), 100);
+        }
+
+        // Merging keeps whichever record is newer
+        let mut other = ReputationTracker::new().with_clock(MockClock::new(2_000));
+        other.apply_event_full("agent-0", ReputationEvent::AttestationVerified).unwrap();
+        other.apply_event_full("newcomer", ReputationEvent::GovernanceVote).unwrap();
+        let mut later = Vec::new();
+        other.save_to(&mut later).unwrap();
+        assert_eq!(tracker.merge_loaded(later.as_slice()).unwrap(), 2);
+        assert_eq!(tracker.get("agent-0").unwrap().total_events, 1);
+        assert_eq!(tracker.agent_count(), 1_001);
+        assert_eq!(tracker.merge_loaded(saved.as_slice()).unwrap(), 0);
+
+        assert!(matches!(
+            ReputationTracker::load_from(&b"{\"version\":1,\"records\":[{"[..]),
+            Err(ReputationError::Corrupt(_))
+        ));
+        let mut miscounted: serde_json::Value = serde_json::from_slice(&later).unwrap();
+        miscounted["records"][0]["total_events"] = 0.into();
+        assert!(matches!(
+            ReputationTracker::load_from(miscounted.to_string().as_bytes()),
+            Err(ReputationError::Corrupt(ref reason)) if reason.contains("total_events")
+        ));
+        assert!(matches!(
+            ReputationTracker::load_from(&b"{\"version\":2,\"records\":[],\"extra\":true}"[..]),
+            Err(ReputationError::UnsupportedVersion { found: 2, supported: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_merge_trackers() {
+        let clock = MockClock::new(1_000);
+        let region = |events: &[(&str, u64, i32, Option<&str>)]| {
+            let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+            for &(agent, at, amount, key) in events {
+                clock.set(at);
+                let event = ReputationEvent::ManualAdjustment { reason: "test".into(), amount };
+                match key {
+                    Some(key) => { tracker.apply_event_with_key(agent, event, key).unwrap(); }
+                    None => { tracker.apply_event_full(agent, event).unwrap(); }
+                }
+            }
+            tracker
+        };
+        let east = || region(&[("shared", 1_000, 50, Some("k1")), ("shared", 1_020, 30, None), ("east", 1_010, 5, None)]);
+        let west = || region(&[("shared", 1_005, 20, None), ("shared", 1_020, -10, None), ("shared", 1_030, 50, Some("k1")), ("west", 1_000, 7, None)]);
+
+        let mut merged = east();
+        merged.leaderboard(10);
+        assert_eq!(merged.merge(west(), MergeStrategy::SumDeltas), 2);
+        let shared = merged.get("shared").unwrap();
+        // k1 was already applied in the east, so the west's copy is dropped
+        assert_eq!(shared.score, 100 + 50 + 20 + 30 - 10);
+        assert_eq!(shared.total_events, 4);
+        assert_eq!(shared.last_updated, 1_030);
+        let timeline: Vec<(u64, i32, u32)> = shared.history.iter().map(|e| (e.timestamp, e.delta, e.score_after)).collect();
+        assert_eq!(timeline, [(1_000, 50, 150), (1_005, 20, 170), (1_020, 30, 200), (1_020, -10, 190)]);
+        assert_eq!((merged.score("east"), merged.score("west")), (105, 107));
+        assert_eq!(merged.leaderboard(1)[0].0, "shared");
+
+        // Replayed events go through this tracker's rate limits
+        clock.set(1_000);
+        let mut limited = ReputationTracker::new()
+            .with_clock(clock.clone())
+            .with_rate_limit(ReputationEventKind::ManualAdjustment, RateLimit::points_per_day(60));
+        limited.apply_event_full("shared", ReputationEvent::ManualAdjustment { reason: "test".into(), amount: 50 }).unwrap();
+        limited.merge(west(), MergeStrategy::SumDeltas);
+        let deltas: Vec<i32> = limited.get("shared").unwrap().history.iter().map(|e| e.delta).collect();
+        assert_eq!(deltas, [50, 10, -10, 0]);
+        assert_eq!(limited.score("shared"), 150);
+
+        // Replaying an already applied keyed event changes nothing
+        let mut twice = region(&[("shared", 1_000, 50, Some("k1"))]);
+        assert_eq!(twice.merge(region(&[("shared", 1_000, 50, Some("k1"))]), MergeStrategy::SumDeltas), 0);
+        assert_eq!(twice.score("shared"), 150);
+
+        let mut newest = east();
+        assert_eq!(newest.merge(west(), MergeStrategy::TakeNewest), 2);
+        assert_eq!(newest.score("shared"), 100 + 20 - 10 + 50);
+        assert_eq!(newest.agent_count(), 3);
+
+        let mut highest = west();
+        assert_eq!(highest.merge(east(), MergeStrategy::MaxScore), 2);
+        assert_eq!(highest.score("shared"), 180);
+
+        // A clamped first event doesn't skew the replayed scores
+        clock.set(1_000);
+        let mut slashed = ReputationTracker::new().with_clock(clock.clone());
+        slashed.apply_event_full("agent", ReputationEvent::Slashed { reason: "test".into(), amount: 500 }).unwrap();
+        assert_eq!(slashed.score("agent"), 0);
+        slashed.merge(region(&[("agent", 900, 1, None)]), MergeStrategy::SumDeltas);
+        let scores: Vec<u32> = slashed.get("agent").unwrap().history.iter().map(|e| e.score_after).collect();
+        assert_eq!(scores, [101, 0]);
+        assert_eq!(slashed.score("agent"), 0);
+
+        // Identical timestamps resolve the same way whichever side merges
+        let a = || region(&[("tie", 2_000, 10, None)]);
+        let b = || region(&[("tie", 2_000, 20, None)]);
+        let mut ab = a();
+        ab.merge(b(), MergeStrategy::TakeNewest);
+        let mut ba = b();
+        ba.merge(a(), MergeStrategy::TakeNewest);
+        assert_eq!((ab.score("tie"), ba.score("tie")), (120, 120));
+
+        // Skipped duplicates don't free IDs the merged events still use
+        let mut base = region(&[("agent", 1_000, 50, Some("k1"))]);
+        base.merge(region(&[("agent", 1_000, 50, Some("k1")), ("agent", 1_005, 20, None)]), MergeStrategy::SumDeltas);
+        clock.set(1_010);
+        let next = base.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap();
+        let ids: Vec<u64> = base.get("agent").unwrap().history.iter().map(|e| e.event_id).collect();
+        assert_eq!(ids, [1, 2, 3]);
+        assert_eq!(next.record.event_id, 3);
+        let reversal = base.reverse_event("agent", 2, "dispute").unwrap();
+        assert!(matches!(reversal.record.event, ReputationEvent::Reversal { event_id: 2, amount: -20, .. }));
+    }
+
+    #[test]
+    fn test_idempotency_keys() {
+        let clock = MockClock::new(1_000);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone()).with_dedup_window(60);
+        let trade = || ReputationEvent::TradeSuccess { pnl_bps: 500, volume_usd: 0 };
+
+        assert_eq!(tracker.apply_event_with_key("agent", trade(), "msg-1").unwrap(), ApplyOutcome::Applied(5));
+        clock.advance(30);
+        assert_eq!(
+            tracker.apply_event_with_key("agent", trade(), "msg-1").unwrap(),
+            ApplyOutcome::AlreadyApplied { applied_at: 1_000 }
+        );
+        assert_eq!(tracker.apply_event_with_key("agent", trade(), "msg-2").unwrap(), ApplyOutcome::Applied(5));
+        // Keys are per agent
+        assert_eq!(tracker.apply_event_with_key("other", trade(), "msg-1").unwrap(), ApplyOutcome::Applied(5));
+        assert_eq!(tracker.score("agent"), 110);
+
+        // Keys survive a restart
+        let mut saved = Vec::new();
+        tracker.save_to(&mut saved).unwrap();
+        let mut tracker = ReputationTracker::load_from(saved.as_slice()).unwrap()
+            .with_clock(clock.clone())
+            .with_dedup_window(60);
+        assert!(matches!(tracker.apply_event_with_key("agent", trade(), "msg-2").unwrap(), ApplyOutcome::AlreadyApplied { .. }));
+
+        // Reusable once the window has passed
+        clock.advance(30);
+        assert_eq!(tracker.apply_event_with_key("agent", trade(), "msg-1").unwrap(), ApplyOutcome::Applied(5));
+        assert_eq!(tracker.score("agent"), 115);
+    }
+
+    #[test]
+    fn test_endorsements() {
+        use sdkey_manager::{AgentMetadata, AgentPermissions};
+
+        let clock = MockClock::new(1_000);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+        let key = || AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let (elite, verified, newcomer) = (key(), key(), key());
+
+        let endorse = |tracker: &mut ReputationTracker, sdkey: &AgentSDKey, endorsee: &str, weight, score| {
+            let endorsement = Endorsement::sign(sdkey, endorsee, weight, clock.now()).unwrap();
+            tracker.apply_endorsement(&endorsement, &sdkey.public_key(), score)
+        };
+
+        // Higher-level endorsers grant more
+        assert_eq!(endorse(&mut tracker, &verified, "agent", 5, 300).unwrap(), 5);
+        assert_eq!(endorse(&mut tracker, &elite, "agent", 5, 900).unwrap(), 20);
+        assert_eq!(tracker.score("agent"), 125);
+        let record = tracker.get("agent").unwrap();
+        assert!(matches!(&record.history.back().unwrap().event, ReputationEvent::Endorsed { points: 20, .. }));
+
+        assert!(matches!(endorse(&mut tracker, &elite, "agent", 5, 900), Err(ReputationError::DuplicateEndorsement)));
+        assert!(matches!(endorse(&mut tracker, &newcomer, "agent", 5, 100), Err(ReputationError::EndorserLevelTooLow)));
+        assert!(matches!(endorse(&mut tracker, &elite, "other", 11, 900), Err(ReputationError::InvalidEndorsementWeight(11))));
+        let own_id = elite.agent_id();
+        assert!(matches!(endorse(&mut tracker, &elite, &own_id, 5, 900), Err(ReputationError::SelfEndorsement)));
+
+        let mut forged = Endorsement::sign(&elite, "other", 5, 1_000).unwrap();
+        forged.weight = 10;
+        assert!(matches!(
+            tracker.apply_endorsement(&forged, &elite.public_key(), 900),
+            Err(ReputationError::InvalidEndorsementSignature)
+        ));
+        assert!(matches!(
+            tracker.apply_endorsement(&forged, &verified.public_key(), 900),
+            Err(ReputationError::EndorserKeyMismatch(_))
+        ));
+
+        // The per-window cap cuts the grant that crosses it
+        let legend = key();
+        assert_eq!(endorse(&mut tracker, &legend, "agent", 10, 1000).unwrap(), 25);
+        assert!(matches!(endorse(&mut tracker, &key(), "agent", 1, 900), Err(ReputationError::EndorsementCapReached)));
+
+        // A fresh window reopens both the cap and the pair
+        let replayed = Endorsement::sign(&elite, "agent", 5, 1_000).unwrap();
+        clock.advance(ENDORSEMENT_WINDOW_SECS);
+        assert_eq!(endorse(&mut tracker, &elite, "agent", 5, 900).unwrap(), 20);
+
+        // Old endorsements can't be replayed, nor future-dated ones banked
+        assert!(matches!(
+            tracker.apply_endorsement(&replayed, &elite.public_key(), 900),
+            Err(ReputationError::EndorsementOutOfWindow(1_000))
+        ));
+        let now = clock.now();
+        let early = Endorsement::sign(&verified, "agent", 5, now + MAX_ENDORSEMENT_CLOCK_SKEW_SECS + 1).unwrap();
+        assert!(matches!(
+            tracker.apply_endorsement(&early, &verified.public_key(), 300),
+            Err(ReputationError::EndorsementOutOfWindow(_))
+        ));
+
+        // Nor can the same signature be used twice
+        let endorsement = Endorsement::sign(&verified, "agent", 5, now).unwrap();
+        tracker.apply_endorsement(&endorsement, &verified.public_key(), 300).unwrap();
+        tracker.endorsements.get_mut("agent").unwrap().last_mut().unwrap().endorser = "someone".into();
+        assert!(matches!(
+            tracker.apply_endorsement(&endorsement, &verified.public_key(), 300),
+            Err(ReputationError::DuplicateEndorsement)
+        ));
+    }
+
+    #[test]
+    fn test_level_change_notifications() {
+        let clock = MockClock::new(1_000);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+        let changes = tracker.subscribe();
+        drop(tracker.subscribe());
+
+        let adjust = |amount| ReputationEvent::ManualAdjustment { reason: "test".into(), amount };
+        tracker.apply_event_full("agent", adjust(50)).unwrap(); // 150, still Newcomer
+        tracker.apply_event_full("agent", adjust(100)).unwrap(); // 250
+        clock.advance(10);
+        tracker.apply_event_full("agent", adjust(200)).unwrap(); // 450
+        tracker.apply_event_full("agent", adjust(10)).unwrap(); // 460, same level
+        clock.advance(10);
+        tracker.apply_event_full("agent", adjust(-300)).unwrap(); // 160
+        tracker.apply_event_full("other", adjust(-100)).unwrap(); // 0, same level
+
+        let seen: Vec<_> = changes.try_iter()
+            .map(|c| (c.agent_id, c.old_level, c.new_level, c.timestamp))
+            .collect();
+        assert_eq!(seen, [
+            ("agent".to_string(), ReputationLevel::Newcomer, ReputationLevel::Verified, 1_000),
+            ("agent".to_string(), ReputationLevel::Verified, ReputationLevel::Established, 1_010),
+            ("agent".to_string(), ReputationLevel::Established, ReputationLevel::Newcomer, 1_020),
+        ]);
+        assert_eq!(tracker.subscribers.len(), 1);
+
+        drop(changes);
+        tracker.apply_event_full("agent", adjust(100)).unwrap();
+        assert!(tracker.subscribers.is_empty());
+    }
+
+    #[test]
+    fn test_probation() {
+        const DAY: u64 = 86_400;
+        let clock = MockClock::new(1_000);
+        let mut tracker = ReputationTracker::new()
+            .with_clock(clock.clone())
+            .with_probation_policy(ProbationPolicy::default());
+        let slash = |amount| ReputationEvent::Slashed { reason: "test".into(), amount };
+        tracker.apply_event_full("agent", ReputationEvent::ManualAdjustment { reason: "seed".into(), amount: 400 }).unwrap();
+
+        // Light slashes don't count
+        tracker.apply_event_full("agent", slash(49)).unwrap();
+        assert!(!tracker.is_on_probation("agent"));
+        assert_eq!(tracker.apply_event_full("agent", ReputationEvent::AttestationVerified).unwrap().record.delta, 10);
+
+        tracker.apply_event_full("agent", slash(50)).unwrap();
+        assert!(tracker.is_on_probation("agent"));
+        assert_eq!(tracker.apply_event_full("agent", ReputationEvent::AttestationVerified).unwrap().record.delta, 5);
+        assert_eq!(tracker.apply_event_full("agent", ReputationEvent::AttestationExpired).unwrap().record.delta, -20);
+        assert_eq!(tracker.agents_on_probation().len(), 1);
+        let flagged = tracker.agents_at_level(ReputationLevel::Verified);
+        assert_eq!(flagged[0].on_probation_until, Some(1_000 + 30 * DAY));
+        let summary = &tracker.query_agents_at_level(ReputationLevel::Verified, &AgentQuery::default()).items[0];
+        assert!(summary.on_probation);
+        assert_eq!(summary.highest_level_achieved, tracker.highest_level("agent").unwrap());
+
+        // Slashing again extends the remaining probation
+        clock.advance(10 * DAY);
+        tracker.apply_event_full("agent", slash(60)).unwrap();
+        assert_eq!(tracker.get("agent").unwrap().on_probation_until, Some(1_000 + 60 * DAY));
+        clock.advance(49 * DAY);
+        assert!(tracker.is_on_probation("agent"));
+        clock.advance(DAY);
+        assert!(!tracker.is_on_probation("agent"));
+        assert!(matches!(tracker.clear_probation("agent", "appeal"), Err(ReputationError::NotOnProbation(_))));
+
+        tracker.apply_event_full("agent", slash(100)).unwrap();
+        tracker.clear_probation("agent", "appeal upheld").unwrap();
+        assert!(!tracker.is_on_probation("agent"));
+        let last = tracker.get("agent").unwrap().history.back().unwrap();
+        assert!(matches!(&last.event, ReputationEvent::ProbationCleared { reason } if reason == "appeal upheld"));
+        assert_eq!(last.delta, 0);
+        assert_eq!(tracker.apply_event_full("agent", ReputationEvent::AttestationVerified).unwrap().record.delta, 10);
+
+        // A slash counts at the amount asked for, not as weighted
+        let mut lenient = ReputationTracker::new()
+            .with_weights(ReputationWeights { slash_multiplier_bps: 5_000, ..ReputationWeights::default() })
+            .with_probation_policy(ProbationPolicy::default());
+        assert_eq!(lenient.apply_event_full("agent", slash(60)).unwrap().record.delta, -30);
+        assert!(lenient.is_on_probation("agent"));
+    }
+
+    #[test]
+    fn test_subscores() {
+        let mut tracker = ReputationTracker::new();
+        tracker.apply_event_full("agent", ReputationEvent::TradeSuccess { pnl_bps: 1000, volume_usd: 0 }).unwrap();
+        tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap();
+        tracker.apply_event_full("agent", ReputationEvent::AttestationExpired).unwrap();
+        tracker.apply_event_full("agent", ReputationEvent::Slashed { reason: "test".into(), amount: 5 }).unwrap();
+
+        // Without weights the headline score is unchanged
+        assert_eq!(tracker.score("agent"), 100 + 10 + 1 - 20 - 5);
+        assert_eq!(tracker.subscore("agent", Dimension::Trading), 105);
+        assert_eq!(tracker.subscore("agent", Dimension::Governance), 96);
+        assert_eq!(tracker.subscore("agent", Dimension::Reliability), 75);
+        assert_eq!(tracker.subscore("nobody", Dimension::Trading), 0);
+
+        let mut tracker = ReputationTracker::new()
+            .with_dimension_weights(DimensionWeights { trading: 2, governance: 1, reliability: 1 });
+        tracker.apply_event_full("trader", ReputationEvent::ManualAdjustment { reason: "seed".into(), amount: 100 }).unwrap();
+        assert_eq!(tracker.score("trader"), 200);
+        tracker.apply_event_full("trader", ReputationEvent::TradeSuccess { pnl_bps: 0, volume_usd: 40_000 }).unwrap();
+        assert_eq!(tracker.subscore("trader", Dimension::Trading), 204);
+        assert_eq!(tracker.score("trader"), 202);
+        tracker.apply_event_full("voter", ReputationEvent::ManualAdjustment { reason: "seed".into(), amount: 300 }).unwrap();
+        for _ in 0..400 {
+            tracker.apply_event_full("voter", ReputationEvent::GovernanceVote).unwrap();
+        }
+        assert_eq!(tracker.subscore("voter", Dimension::Governance), 800);
+        assert_eq!(tracker.score("voter"), 500);
+        assert_eq!(tracker.level("voter"), ReputationLevel::Established);
+
+        let elite_governance = tracker.agents_at_dimension_level(Dimension::Governance, ReputationLevel::Elite);
+        assert_eq!(elite_governance.len(), 1);
+        assert_eq!(elite_governance[0].agent_id, "voter");
+        assert!(tracker.agents_at_dimension_level(Dimension::Trading, ReputationLevel::Trusted).is_empty());
+
+        // Records saved without subscores start them at the headline score
+        let mut json = serde_json::to_value(tracker.get("voter").unwrap()).unwrap();
+        json.as_object_mut().unwrap().remove("subscores");
+        let restored: ReputationRecord = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.subscores, Subscores::uniform(500));
+        let round_trip: ReputationRecord =
+            serde_json::from_str(&serde_json::to_string(tracker.get("voter").unwrap()).unwrap()).unwrap();
+        assert_eq!(round_trip.subscore(Dimension::Governance), 800);
+    }
+
+    #[test]
+    fn test_attestations() {
+        use sdkey_manager::{AgentMetadata, AgentPermissions};
+
+        let clock = MockClock::new(1_000);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+        tracker.apply_event_full("agent", ReputationEvent::ManualAdjustment { reason: "seed".into(), amount: 350 }).unwrap();
+        let operator = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let other = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+
+        assert!(matches!(tracker.attest("nobody", &operator, 60), Err(ReputationError::AgentNotFound(_))));
+        let attestation = tracker.attest("agent", &operator, 3_600).unwrap();
+        assert_eq!((attestation.score, attestation.level), (450, ReputationLevel::Established));
+        assert_eq!((attestation.issued_at, attestation.expires_at), (1_000, 4_600));
+
+        // Verifiable by a third party from its JSON form alone
+        let json = serde_json::to_string(&attestation).unwrap();
+        let received: ReputationAttestation = serde_json::from_str(&json).unwrap();
+        received.verify_at(&operator.public_key(), 4_599).unwrap();
+        assert!(matches!(
+            received.verify_at(&operator.public_key(), 4_600),
+            Err(ReputationError::AttestationExpired { expires_at: 4_600 })
+        ));
+        assert!(matches!(
+            received.verify_at(&other.public_key(), 2_000),
+            Err(ReputationError::AttestationIssuerMismatch)
+        ));
+
+        // Claiming another issuer, or editing the score, breaks the signature
+        let mut forged = tracker.attest("agent", &other, 3_600).unwrap();
+        forged.issuer_pubkey = operator.public_key_hex();
+        assert!(matches!(
+            forged.verify_at(&operator.public_key(), 2_000),
+            Err(ReputationError::InvalidAttestationSignature)
+        ));
+        let mut tampered = received.clone();
+        tampered.score = 999;
+        assert!(matches!(
+            tampered.verify_at(&operator.public_key(), 2_000),
+            Err(ReputationError::InvalidAttestationSignature)
+        ));
+    }
+
+    #[test]
+    fn test_remove_agents() {
+        const YEAR: u64 = 365 * 86_400;
+        let clock = MockClock::new(1_000);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+        for agent in ["alice", "bob", "carol"] {
+            tracker.apply_event_with_key(agent, ReputationEvent::AttestationVerified, "k1").unwrap();
+        }
+        tracker.apply_event_full("bob", ReputationEvent::AttestationVerified).unwrap();
+        tracker.endorsements.entry("carol".into()).or_default().push(EndorsementGrant {
+            endorser: "bob".into(),
+            granted_at: 1_000,
+            points: 10,
+            signature: Vec::new(),
+        });
+        assert_eq!(tracker.leaderboard(3)[0].0, "bob");
+
+        assert!(tracker.remove("nobody").is_empty());
+        let removed = tracker.remove("bob");
+        assert_eq!(removed.record.unwrap().score, 120);
+        let board: Vec<&String> = tracker.leaderboard(3).into_iter().map(|(id, _)| id).collect();
+        assert_eq!(board, ["alice", "carol"]);
+        assert!(!tracker.seen_keys.contains_key("bob"));
+        // bob's grant still counts toward carol's endorsement cap
+        assert_eq!(tracker.endorsements["carol"].len(), 1);
+
+        // A forgotten agent starts over
+        assert_eq!(tracker.get_or_create("bob").score, 100);
+        assert!(matches!(
+            tracker.apply_event_with_key("bob", ReputationEvent::AttestationVerified, "k1").unwrap(),
+            ApplyOutcome::Applied(10)
+        ));
+
+        clock.advance(YEAR + 1);
+        tracker.apply_event_full("carol", ReputationEvent::GovernanceVote).unwrap();
+        let now = clock.now();
+        assert_eq!(tracker.retain(|r| now - r.last_updated <= YEAR), 2);
+        assert_eq!(tracker.agent_count(), 1);
+        assert_eq!(tracker.leaderboard_page(0, 10).len(), 1);
+        assert_eq!(tracker.seen_keys.keys().collect::<Vec<_>>(), ["carol"]);
+
+        // An agent known only in a context is still forgotten
+        tracker.apply_event_in_context("dave", "trading", ReputationEvent::GovernanceVote).unwrap();
+        tracker.endorsements.entry("dave".into()).or_default().push(EndorsementGrant {
+            endorser: "carol".into(),
+            granted_at: now,
+            points: 5,
+            signature: Vec::new(),
+        });
+        let removed = tracker.remove("dave");
+        assert!(removed.record.is_none());
+        assert!(!removed.is_empty());
+        assert!(removed.context_records.contains_key("trading"));
+        assert_eq!(removed.endorsements_received, 1);
+        assert!(tracker.get_in("dave", "trading").is_none());
+        assert!(!tracker.contexts.contains_key("trading"));
+        assert!(!tracker.endorsements.contains_key("dave"));
+    }
+
+    #[test]
+    fn test_recovery_schedule() {
+        let clock = MockClock::new(1_000);
+        let mut tracker = ReputationTracker::new()
+            .with_clock(clock.clone())
+            .with_probation_policy(ProbationPolicy::default());
+        let slash = |amount| ReputationEvent::Slashed { reason: "test".into(), amount };
+        tracker.apply_event_full("agent", ReputationEvent::ManualAdjustment { reason: "seed".into(), amount: 300 }).unwrap();
+        tracker.apply_event_full("agent", slash(100)).unwrap();
+        assert!(matches!(tracker.schedule_recovery("nobody", 10, 10), Err(ReputationError::AgentNotFound(_))));
+        assert!(matches!(tracker.schedule_recovery("agent", 10, 0), Err(ReputationError::InvalidRecoveryPlan)));
+
+        // Full recovery, unaffected by probation, in uneven installments
+        tracker.schedule_recovery("agent", 100, 3).unwrap();
+        assert_eq!(tracker.process_recoveries(1_001), 1);
+        assert_eq!(tracker.score("agent"), 333);
+        assert_eq!(tracker.process_recoveries(1_001), 0);
+        tracker.process_recoveries(1_002);
+        assert_eq!(tracker.recovery("agent").unwrap().remaining(), 34);
+        tracker.process_recoveries(5_000);
+        assert_eq!(tracker.score("agent"), 400);
+        assert!(tracker.recovery("agent").is_none());
+        assert_eq!(tracker.process_recoveries(6_000), 0);
+        assert!(matches!(
+            tracker.get("agent").unwrap().history.back().unwrap().event,
+            ReputationEvent::ScheduledRecovery { amount: 34 }
+        ));
+
+        // A slash pauses the plan; paused time doesn't count
+        clock.set(10_000);
+        tracker.schedule_recovery("agent", 100, 1_000).unwrap();
+        tracker.process_recoveries(10_250);
+        clock.set(10_300);
+        tracker.apply_event_full("agent", slash(10)).unwrap();
+        assert!(tracker.recovery("agent").unwrap().is_paused());
+        assert_eq!(tracker.process_recoveries(10_600), 0);
+        assert_eq!(tracker.score("agent"), 400 + 25 - 10);
+
+        // The plan survives a save and load
+        let mut saved = Vec::new();
+        tracker.save_to(&mut saved).unwrap();
+        let mut tracker = ReputationTracker::load_from(saved.as_slice()).unwrap().with_clock(clock.clone());
+        clock.set(10_700);
+        tracker.resume_recovery("agent").unwrap();
+        tracker.process_recoveries(10_900);
+        assert_eq!(tracker.recovery("agent").unwrap().granted, 50);
+        tracker.process_recoveries(20_000);
+        assert_eq!(tracker.score("agent"), 400 + 100 - 10);
+        assert!(matches!(tracker.resume_recovery("agent"), Err(ReputationError::RecoveryNotFound(_))));
+    }
+
+    #[test]
+    fn test_event_validation() {
+        let mut tracker = ReputationTracker::new();
+        let huge_trade = ReputationEvent::TradeSuccess { pnl_bps: 100, volume_usd: u64::MAX };
+        let zero_slash = ReputationEvent::Slashed { reason: "test".into(), amount: 0 };
+        assert_eq!(tracker.apply_event_full("agent", huge_trade.clone()).unwrap().record.delta, 6);
+        assert!(matches!(
+            tracker.apply_event_full("agent", zero_slash.clone()),
+            Err(ReputationError::InvalidEvent { kind: ReputationEventKind::Slashed, field: "amount", .. })
+        ));
+
+        let mut tracker = ReputationTracker::new().with_validator(EventValidator::strict());
+        let err = tracker.apply_event_full("agent", huge_trade).unwrap_err();
+        assert!(matches!(err, ReputationError::InvalidEvent { field: "volume_usd", .. }));
+        assert_eq!(
+            err.to_string(),
+            format!("Invalid TradeSuccess event: volume_usd is {}, above the limit of 1000000000", u64::MAX)
+        );
+        let unexplained = ReputationEvent::Slashed { reason: " ".into(), amount: 10 };
+        assert!(matches!(
+            tracker.apply_event_full("agent", unexplained),
+            Err(ReputationError::InvalidEvent { field: "reason", .. })
+        ));
+        assert!(matches!(
+            tracker.apply_event_full("agent", ReputationEvent::UptimeReward { hours: 1_000 }),
+            Err(ReputationError::InvalidEvent { field: "hours", .. })
+        ));
+        assert!(matches!(
+            tracker.apply_event_with_key("agent", zero_slash, "k1"),
+            Err(ReputationError::InvalidEvent { .. })
+        ));
+        assert!(tracker.get("agent").is_none());
+
+        // A rejected keyed event can be retried under the same key
+        let outcome = tracker.apply_event_with_key("agent", ReputationEvent::GovernanceVote, "k1").unwrap();
+        assert!(matches!(outcome, ApplyOutcome::Applied(1)));
+    }
+
+    #[test]
+    fn test_rebuild_from_events() {
+        let stream: Vec<(String, ReputationEvent, u64)> = (0..400u64)
+            .map(|i| {
+                let event = match i % 4 {
+                    0 => ReputationEvent::TradeSuccess { pnl_bps: 500, volume_usd: 30_000 },
+                    1 => ReputationEvent::TradeFailed { loss_bps: 900 },
+                    2 => ReputationEvent::Slashed { reason: "test".into(), amount: 30 },
+                    _ => ReputationEvent::ManualAdjustment { reason: "bump".into(), amount: 40 },
+                };
+                (format!("agent-{}", i % 3), event, 1_000 + i * 3_600)
+            })
+            .collect();
+
+        let clock = MockClock::new(0);
+        let mut incremental = ReputationTracker::new().with_clock(clock.clone());
+        for (agent, event, at) in stream.clone() {
+            clock.set(at);
+            incremental.apply_event_full(&agent, event).unwrap();
+        }
+        let rebuilt = ReputationTracker::rebuild_from_events(stream.into_iter()).unwrap();
+        assert_eq!(rebuilt.agent_count(), 3);
+        for original in incremental.export() {
+            let record = rebuilt.get(&original.agent_id).unwrap();
+            assert_eq!(serde_json::to_value(record).unwrap(), serde_json::to_value(original).unwrap());
+            // History has been trimmed, so this replays from a checkpoint
+            assert!(!record.checkpoints.is_empty());
+            record.verify_consistency().unwrap();
+        }
+
+        let mut tampered = rebuilt.get("agent-1").unwrap().clone();
+        tampered.history[40].score_after += 5;
+        assert!(matches!(
+            tampered.verify_consistency(),
+            Err(ReputationError::InconsistentHistory { index: Some(40), .. })
+        ));
+        let mut tampered = rebuilt.get("agent-1").unwrap().clone();
+        tampered.score += 1;
+        assert!(matches!(
+            tampered.verify_consistency(),
+            Err(ReputationError::InconsistentHistory { index: None, .. })
+        ));
+        // Inflating a delta along with every score after it is still caught
+        let mut tampered = rebuilt.get("agent-1").unwrap().clone();
+        let last = tampered.history.len() - 1;
+        tampered.history[last].delta += 5;
+        tampered.history[last].score_after += 5;
+        tampered.score += 5;
+        assert!(matches!(
+            tampered.verify_consistency(),
+            Err(ReputationError::UnexpectedDelta { index, .. }) if index == last
+        ));
+
+        // Custom weights, probation and rate limits are all accounted for
+        let weights = ReputationWeights { governance_vote_points: 4, ..Default::default() };
+        let mut tracker = ReputationTracker::new()
+            .with_clock(clock.clone())
+            .with_weights(weights)
+            .with_probation_policy(ProbationPolicy::default())
+            .with_rate_limit(ReputationEventKind::GovernanceVote, RateLimit::points_per_day(5));
+        tracker.apply_event_full("agent", ReputationEvent::ManualAdjustment { reason: "seed".into(), amount: 300 }).unwrap();
+        tracker.apply_event_full("agent", ReputationEvent::Slashed { reason: "test".into(), amount: 100 }).unwrap();
+        for _ in 0..3 {
+            tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap();
+        }
+        let record = tracker.get("agent").unwrap();
+        let gains: Vec<(i32, Option<i32>)> = record.history.iter().skip(2).map(|e| (e.delta, e.clamped_from)).collect();
+        assert_eq!(gains, [(2, Some(4)), (2, Some(4)), (1, Some(4))]);
+        record.verify_consistency_with(&weights).unwrap();
+        assert!(matches!(record.verify_consistency(), Err(ReputationError::UnexpectedDelta { .. })));
+
+        let invalid = vec![("agent".to_string(), ReputationEvent::Slashed { reason: "test".into(), amount: 0 }, 1)];
+        assert!(ReputationTracker::rebuild_from_events(invalid.into_iter()).is_err());
+    }
+
+    #[test]
+    fn test_backfill_with_timestamps() {
+        let now = SystemClock.now();
+        let day = 86_400;
+        let mut record = ReputationRecord::new_at("agent".into(), now - 60 * day);
+        let seed = |amount| ReputationEvent::ManualAdjustment { reason: "seed".into(), amount };
+        record.apply_event_at(seed(100), now - 30 * day).unwrap();
+        record.apply_event_at(seed(50), now - 10 * day).unwrap();
+        record.apply_event_at(seed(20), now - 3 * day).unwrap();
+        record.apply_event_at(seed(-5), now - day).unwrap();
+        assert_eq!(record.weekly_trend(), 15);
+        assert_eq!(record.last_updated, now - day);
+        assert!(matches!(
+            record.apply_event_at(seed(1), now - 2 * day),
+            Err(ReputationError::OutOfOrderEvent { .. })
+        ));
+        assert_eq!(record.total_events, 4);
+
+        // Without a timestamp, a record updated ahead of now is stamped
+        // with its last update
+        let mut ahead = ReputationRecord::new_at("agent".into(), now + day);
+        assert_eq!(ahead.apply_event(seed(10)), 10);
+        assert_eq!(ahead.history[0].timestamp, now + day);
+        assert_eq!(ahead.last_updated, now + day);
+
+        let clock = MockClock::new(now);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+        tracker.apply_event_at("agent", seed(100), now - 30 * day).unwrap();
+        tracker.apply_event_at("agent", seed(20), now - 3 * day).unwrap();
+        assert!(tracker.apply_event_at("agent", seed(1), now - 4 * day).is_err());
+        tracker.apply_event_full("agent", seed(10)).unwrap();
+        let record = tracker.get("agent").unwrap();
+        assert_eq!(record.trend(7 * day, now).value, 30);
+        assert_eq!(record.last_updated, now);
+
+        clock.set(now + day);
+        tracker.apply_event_in_context("agent", "alpha", seed(10)).unwrap();
+        clock.set(now);
+        assert!(matches!(
+            tracker.apply_event_in_context("agent", "alpha", seed(1)),
+            Err(ReputationError::OutOfOrderEvent { .. })
+        ));
+        assert_eq!(tracker.get_in("agent", "alpha").unwrap().total_events, 1);
+    }
+
+    #[test]
+    fn test_reverse_event() {
+        let mut tracker = ReputationTracker::new();
+        tracker.apply_event_full("agent", ReputationEvent::ManualAdjustment { reason: "seed".into(), amount: -95 }).unwrap();
+        let slash = tracker.apply_event_full("agent", ReputationEvent::Slashed { reason: "wrongful".into(), amount: 100 }).unwrap();
+        assert_eq!(slash.record.delta, -100);
+        assert_eq!(tracker.score("agent"), 0);
+        tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap();
+
+        // Only the 5 points the slash actually took come back
+        let reversal = tracker.reverse_event("agent", slash.record.event_id, "appeal upheld").unwrap();
+        assert_eq!(reversal.record.delta, 5);
+        assert_eq!(reversal.record.event_id, 4);
+        assert_eq!(tracker.score("agent"), 6);
+        assert!(matches!(
+            tracker.reverse_event("agent", slash.record.event_id, "again"),
+            Err(ReputationError::AlreadyReversed { event_id: 2, reversed_by: 4 })
+        ));
+        assert!(matches!(
+            tracker.reverse_event("agent", 4, "undo"),
+            Err(ReputationError::CannotReverseReversal(4))
+        ));
+        assert!(matches!(tracker.reverse_event("agent", 9, "missing"), Err(ReputationError::EventNotFound { .. })));
+
+        let history = &tracker.get("agent").unwrap().history;
+        assert_eq!(history[1].reversed_by, Some(4));
+        let reversals = tracker.reversals("agent");
+        assert_eq!(reversals.len(), 1);
+        assert!(matches!(&reversals[0].event, ReputationEvent::Reversal { event_id: 2, amount: 5, .. }));
+
+        // Reversing a vote takes back exactly what it gave
+        tracker.reverse_event("agent", 3, "duplicate vote").unwrap();
+        assert_eq!(tracker.score("agent"), 5);
+    }
+
+    #[test]
+    fn test_query_agents() {
+        let clock = MockClock::new(1_000_000);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+        for i in 0..25u32 {
+            clock.advance(10);
+            // Five agents share each score
+            let amount = (i % 5) as i32 * 50 + 100;
+            tracker.apply_event_full(&format!("agent-{:02}", i), ReputationEvent::ManualAdjustment { reason: "seed".into(), amount }).unwrap();
+        }
+
+        let query = AgentQuery { limit: Some(4), ..Default::default() };
+        let first = tracker.query_agents_at_level(ReputationLevel::Verified, &query);
+        assert_eq!(first.total, 25);
+        let ids: Vec<&str> = first.items.iter().map(|a| a.agent_id.as_str()).collect();
+        assert_eq!(ids, ["agent-04", "agent-09", "agent-14", "agent-19"]);
+        assert_eq!(first.items[0].score, 400);
+
+        // Paging covers every agent once, in the same order on every call
+        let mut seen = Vec::new();
+        let mut offset = Some(0);
+        while let Some(next) = offset {
+            let page = tracker.query_agents_at_level(ReputationLevel::Verified, &AgentQuery { offset: next, ..query.clone() });
+            seen.extend(page.items.into_iter().map(|a| a.agent_id));
+            offset = page.next_offset;
+        }
+        assert_eq!(seen.len(), 25);
+        assert_eq!(seen.iter().collect::<HashSet<_>>().len(), 25);
+        let all = tracker.query_agents_at_level(ReputationLevel::Verified, &AgentQuery::default());
+        assert_eq!(all.items.into_iter().map(|a| a.agent_id).collect::<Vec<_>>(), seen);
+        let unpaged: Vec<String> = tracker.agents_at_level(ReputationLevel::Verified).iter().map(|r| r.agent_id.clone()).collect();
+        assert_eq!(unpaged, seen);
+
+        let oldest = AgentQuery { sort_by: SortBy::LastUpdated, order: SortOrder::Ascending, limit: Some(2), ..Default::default() };
+        let page = tracker.query_agents_in_range(300, 350, &oldest);
+        assert_eq!(page.total, 10);
+        assert_eq!(page.items.iter().map(|a| a.agent_id.as_str()).collect::<Vec<_>>(), ["agent-02", "agent-03"]);
+        assert_eq!(page.next_offset, Some(2));
+
+        let trending = AgentQuery { sort_by: SortBy::WeeklyTrend, limit: Some(1), ..Default::default() };
+        assert_eq!(tracker.query_agents_in_range(0, 1000, &trending).items[0].weekly_trend, 300);
+    }
+
+    #[test]
+    fn test_tracker_config() {
+        let config = TrackerConfig {
+            min_score: 0,
+            max_score: 10_000,
+            default_start: 1_000,
+            classes: HashMap::new(),
+        }.with_class("institutional", 3_000);
+        let mut tracker = ReputationTracker::with_config(config.clone()).unwrap();
+        let seed = |amount| ReputationEvent::ManualAdjustment { reason: "seed".into(), amount };
+
+        tracker.apply_event_full("agent", seed(1_000)).unwrap();
+        assert_eq!(tracker.score("agent"), 2_000);
+        assert_eq!(tracker.level("agent"), ReputationLevel::Verified);
+        for _ in 0..10 {
+            tracker.apply_event_full("agent", seed(1_000)).unwrap();
+        }
+        assert_eq!(tracker.score("agent"), 10_000);
+        assert_eq!(tracker.level("agent"), ReputationLevel::Legendary);
+        tracker.get("agent").unwrap().verify_consistency().unwrap();
+
+        let record = tracker.get_or_create_with_class("fund", "institutional").unwrap();
+        assert_eq!((record.score, record.level), (3_000, ReputationLevel::Verified));
+        assert!(matches!(
+            tracker.get_or_create_with_class("other", "retail"),
+            Err(ReputationError::UnknownAgentClass(_))
+        ));
+        for _ in 0..4 {
+            tracker.apply_event_full("fund", ReputationEvent::Slashed { reason: "test".into(), amount: 1_000 }).unwrap();
+        }
+        assert_eq!(tracker.score("fund"), 0);
+
+        let histogram = tracker.histogram(5_000);
+        assert_eq!(histogram.iter().map(|b| (b.max, b.count)).collect::<Vec<_>>(), [(4_999, 1), (9_999, 0), (10_000, 1)]);
+        assert_eq!(tracker.percentile("agent"), Some(75.0));
+
+        let mut saved = Vec::new();
+        tracker.save_to(&mut saved).unwrap();
+        let loaded = ReputationTracker::load_from(saved.as_slice()).unwrap();
+        assert_eq!(loaded.get("agent").unwrap().bounds.max, 10_000);
+        assert_eq!(loaded.get("fund").unwrap().bounds.start, 3_000);
+
+        // Payload bounds follow the configured range
+        assert_eq!(tracker.validator().max_amount, 10_000);
+        tracker.apply_event_full("fund", seed(5_000)).unwrap();
+        assert_eq!(tracker.score("fund"), 5_000);
+
+        // Statistics over a huge range don't allocate per score
+        let mut huge = ReputationTracker::with_config(TrackerConfig { max_score: u32::MAX, ..config.clone() }).unwrap();
+        huge.get_or_create("a").score = u32::MAX;
+        huge.get_or_create("b");
+        assert_eq!(huge.distribution().max, u32::MAX);
+        assert_eq!(huge.percentile("a"), Some(75.0));
+        assert_eq!(huge.percentile_within_level("a"), Some(50.0));
+        assert_eq!(huge.histogram(u32::MAX / 2 + 1).iter().map(|b| b.count).collect::<Vec<_>>(), [1, 1]);
+
+        let bad = TrackerConfig { default_start: 20_000, ..config };
+        assert!(matches!(ReputationTracker::with_config(bad), Err(ReputationError::InvalidConfig(_))));
+        assert_eq!(ReputationTracker::new().config(), &TrackerConfig::default());
+        assert_eq!(ReputationLevel::from_score_scaled(6_000, 10_000), ReputationLevel::Trusted);
+        assert_eq!(ReputationLevel::Trusted.min_score_scaled(10_000), 6_000);
+    }
+
+    #[test]
+    fn test_apply_event_full() {
+        let clock = MockClock::new(5_000);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+        let applied = tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap();
+        assert_eq!((applied.record.score_after, applied.record.timestamp), (tracker.score("agent"), 5_000));
+        assert_eq!(applied.level, ReputationLevel::Newcomer);
+        assert!(!applied.level_changed);
+
+        let seed = ReputationEvent::ManualAdjustment { reason: "seed".into(), amount: 150 };
+        let applied = tracker.apply_event_full("agent", seed).unwrap();
+        assert_eq!(applied.record.delta, 150);
+        assert_eq!(applied.level, ReputationLevel::Verified);
+        assert!(applied.level_changed);
+
+        #[allow(deprecated)]
+        let delta = tracker.apply_event("agent", ReputationEvent::GovernanceVote).unwrap();
+        assert_eq!(tracker.get("agent").unwrap().history.back().unwrap().delta, delta);
+    }
+
+    #[test]
+    fn test_recency_weighting() {
+        let now = 100 * 86_400;
+        let trade = || ReputationEvent::TradeSuccess { pnl_bps: 500, volume_usd: 10_000 };
+        let mut tracker = ReputationTracker::new()
+            .with_recency_weighting(RecencyWeighting::default())
+            .with_clock(MockClock::new(now));
+        let fresh = tracker.apply_event_at("fresh", trade(), now).unwrap();
+        let old = tracker.apply_event_at("old", trade(), now - 90 * 86_400).unwrap();
+        let mid = tracker.apply_event_at("mid", trade(), now - 45 * 86_400).unwrap();
+        assert!(fresh > 0);
+        assert_eq!(old, fresh / 2);
+        assert_eq!(mid, fresh * 3 / 4);
+        let recorded = tracker.get("old").unwrap().history.back().unwrap();
+        assert_eq!((recorded.delta, recorded.weighted_from), (old, Some(fresh)));
+        assert_eq!(tracker.get("fresh").unwrap().history.back().unwrap().weighted_from, None);
+
+        // Slashes are exempt
+        let slash = || ReputationEvent::Slashed { reason: "test".into(), amount: 40 };
+        assert_eq!(tracker.apply_event_at("old", slash(), now - 90 * 86_400).unwrap(), -40);
+
+        // Unweighted by default
+        let mut plain = ReputationTracker::new().with_clock(MockClock::new(now));
+        assert_eq!(plain.apply_event_at("old", trade(), now - 90 * 86_400).unwrap(), fresh);
+    }
+
+    #[test]
+    fn test_event_counts() {
+        let mut tracker = ReputationTracker::new();
+        for i in 0..250 {
+            let event = match i % 5 {
+                0 | 1 => ReputationEvent::GovernanceVote,
+                2 => ReputationEvent::ProofSubmitted,
+                3 => ReputationEvent::TradeFailed { loss_bps: 10 },
+                _ => ReputationEvent::TradeSuccess { pnl_bps: 10, volume_usd: 100 },
+            };
+            tracker.apply_event_full("agent", event).unwrap();
+        }
+        tracker.apply_event_full("other", ReputationEvent::GovernanceVote).unwrap();
+
+        let record = tracker.get("agent").unwrap();
+        assert_eq!(record.history.len(), DEFAULT_HISTORY_LIMIT);
+        assert_eq!(tracker.event_count("agent", ReputationEventKind::GovernanceVote), 100);
+        assert_eq!(tracker.event_count("agent", ReputationEventKind::ProofSubmitted), 50);
+        assert_eq!(tracker.event_count("agent", ReputationEventKind::TradeFailed), 50);
+        assert_eq!(tracker.event_count("agent", ReputationEventKind::TradeSuccess), 50);
+        assert_eq!(tracker.event_count("agent", ReputationEventKind::Slashed), 0);
+        assert_eq!(record.event_counts.values().sum::<u64>(), record.total_events);
+        assert_eq!(tracker.event_totals()[&ReputationEventKind::GovernanceVote], 101);
+
+        let mut saved = Vec::new();
+        tracker.save_to(&mut saved).unwrap();
+        let loaded = ReputationTracker::load_from(saved.as_slice()).unwrap();
+        assert_eq!(loaded.event_count("agent", ReputationEventKind::GovernanceVote), 100);
+        assert_eq!(loaded.event_totals(), tracker.event_totals());
+    }
+
+    #[test]
+    fn test_level_floors() {
+        let floors = HashMap::from([(ReputationLevel::Trusted, 450), (ReputationLevel::Elite, 600)]);
+        let mut tracker = ReputationTracker::new().with_level_floors(floors);
+        let seed = |amount| ReputationEvent::ManualAdjustment { reason: "seed".into(), amount };
+        let loss = || ReputationEvent::TradeFailed { loss_bps: 500 };
+        tracker.apply_event_full("trusted", seed(550)).unwrap();
+        tracker.apply_event_full("trusted", seed(-100)).unwrap();
+        assert_eq!(tracker.level("trusted"), ReputationLevel::Established);
+        assert_eq!(tracker.highest_level("trusted"), Some(ReputationLevel::Trusted));
+
+        for _ in 0..200 {
+            tracker.apply_event_full("trusted", loss()).unwrap();
+        }
+        assert_eq!(tracker.score("trusted"), 450);
+        let last = tracker.get("trusted").unwrap().history.back().unwrap();
+        assert_eq!(last.delta, 0);
+        assert!(last.clamped_from.unwrap() < 0);
+
+        // Slashes go through the floor, and the floor stays earned
+        tracker.apply_event_full("trusted", ReputationEvent::Slashed { reason: "fraud".into(), amount: 100 }).unwrap();
+        assert_eq!(tracker.score("trusted"), 350);
+        tracker.apply_event_full("trusted", loss()).unwrap();
+        assert_eq!(tracker.score("trusted"), 350);
+
+        // Agents that never reached a floored level aren't protected
+        tracker.apply_event_full("newcomer", seed(300)).unwrap();
+        for _ in 0..200 {
+            tracker.apply_event_full("newcomer", loss()).unwrap();
+        }
+        assert_eq!(tracker.score("newcomer"), 0);
+
+        let mut saved = Vec::new();
+        tracker.save_to(&mut saved).unwrap();
+        let loaded = ReputationTracker::load_from(saved.as_slice()).unwrap();
+        assert_eq!(loaded.highest_level("trusted"), Some(ReputationLevel::Trusted));
+    }
+
+    #[test]
+    fn test_reputation_contexts() {
+        let mut tracker = ReputationTracker::new();
+        let seed = |amount| ReputationEvent::ManualAdjustment { reason: "seed".into(), amount };
+        tracker.apply_event_full("agent", seed(500)).unwrap();
+        tracker.apply_event_in_context("agent", "alpha", seed(300)).unwrap();
+        tracker.apply_event_in_context("agent", "beta", seed(300)).unwrap();
+        tracker.apply_event_in_context("rival", "alpha", seed(100)).unwrap();
+
+        tracker.apply_event_in_context("agent", "alpha", ReputationEvent::Slashed { reason: "test".into(), amount: 250 }).unwrap();
+        assert_eq!(tracker.score_in("agent", "alpha"), 150);
+        assert_eq!(tracker.score_in("agent", "beta"), 400);
+        assert_eq!(tracker.score("agent"), 600);
+        assert_eq!(tracker.score_in("agent", GLOBAL_CONTEXT), 600);
+        assert_eq!(tracker.score_in("agent", "gamma"), 0);
+        assert!(tracker.get("rival").is_none());
+
+        assert_eq!(tracker.global_score("agent"), Some((600 + 150 + 400 + 1) / 3));
+        assert_eq!(tracker.global_score("rival"), Some(200));
+        assert_eq!(tracker.global_score("nobody"), None);
+        let weights = HashMap::from([(GLOBAL_CONTEXT.to_string(), 2), ("beta".to_string(), 0)]);
+        let mut weighted = ReputationTracker::new().with_context_aggregation(ContextAggregation::WeightedMean(weights));
+        weighted.apply_event_full("agent", seed(500)).unwrap();
+        weighted.apply_event_in_context("agent", "alpha", seed(200)).unwrap();
+        weighted.apply_event_in_context("agent", "beta", seed(900)).unwrap();
+        assert_eq!(weighted.global_score("agent"), Some(500));
+        let mut highest = ReputationTracker::new().with_context_aggregation(ContextAggregation::Max);
+        highest.apply_event_in_context("agent", "alpha", seed(200)).unwrap();
+        assert_eq!(highest.global_score("agent"), Some(300));
+
+        let alpha: Vec<(String, usize)> = tracker.leaderboard_in("alpha", 0, 10).into_iter().map(|e| (e.agent_id, e.rank)).collect();
+        assert_eq!(alpha, [("rival".to_string(), 1), ("agent".to_string(), 2)]);
+        assert_eq!(tracker.leaderboard_in(GLOBAL_CONTEXT, 0, 10).len(), 1);
+        assert!(tracker.leaderboard_in("gamma", 0, 10).is_empty());
+
+        let mut saved = Vec::new();
+        tracker.save_to(&mut saved).unwrap();
+        let mut loaded = ReputationTracker::load_from(saved.as_slice()).unwrap();
+        assert_eq!(loaded.score_in("agent", "alpha"), 150);
+        assert_eq!(loaded.score_in("rival", "alpha"), 200);
+
+        loaded.remove("agent");
+        assert_eq!(loaded.score_in("agent", "beta"), 0);
+        assert_eq!(loaded.leaderboard_in("alpha", 0, 10).len(), 1);
+    }
+
+    #[test]
+    fn test_apply_batch() {
+        let mut tracker = ReputationTracker::new();
+        tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap();
+        let before = tracker.get("agent").unwrap().clone();
+
+        let mut events = vec![ReputationEvent::AttestationVerified; 10];
+        events[6] = ReputationEvent::Slashed { reason: "test".into(), amount: -50 };
+        let err = tracker.apply_batch("agent", events.clone()).unwrap_err();
+        assert!(matches!(err, BatchError::InvalidEvent { index: 6, .. }));
+        let after = tracker.get("agent").unwrap();
+        assert_eq!((after.score, after.total_events, after.history.len()), (before.score, before.total_events, 1));
+        assert!(tracker.apply_batch("new", events).is_err());
+        assert!(tracker.get("new").is_none());
+
+        let mut events = vec![ReputationEvent::AttestationVerified; 10];
+        events[6] = ReputationEvent::Slashed { reason: "test".into(), amount: 50 };
+        let result = tracker.apply_batch("agent", events).unwrap();
+        assert_eq!(result.deltas.len(), 10);
+        assert_eq!(result.deltas[6], -50);
+        assert_eq!(result.total_delta, 9 * 10 - 50);
+        assert_eq!(result.final_score, 101 + 40);
+        assert_eq!(tracker.score("agent"), 141);
+
+        // Level changes wait for the batch to commit
+        let changes = tracker.subscribe();
+        let promote = ReputationEvent::ManualAdjustment { reason: "promote".into(), amount: 300 };
+        let mut events = vec![promote.clone(), ReputationEvent::GovernanceVote];
+        events.push(ReputationEvent::Slashed { reason: "test".into(), amount: -50 });
+        assert!(tracker.apply_batch("agent", events).is_err());
+        assert!(changes.try_recv().is_err());
+        assert_eq!(tracker.score("agent"), 141);
+        tracker.apply_batch("agent", vec![promote, ReputationEvent::GovernanceVote]).unwrap();
+        let change = changes.try_recv().unwrap();
+        assert_eq!(change.new_level, tracker.get("agent").unwrap().level);
+        assert_ne!(change.old_level, change.new_level);
+        assert!(changes.try_recv().is_err());
+
+        // Batches can't write events older than the last update
+        let clock = MockClock::new(2_000);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+        tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap();
+        clock.set(1_000);
+        let err = tracker.apply_batch("agent", vec![ReputationEvent::GovernanceVote; 3]).unwrap_err();
+        assert!(matches!(
+            err,
+            BatchError::InvalidEvent { index: 0, error: ReputationError::OutOfOrderEvent { timestamp: 1_000, last_updated: 2_000, .. } }
+        ));
+        let record = tracker.get("agent").unwrap();
+        assert_eq!((record.total_events, record.last_updated), (1, 2_000));
+    }
+
+    #[test]
+    fn test_history_retention_and_archival() {
+        use std::sync::{Arc, Mutex};
+
+        let clock = MockClock::new(1_000);
+        let archived = Arc::new(Mutex::new(Vec::new()));
+        let sink = archived.clone();
+        let mut tracker = ReputationTracker::new()
+            .with_clock(clock.clone())
+            .with_history_limit(3)
+            .with_archiver(move |agent_id, record| {
+                sink.lock().unwrap().push((agent_id.to_string(), record.timestamp, record.score_after));
+            });
+
+        for _ in 0..5 {
+            clock.advance(10);
+            tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap();
+        }
+        assert_eq!(*archived.lock().unwrap(), [("agent".to_string(), 1_010, 101), ("agent".to_string(), 1_020, 102)]);
+        let record = tracker.get("agent").unwrap();
+        let kept: Vec<u64> = record.history.iter().map(|r| r.timestamp).collect();
+        assert_eq!(kept, [1_030, 1_040, 1_050]);
+        assert_eq!(record.recent_history(2).map(|r| r.score_after).collect::<Vec<_>>(), [104, 105]);
+        assert_eq!(record.total_events, 5);
+    }
+
+    #[test]
+    fn test_trend_analytics() {
+        let clock = MockClock::new(0);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone()).with_history_limit(5);
+        let adjust = |amount| ReputationEvent::ManualAdjustment { reason: "test".into(), amount };
+        for amount in [4, -2, 6, 8, -4, 10] {
+            clock.advance(100);
+            tracker.apply_event_full("riser", adjust(amount)).unwrap();
+        }
+        tracker.apply_event_full("faller", adjust(-30)).unwrap();
+        tracker.apply_event_full("steady", ReputationEvent::GovernanceVote).unwrap();
+        tracker.apply_event_full("steady", adjust(-1)).unwrap();
+
+        let record = tracker.get("riser").unwrap();
+        // Events at 200..=600 are retained; the one at 100 was dropped
+        assert_eq!(record.trend(300, 600), Windowed { value: 20, partial: false });
+        assert_eq!(record.trend(600, 600), Windowed { value: 18, partial: true });
+
+        let series = record.moving_average(400, 200, 600);
+        assert!(!series.partial);
+        let changes: Vec<(u64, i32, usize)> = series.value.iter().map(|b| (b.start, b.change, b.events)).collect();
+        assert_eq!(changes, [(200, 4, 2), (400, 14, 3)]);
+        assert_eq!(series.value[0].average_delta, 2.0);
+
+        let volatility = record.volatility(300, 600);
+        assert!((volatility.value - 29.0_f64.sqrt()).abs() < 1e-9);
+        assert_eq!(record.volatility(0, 0).value, 0.0);
+
+        let ids = |trends: Vec<AgentTrend>| trends.into_iter().map(|t| t.agent_id).collect::<Vec<_>>();
+        assert_eq!(ids(tracker.most_improved(1_000, 5)), ["riser"]);
+        assert_eq!(ids(tracker.most_declined(1_000, 5)), ["faller"]);
+        assert!(tracker.trends(1_000).iter().any(|t| t.agent_id == "steady" && t.change == 0));
+    }
+
+    #[test]
+    fn test_rate_limits() {
+        let clock = MockClock::new(100_000);
+        let mut tracker = ReputationTracker::new()
+            .with_clock(clock.clone())
+            .with_rate_limit(ReputationEventKind::GovernanceVote, RateLimit::points_per_day(3))
+            .with_rate_limit(ReputationEventKind::TradeSuccess, RateLimit::events_per_day(2))
+            .with_rate_limit(ReputationEventKind::Slashed, RateLimit::points_per_day(0));
+
+        let deltas: Vec<i32> = (0..5)
+            .map(|_| {
+                clock.advance(60);
+                tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap().record.delta
+            })
+            .collect();
+        assert_eq!(deltas, [1, 1, 1, 0, 0]);
+        let record = tracker.get("agent").unwrap();
+        assert_eq!(record.history.back().unwrap().clamped_from, Some(1));
+        assert_eq!(record.history.front().unwrap().clamped_from, None);
+
+        let trade = || ReputationEvent::TradeSuccess { pnl_bps: 200, volume_usd: 0 };
+        assert_eq!(tracker.apply_event_full("agent", trade()).unwrap().record.delta, 2);
+        assert_eq!(tracker.apply_event_full("agent", trade()).unwrap().record.delta, 2);
+        assert_eq!(tracker.apply_event_full("agent", trade()).unwrap().record.delta, 0);
+
+        // Penalties are never limited
+        let slash = ReputationEvent::Slashed { reason: "test".into(), amount: 4 };
+        assert_eq!(tracker.apply_event_full("agent", slash).unwrap().record.delta, -4);
+
+        // The window slides past the first vote
+        clock.advance(86_400 - 4 * 60);
+        assert_eq!(tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap().record.delta, 1);
+        assert_eq!(tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap().record.delta, 0);
+        assert_eq!(tracker.score("agent"), 104);
+
+        // A short history doesn't forget gains still in the window
+        let mut tracker = ReputationTracker::new()
+            .with_clock(clock.clone())
+            .with_history_limit(1)
+            .with_rate_limit(ReputationEventKind::GovernanceVote, RateLimit::points_per_day(2))
+            .with_rate_limit(ReputationEventKind::TradeSuccess, RateLimit::events_per_day(1));
+        let vote = |tracker: &mut ReputationTracker| {
+            tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap().record.delta
+        };
+        assert_eq!(vote(&mut tracker), 1);
+        assert_eq!(tracker.apply_event_full("agent", trade()).unwrap().record.delta, 2);
+        assert_eq!(vote(&mut tracker), 1);
+        assert_eq!(tracker.get("agent").unwrap().history.len(), 1);
+        assert_eq!(vote(&mut tracker), 0);
+        assert_eq!(tracker.apply_event_full("agent", trade()).unwrap().record.delta, 0);
+
+        // Caps beyond i32 don't wrap around to a negative allowance
+        let mut tracker = ReputationTracker::new()
+            .with_clock(clock.clone())
+            .with_rate_limit(ReputationEventKind::GovernanceVote, RateLimit::points_per_day(u32::MAX));
+        assert_eq!(vote(&mut tracker), 1);
+    }
+
+    #[test]
+    fn test_metrics_snapshot() {
+        let clock = MockClock::new(10_000);
+        let mut tracker = ReputationTracker::new()
+            .with_probation_policy(ProbationPolicy::default())
+            .with_clock(clock.clone());
+        let seed = |amount| ReputationEvent::ManualAdjustment { reason: "seed".into(), amount };
+        tracker.apply_event_full("old", seed(150)).unwrap();
+        clock.advance(7_200);
+        tracker.apply_event_full("trusted", seed(550)).unwrap();
+        tracker.apply_event_full("slashed", seed(300)).unwrap();
+        tracker.apply_event_full("slashed", ReputationEvent::Slashed { reason: "fraud".into(), amount: 100 }).unwrap();
+        tracker.leaderboard(10);
+        clock.advance(30);
+
+        let metrics = tracker.metrics_snapshot();
+        assert_eq!(metrics.total_agents, 3);
+        assert_eq!(metrics.events_last_hour, 3);
+        assert_eq!(metrics.mean_score, 400.0);
+        assert_eq!(ReputationTracker::new().metrics_snapshot().leaderboard_cache_age_secs, None);
+        assert_eq!(metrics.render_prometheus(), "\
+# HELP reputation_agents Agents with a reputation record.
+# TYPE reputation_agents gauge
+reputation_agents 3
+# HELP reputation_agents_by_level Agents at each reputation level.
+# TYPE reputation_agents_by_level gauge
+reputation_agents_by_level{level=\"newcomer\"} 0
+reputation_agents_by_level{level=\"verified\"} 2
+reputation_agents_by_level{level=\"established\"} 0
+reputation_agents_by_level{level=\"trusted\"} 1
+reputation_agents_by_level{level=\"elite\"} 0
+reputation_agents_by_level{level=\"legendary\"} 0
+# HELP reputation_events_last_hour Reputation events applied in the last hour.
+# TYPE reputation_events_last_hour gauge
+reputation_events_last_hour 3
+# HELP reputation_mean_score Mean reputation score.
+# TYPE reputation_mean_score gauge
+reputation_mean_score 400
+# HELP reputation_leaderboard_cache_age_seconds Age of the cached leaderboard.
+# TYPE reputation_leaderboard_cache_age_seconds gauge
+reputation_leaderboard_cache_age_seconds 30
+# HELP reputation_agents_on_probation Agents on probation.
+# TYPE reputation_agents_on_probation gauge
+reputation_agents_on_probation 1
+# HELP reputation_agents_slashed Agents slashed at least once.
+# TYPE reputation_agents_slashed gauge
+reputation_agents_slashed 1
+");
+    }
+
+    #[test]
+    fn test_report() {
+        let day = 86_400;
+        let clock = MockClock::new(100 * day);
+        let mut tracker = ReputationTracker::new()
+            .with_probation_policy(ProbationPolicy::default())
+            .with_clock(clock.clone());
+        let seed = |amount| ReputationEvent::ManualAdjustment { reason: "seed".into(), amount };
+        tracker.apply_event_full("rival", seed(400)).unwrap();
+        tracker.apply_event_full("agent", seed(200)).unwrap();
+        clock.advance(20 * day);
+        tracker.apply_event_full("agent", ReputationEvent::GovernanceVote).unwrap();
+        clock.advance(5 * day);
+        tracker.apply_event_full("agent", ReputationEvent::Slashed { reason: "late".into(), amount: 50 }).unwrap();
+        clock.advance(day);
+        assert!(tracker.report("missing").is_none());
+
+        let json = serde_json::to_string_pretty(&tracker.report("agent").unwrap()).unwrap();
+        assert_eq!(json, r#"{
+  "agent_id": "agent",
+  "score": 251,
+  "level": "Verified",
+  "rank": 2,
+  "percentile": 25.0,
+  "weekly_trend": {
+    "value": -49,
+    "partial": false
+  },
+  "monthly_trend": {
+    "value": 151,
+    "partial": false
+  },
+  "event_counts": {
+    "GovernanceVote": 1,
+    "Slashed": 1,
+    "ManualAdjustment": 1
+  },
+  "on_probation_until": 13392000,
+  "recent_events": [
+    {
+      "event": {
+        "ManualAdjustment": {
+          "reason": "seed",
+          "amount": 200
+        }
+      },
+      "delta": 200,
+      "score_after": 300,
+      "timestamp": 8640000,
+      "clamped_from": null,
+      "weighted_from": null,
+      "idempotency_key": null,
+      "event_id": 1,
+      "reversed_by": null
+    },
+    {
+      "event": "GovernanceVote",
+      "delta": 1,
+      "score_after": 301,
+      "timestamp": 10368000,
+      "clamped_from": null,
+      "weighted_from": null,
+      "idempotency_key": null,
+      "event_id": 2,
+      "reversed_by": null
+    },
+    {
+      "event": {
+        "Slashed": {
+          "reason": "late",
+          "amount": 50
+        }
+      },
+      "delta": -50,
+      "score_after": 251,
+      "timestamp": 10800000,
+      "clamped_from": null,
+      "weighted_from": null,
+      "idempotency_key": null,
+      "event_id": 3,
+      "reversed_by": null
+    }
+  ],
+  "time_at_level_secs": {
+    "value": 2246400,
+    "partial": false
+  },
+  "generated_at": 10886400
+}"#);
+    }
+
+    #[test]
+    fn test_distribution_and_histogram() {
+        let mut tracker = ReputationTracker::new();
+        assert_eq!(tracker.distribution().agent_count, 0);
+        for score in 1..=100u32 {
+            let record = tracker.get_or_create(&format!("agent-{}", score));
+            record.score = score * 10;
+            record.level = ReputationLevel::from_score(record.score);
+        }
+
+        let stats = tracker.distribution();
+        assert_eq!(stats.agent_count, 100);
+        assert_eq!((stats.p10, stats.p50, stats.p90, stats.p99), (100, 500, 900, 990));
+        assert_eq!((stats.min, stats.max), (10, 1000));
+        assert_eq!(stats.mean, 505.0);
+        assert_eq!(stats.per_level[&ReputationLevel::Newcomer], 19);
+        assert_eq!(stats.per_level[&ReputationLevel::Elite], 20);
+        assert_eq!(stats.per_level[&ReputationLevel::Legendary], 1);
+
+        let histogram = tracker.histogram(250);
+        let buckets: Vec<(u32, u32, usize)> = histogram.iter().map(|b| (b.min, b.max, b.count)).collect();
+        assert_eq!(buckets, [(0, 249, 24), (250, 499, 25), (500, 749, 25), (750, 999, 25), (1000, 1000, 1)]);
+        assert!(tracker.histogram(0).is_empty());
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let clock = MockClock::new(1_000);
+        let mut tracker = ReputationTracker::new().with_clock(clock.clone());
+        tracker.apply_event_full("plain", ReputationEvent::AttestationVerified).unwrap();
+        tracker.apply_event_full("with,comma", ReputationEvent::ManualAdjustment { reason: "x".into(), amount: 400 }).unwrap();
+        tracker.apply_event_full("say \"hi\"\nthere", ReputationEvent::GovernanceVote).unwrap();
+
+        let mut csv = Vec::new();
+        tracker.export_csv(&mut csv).unwrap();
+        let text = String::from_utf8(csv.clone()).unwrap();
+        assert!(text.contains("\"with,comma\",500,Established,1,1000,400\n"));
+
+        let mut imported = ReputationTracker::new();
+        let report = imported.import_csv(csv.as_slice(), CsvImportMode::CreateOnly).unwrap();
+        assert_eq!(report, CsvImportReport { imported: 3, errors: Vec::new() });
+        for original in tracker.export() {
+            let record = imported.get(&original.agent_id).unwrap();
+            assert_eq!(
+                (record.score, record.level, record.total_events, record.last_updated),
+                (original.score, original.level, original.total_events, original.last_updated)
+            );
+            record.verify_consistency().unwrap();
+        }
+
+        let report = imported.import_csv(csv.as_slice(), CsvImportMode::CreateOnly).unwrap();
+        assert_eq!((report.imported, report.errors.len()), (0, 3));
+        let report = imported.import_csv(csv.as_slice(), CsvImportMode::Overwrite).unwrap();
+        assert_eq!(report.imported, 3);
+    }
+
+    #[test]
+    fn test_csv_malformed_rows() {
+        let csv = format!(
+            "{}\nok,150,Newcomer,2,10,0\nshort,150\nbad-score,lots,Newcomer,1,1,0\nwrong-level,700,Newcomer,1,1,0\n\n\"split\nid\",250,Verified,1,1,0\n\"broken\"x,1,Newcomer,1,1,0\n\"open,1,Newcomer,1,1,0\n",
+            CSV_HEADER
+        );
+        let mut tracker = ReputationTracker::new();
+        let report = tracker.import_csv(csv.as_bytes(), CsvImportMode::CreateOnly).unwrap();
+        assert_eq!(report.imported, 2);
+        let lines: Vec<usize> = report.errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, [3, 4, 5, 9, 10]);
+        assert_eq!(tracker.score("split\nid"), 250);
+        assert!(report.errors[4].reason.contains("unterminated"));
+
+        assert!(matches!(
+            tracker.import_csv(&b"id,score\n"[..], CsvImportMode::CreateOnly),
+            Err(ReputationError::Corrupt(_))
+        ));
+    }
+
+    #[test]
+    fn test_score_at() {
+        const DAY: u64 = 86_400;
+        let mut record = ReputationRecord::new_at("agent".into(), 0);
+        // Two events a day for 100 days, each worth one point
+        for day in 0..100 {
+            for hour in [6, 18] {
+                record.apply_event_at(ReputationEvent::GovernanceVote, 10 * DAY + day * DAY + hour * 3_600).unwrap();
+            }
+        }
+        assert_eq!(record.score, 300);
+        assert_eq!(record.history.len(), 100);
+        assert_eq!(record.checkpoints.len(), 50);
+
+        // Before any event
+        assert_eq!(record.score_at(10 * DAY), 100);
+        // Within retained history (days 60-109)
+        assert_eq!(record.score_at(80 * DAY + 12 * 3_600), 241);
+        assert_eq!(record.score_at(200 * DAY), 300);
+        // From checkpoints, to a day's resolution
+        assert_eq!(record.score_at(20 * DAY + 19 * 3_600), 122);
+        assert_eq!(record.score_at(20 * DAY + 12 * 3_600), 120);
+
+        let mut tracker = ReputationTracker::new();
+        tracker.records.insert("agent".into(), record);
+        assert_eq!(tracker.score_at("agent", 80 * DAY + 12 * 3_600), 241);
+        assert_eq!(tracker.score_at("unknown", 80 * DAY), 0);
+    }
+
+    #[test]
+    fn test_reputation_decay() {
+        const DAY: u64 = 86_400;
+        let clock = MockClock::new(1_000_000);
+        let mut tracker = ReputationTracker::new()
+            .with_clock(clock.clone())
+            .with_decay_policy(DecayPolicy { half_life_secs: 30 * DAY, floor: 100 });
+
+        for agent in ["idle", "active"] {
+            tracker.get_or_create(agent).score = 500;
+        }
+        tracker.get_or_create("low").score = 80;
+
+        // The active agent's last event is more recent, so it decays less
+        clock.advance(20 * DAY);
+        tracker.apply_event_full("active", ReputationEvent::GovernanceVote).unwrap();
+        let now = 1_000_000 + 30 * DAY;
+        assert_eq!(tracker.apply_decay(now), 2);
+        assert_eq!(tracker.score("idle"), 300);
+        assert!(tracker.score("active") > 300);
+        assert_eq!(tracker.score("low"), 80);
+
+        let record = tracker.get("idle").unwrap();
+        let last = record.history.back().unwrap();
+        assert!(matches!(last.event, ReputationEvent::Decay { idle_secs, amount: 200 } if idle_secs == 30 * DAY));
+        assert_eq!((last.delta, last.score_after), (-200, 300));
+
+        // Same timestamp again is a no-op
+        assert_eq!(tracker.apply_decay(now), 0);
+        assert_eq!(tracker.score("idle"), 300);
+        assert_eq!(tracker.get("idle").unwrap().total_events, 1);
+
+        // Scores approach but never cross the floor
+        tracker.apply_decay(now + 3_650 * DAY);
+        assert_eq!(tracker.score("idle"), 100);
+        assert_eq!(tracker.level("idle"), ReputationLevel::Newcomer);
+    }
 }
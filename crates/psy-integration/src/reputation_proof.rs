@@ -0,0 +1,244 @@
+//! Reputation Threshold Proofs
+//!
+//! Lets an agent prove its reputation is at least some threshold without
+//! the tracker operator handing out the full record.
+//!
+//! NOTE: Like `zk_proofs::PerformanceProof`, the proof bytes are a
+//! placeholder for a real ZK circuit.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use zk_proofs::{ProofError, VerificationResult};
+use crate::clock::{Clock, SystemClock};
+use crate::reputation::{ReputationRecord, TrackerConfig};
+
+/// Version tag mixed into placeholder proof bytes
+const PROOF_VERSION: &[u8] = b"PLACEHOLDER_REPUTATION_PROOF_V1";
+
+/// Proof lifetime
+const PROOF_TTL_SECS: u64 = 2_592_000; // 30 days
+
+/// Proof that an agent's score was at least `threshold`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReputationProof {
+    /// Proof identifier
+    pub id: String,
+    /// Agent ID (public)
+    pub agent_id: String,
+    /// Commitment to the reputation record
+    pub commitment: String,
+    /// The actual proof data (would be ZK-SNARK in production)
+    pub proof_data: Vec<u8>,
+    /// Score the agent proves to meet
+    pub threshold: u32,
+    /// Timestamp when proof was generated
+    pub generated_at: u64,
+    /// Optional expiration
+    pub expires_at: Option<u64>,
+}
+
+impl ReputationProof {
+    /// Prove a record's score is at least `threshold`
+    pub fn prove_threshold(record: &ReputationRecord, threshold: u32) -> Result<Self, ProofError> {
+        Self::prove_threshold_at(record, threshold, SystemClock.now())
+    }
+
+    /// Prove a record's score is at least `threshold` as of `now`
+    pub fn prove_threshold_at(record: &ReputationRecord, threshold: u32, now: u64) -> Result<Self, ProofError> {
+        if record.score < threshold {
+            return Err(ProofError::ThresholdNotMet {
+                actual: record.score as i64,
+                threshold: threshold as i64,
+            });
+        }
+
+        let id = {
+            let mut hasher = Sha256::new();
+            hasher.update(record.agent_id.as_bytes());
+            hasher.update(now.to_le_bytes());
+            hasher.update(threshold.to_le_bytes());
+            format!("repproof_{}", hex::encode(&hasher.finalize()[..8]))
+        };
+
+        let mut proof = Self {
+            id,
+            agent_id: record.agent_id.clone(),
+            commitment: commitment(record),
+            proof_data: Vec::new(),
+            threshold,
+            generated_at: now,
+            expires_at: Some(now.saturating_add(PROOF_TTL_SECS)),
+        };
+        proof.proof_data = proof.placeholder_proof();
+        Ok(proof)
+    }
+
+    /// Check if proof is expired at `now`
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires| now >= expires)
+    }
+
+    /// Serialize proof to JSON
+    pub fn to_json(&self) -> Result<String, ProofError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))
+    }
+
+    /// Deserialize proof from JSON
+    pub fn from_json(json: &str) -> Result<Self, ProofError> {
+        serde_json::from_str(json)
+            .map_err(|e| ProofError::DeserializationError(e.to_string()))
+    }
+
+    /// Placeholder proof bytes binding the commitment to the public inputs
+    fn placeholder_proof(&self) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.commitment.as_bytes());
+        hasher.update(self.agent_id.as_bytes());
+        hasher.update(self.threshold.to_le_bytes());
+        hasher.update(self.generated_at.to_le_bytes());
+        hasher.update(PROOF_VERSION);
+        hasher.finalize().to_vec()
+    }
+}
+
+/// Commitment hash for a reputation record
+fn commitment(record: &ReputationRecord) -> String {
+    let json = serde_json::to_string(record).unwrap_or_default();
+    hex::encode(Sha256::digest(json.as_bytes()))
+}
+
+/// Verifier for reputation proofs
+pub struct ReputationProofVerifier {
+    /// Maximum proof age in seconds
+    max_proof_age: u64,
+    /// Highest score a threshold can be
+    max_score: u32,
+    /// Time source
+    clock: Box<dyn Clock>,
+}
+
+impl Default for ReputationProofVerifier {
+    fn default() -> Self {
+        Self {
+            max_proof_age: PROOF_TTL_SECS,
+            max_score: TrackerConfig::default().max_score,
+            clock: Box::new(SystemClock),
+        }
+    }
+}
+
+impl ReputationProofVerifier {
+    /// Create new verifier
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set maximum proof age
+    pub fn with_max_age(mut self, max_age_seconds: u64) -> Self {
+        self.max_proof_age = max_age_seconds;
+        self
+    }
+
+    /// Accept thresholds up to `config`'s highest score, for proofs from a
+    /// tracker with a non-default range
+    pub fn with_score_range(mut self, config: &TrackerConfig) -> Self {
+        self.max_score = config.max_score;
+        self
+    }
+
+    /// Use a different time source (e.g. `MockClock` in tests)
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Verify a proof
+    pub fn verify(&self, proof: &ReputationProof) -> VerificationResult {
+        let now = self.clock.now();
+        let failure = |reason: &str| VerificationResult {
+            valid: false,
+            proof_id: proof.id.clone(),
+            agent_id: proof.agent_id.clone(),
+            verified_at: now,
+            notes: vec![format!("Verification failed: {}", reason)],
+        };
+
+        if proof.is_expired_at(now) {
+            return failure("Proof has expired");
+        }
+        if proof.generated_at > now {
+            return failure("Proof is from the future");
+        }
+        if now - proof.generated_at > self.max_proof_age {
+            return failure("Proof is too old");
+        }
+        if proof.threshold > self.max_score {
+            return failure("Threshold is out of range");
+        }
+        // Placeholder verification; in production, actual ZK verification
+        if proof.proof_data != proof.placeholder_proof() {
+            return failure("Proof data verification failed");
+        }
+
+        VerificationResult {
+            valid: true,
+            proof_id: proof.id.clone(),
+            agent_id: proof.agent_id.clone(),
+            verified_at: now,
+            notes: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::reputation::{ReputationEvent, ScoreBounds};
+
+    #[test]
+    fn test_reputation_threshold_proof() {
+        let mut record = ReputationRecord::new_at("agent-001".into(), 1_000);
+        record.apply_event_at(ReputationEvent::ManualAdjustment { reason: "seed".into(), amount: 550 }, 1_000).unwrap();
+
+        assert!(matches!(
+            ReputationProof::prove_threshold_at(&record, 700, 1_000),
+            Err(ProofError::ThresholdNotMet { actual: 650, threshold: 700 })
+        ));
+        let proof = ReputationProof::prove_threshold_at(&record, 600, 1_000).unwrap();
+        let restored = ReputationProof::from_json(&proof.to_json().unwrap()).unwrap();
+        assert_eq!(restored, proof);
+
+        let clock = MockClock::new(2_000);
+        let verifier = ReputationProofVerifier::new().with_clock(clock.clone());
+        assert!(verifier.verify(&restored).valid);
+
+        // The proof is bound to the agent, threshold, and generation time
+        let mut tampered = proof.clone();
+        tampered.agent_id = "agent-002".into();
+        assert!(!verifier.verify(&tampered).valid);
+        let mut tampered = proof.clone();
+        tampered.threshold = 650;
+        assert!(!verifier.verify(&tampered).valid);
+        let mut tampered = proof.clone();
+        tampered.generated_at = 1_500;
+        assert!(!verifier.verify(&tampered).valid);
+
+        clock.set(1_000 + PROOF_TTL_SECS);
+        assert!(!verifier.verify(&proof).valid);
+        let late = ReputationProof::prove_threshold_at(&record, 600, u64::MAX).unwrap();
+        assert_eq!(late.expires_at, Some(u64::MAX));
+
+        // Thresholds are checked against the tracker's range
+        let config = TrackerConfig { max_score: 10_000, default_start: 6_000, ..Default::default() };
+        let bounds = ScoreBounds { min: 0, max: 10_000, start: 6_000 };
+        let record = ReputationRecord::new_bounded("agent-003".into(), bounds, 1_000);
+        let proof = ReputationProof::prove_threshold_at(&record, 5_000, 1_000).unwrap();
+        clock.set(2_000);
+        assert!(!verifier.verify(&proof).valid);
+        let verifier = ReputationProofVerifier::new().with_clock(clock.clone()).with_score_range(&config);
+        assert!(verifier.verify(&proof).valid);
+    }
+}
@@ -0,0 +1,229 @@
+//! Shared Reputation Tracker
+//!
+//! Lets concurrent services apply events and read scores without a global
+//! lock around one `ReputationTracker`.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::reputation::{
+    AppliedEvent, LeaderboardEntry, ReputationError, ReputationEvent, ReputationLevel, ReputationRecord,
+    ReputationTracker,
+};
+
+/// Shards used by `SharedReputationTracker::new`
+const DEFAULT_SHARDS: usize = 16;
+
+/// Cloneable, thread-safe reputation tracker
+///
+/// Agents are spread over shards, each a `ReputationTracker` behind its own
+/// lock, so events for agents in different shards apply in parallel and
+/// reads only wait on writes to the same shard. The leaderboard is cached
+/// behind a separate lock and rebuilt on the first read after a change.
+///
+/// Each shard only sees its own agents, so features relating two agents,
+/// like endorsements, only work through `write` when both land in the same
+/// shard.
+#[derive(Clone)]
+pub struct SharedReputationTracker {
+    inner: Arc<Shared>,
+}
+
+/// State shared by every clone
+struct Shared {
+    shards: Vec<RwLock<ReputationTracker>>,
+    leaderboard: RwLock<Vec<LeaderboardEntry>>,
+    leaderboard_dirty: AtomicBool,
+}
+
+impl SharedReputationTracker {
+    /// Create a tracker with default shards and configuration
+    pub fn new() -> Self {
+        Self::with_shards(DEFAULT_SHARDS, ReputationTracker::new)
+    }
+
+    /// Create a tracker with `count` shards, each built by `factory`
+    ///
+    /// Every shard should be configured the same; give them clones of one
+    /// clock (e.g. a `MockClock`) to control time in tests.
+    pub fn with_shards(count: usize, factory: impl Fn() -> ReputationTracker) -> Self {
+        Self {
+            inner: Arc::new(Shared {
+                shards: (0..count.max(1)).map(|_| RwLock::new(factory())).collect(),
+                leaderboard: RwLock::new(Vec::new()),
+                leaderboard_dirty: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Run a closure with shared access to an agent's shard
+    pub fn read<T>(&self, agent_id: &str, f: impl FnOnce(&ReputationTracker) -> T) -> T {
+        f(&self.read_shard(agent_id))
+    }
+
+    /// Run a closure with exclusive access to an agent's shard
+    pub fn write<T>(&self, agent_id: &str, f: impl FnOnce(&mut ReputationTracker) -> T) -> T {
+        let result = f(&mut self.write_shard(agent_id));
+        self.inner.leaderboard_dirty.store(true, Ordering::Release);
+        result
+    }
+
+    /// Apply event to agent
+    pub fn apply_event_full(&self, agent_id: &str, event: ReputationEvent) -> Result<AppliedEvent, ReputationError> {
+        self.write(agent_id, |tracker| tracker.apply_event_full(agent_id, event))
+    }
+
+    /// Apply event to agent as of `timestamp` rather than the clock's time
+    pub fn apply_event_at(&self, agent_id: &str, event: ReputationEvent, timestamp: u64) -> Result<i32, ReputationError> {
+        self.write(agent_id, |tracker| tracker.apply_event_at(agent_id, event, timestamp))
+    }
+
+    /// Get an agent's record
+    pub fn get(&self, agent_id: &str) -> Option<ReputationRecord> {
+        self.read_shard(agent_id).get(agent_id).cloned()
+    }
+
+    /// Get agent score
+    pub fn score(&self, agent_id: &str) -> u32 {
+        self.read_shard(agent_id).score(agent_id)
+    }
+
+    /// Get agent level
+    pub fn level(&self, agent_id: &str) -> ReputationLevel {
+        self.read_shard(agent_id).level(agent_id)
+    }
+
+    /// Get number of tracked agents
+    pub fn agent_count(&self) -> usize {
+        self.inner.shards.iter().map(|shard| read(shard).agent_count()).sum()
+    }
+
+    /// Top `count` agents, in `ReputationTracker::leaderboard` order
+    pub fn leaderboard(&self, count: usize) -> Vec<LeaderboardEntry> {
+        if self.inner.leaderboard_dirty.load(Ordering::Acquire) {
+            let mut cache = self.inner.leaderboard.write().unwrap_or_else(PoisonError::into_inner);
+            // Another reader may have rebuilt it while we waited
+            if self.inner.leaderboard_dirty.swap(false, Ordering::AcqRel) {
+                *cache = self.build_leaderboard();
+            }
+        }
+        let cache = self.inner.leaderboard.read().unwrap_or_else(PoisonError::into_inner);
+        cache.iter().take(count).cloned().collect()
+    }
+
+    /// Rank every agent across the shards
+    fn build_leaderboard(&self) -> Vec<LeaderboardEntry> {
+        let shards: Vec<RwLockReadGuard<'_, ReputationTracker>> = self.inner.shards.iter().map(read).collect();
+        let mut sorted: Vec<&ReputationRecord> = shards.iter().flat_map(|shard| shard.export()).collect();
+        sorted.sort_by(|a, b| a.leaderboard_cmp(b));
+        sorted.into_iter()
+            .enumerate()
+            .map(|(index, r)| LeaderboardEntry {
+                agent_id: r.agent_id.clone(),
+                score: r.score,
+                level: r.level,
+                rank: index + 1,
+            })
+            .collect()
+    }
+
+    /// Shard holding an agent
+    fn shard(&self, agent_id: &str) -> &RwLock<ReputationTracker> {
+        let mut hasher = DefaultHasher::new();
+        agent_id.hash(&mut hasher);
+        &self.inner.shards[(hasher.finish() % self.inner.shards.len() as u64) as usize]
+    }
+
+    /// Shared lock on an agent's shard, recovering from poisoning
+    ///
+    /// Tracker methods validate before mutating, so a panic in another
+    /// holder doesn't leave the state half-updated.
+    fn read_shard(&self, agent_id: &str) -> RwLockReadGuard<'_, ReputationTracker> {
+        read(self.shard(agent_id))
+    }
+
+    /// Exclusive lock on an agent's shard, recovering from poisoning
+    fn write_shard(&self, agent_id: &str) -> RwLockWriteGuard<'_, ReputationTracker> {
+        self.shard(agent_id).write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for SharedReputationTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shared lock on a shard, recovering from poisoning
+fn read(shard: &RwLock<ReputationTracker>) -> RwLockReadGuard<'_, ReputationTracker> {
+    shard.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    /// Deterministic event stream for one agent
+    fn event(i: usize) -> ReputationEvent {
+        match i % 7 {
+            0 => ReputationEvent::TradeFailed { loss_bps: 300 },
+            1 | 2 => ReputationEvent::TradeSuccess { pnl_bps: 200, volume_usd: 5_000 },
+            3 => ReputationEvent::GovernanceVote,
+            4 => ReputationEvent::AttestationExpired,
+            5 => ReputationEvent::ProofSubmitted,
+            _ => ReputationEvent::Slashed { reason: "test".into(), amount: 5 },
+        }
+    }
+
+    #[test]
+    fn test_concurrent_events() {
+        const THREADS: usize = 16;
+        const EVENTS: usize = 10_000;
+        const AGENTS_PER_THREAD: usize = 4;
+        let shared = SharedReputationTracker::new();
+
+        let workers: Vec<_> = (0..THREADS)
+            .map(|worker| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    for i in 0..EVENTS {
+                        let agent = format!("agent-{}", worker * AGENTS_PER_THREAD + i % AGENTS_PER_THREAD);
+                        shared.apply_event_full(&agent, event(i / AGENTS_PER_THREAD)).unwrap();
+                        // Readers interleave with writers
+                        if i % 1_000 == 0 {
+                            assert!(shared.leaderboard(3).len() <= 3);
+                        }
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        let mut replay = ReputationTracker::new();
+        for worker in 0..THREADS {
+            for i in 0..EVENTS {
+                let agent = format!("agent-{}", worker * AGENTS_PER_THREAD + i % AGENTS_PER_THREAD);
+                replay.apply_event_full(&agent, event(i / AGENTS_PER_THREAD)).unwrap();
+            }
+        }
+
+        assert_eq!(shared.agent_count(), THREADS * AGENTS_PER_THREAD);
+        for record in replay.export() {
+            let concurrent = shared.get(&record.agent_id).unwrap();
+            assert_eq!(concurrent.score, record.score);
+            assert_eq!(concurrent.total_events, record.total_events);
+        }
+        let expected: Vec<(String, usize)> = replay.leaderboard_page(0, usize::MAX).into_iter()
+            .map(|e| (e.agent_id, e.rank))
+            .collect();
+        let actual: Vec<(String, usize)> = shared.leaderboard(usize::MAX).into_iter()
+            .map(|e| (e.agent_id, e.rank))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+}
@@ -2,11 +2,15 @@
 //!
 //! Manages agent syndicates, membership, and governance.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
-use sdkey_manager::DelegationChain;
-use zk_proofs::PerformanceProof;
+use sdkey_manager::{AgentSDKey, DelegationChain, SDKeyError};
+use crate::clock::{Clock, SystemClock};
+use crate::history::{self, GovernanceHistory};
+use zk_proofs::{PerformanceProof, ProofType, ProofVerifier, ThresholdCondition, VerificationResult};
 
 /// Syndicate configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,8 +21,20 @@ pub struct SyndicateConfig {
     pub name: String,
     /// Description
     pub description: String,
+    /// Discovery tags (e.g. strategy or market)
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Website URL
+    #[serde(default)]
+    pub website: Option<String>,
+    /// Who can discover and join the syndicate
+    #[serde(default)]
+    pub visibility: SyndicateVisibility,
     /// Minimum reputation score to join (0-1000)
     pub min_reputation: u32,
+    /// Require evidence on RemoveMember proposals
+    #[serde(default)]
+    pub require_removal_evidence: bool,
     /// Minimum performance proof required
     pub requires_performance_proof: bool,
     /// Minimum PnL in basis points for membership
@@ -27,12 +43,66 @@ pub struct SyndicateConfig {
     pub max_members: usize,
     /// Voting threshold for proposals (basis points, e.g., 5000 = 50%)
     pub voting_threshold_bps: u32,
+    /// Thresholds for specific proposal kinds, replacing `voting_threshold_bps`
+    #[serde(default)]
+    pub threshold_overrides: HashMap<ProposalKind, u32>,
+    /// Minimum participation for a proposal to be valid (basis points of total voting power)
+    #[serde(default = "default_quorum_bps")]
+    pub quorum_bps: u32,
+    /// Leave the proposer's power out of quorum, both cast and total
+    #[serde(default)]
+    pub quorum_excludes_proposer: bool,
+    /// How reputation translates into voting power
+    #[serde(default)]
+    pub voting_model: VotingModel,
+    /// How reputation and contribution combine into voting power
+    #[serde(default)]
+    pub voting_power_formula: VotingPowerFormula,
+    /// Idle time over which a member's voting power halves (no decay if unset)
+    #[serde(default)]
+    pub voting_power_half_life_secs: Option<u64>,
+    /// Voting power decay never takes a member below this
+    #[serde(default)]
+    pub min_voting_power: u32,
     /// Proposal duration in seconds
     pub proposal_duration: u64,
+    /// Timelock between passing and execution for specific proposal kinds (seconds)
+    #[serde(default)]
+    pub execution_delay_secs: HashMap<ProposalKind, u64>,
+    /// Let members without `can_create_proposals` open `Custom` proposals
+    #[serde(default = "default_true")]
+    pub members_can_propose_custom: bool,
+    /// Maximum proposals one member may have open at once (unlimited if unset)
+    #[serde(default)]
+    pub max_active_proposals_per_member: Option<usize>,
+    /// Minimum time between proposals from the same member (seconds)
+    #[serde(default)]
+    pub proposal_cooldown_secs: u64,
+    /// Vetoes each member may use per `veto_period_secs` (unlimited if unset)
+    #[serde(default)]
+    pub max_vetoes_per_period: Option<u32>,
+    /// Window over which vetoes are counted (seconds)
+    #[serde(default = "default_veto_period_secs")]
+    pub veto_period_secs: u64,
+    /// Time a member who left must wait before rejoining (seconds)
+    #[serde(default)]
+    pub rejoin_cooldown_secs: u64,
+    /// How long members removed by proposal stay banned (`None` bans for good)
+    #[serde(default)]
+    pub removal_ban_secs: Option<u64>,
     /// Profit share for syndicate (basis points)
     pub syndicate_fee_bps: u32,
     /// Treasury address
     pub treasury_address: Option<String>,
+    /// Maximum audit log entries kept in memory (oldest are dropped first)
+    #[serde(default = "default_max_events")]
+    pub max_events: usize,
+    /// Largest serialized payload a structured custom proposal may carry (bytes)
+    #[serde(default = "default_max_custom_payload_bytes")]
+    pub max_custom_payload_bytes: usize,
+    /// Past configs kept for `config_at` (oldest are compacted first)
+    #[serde(default = "default_max_config_versions")]
+    pub max_config_versions: usize,
 }
 
 impl Default for SyndicateConfig {
@@ -41,18 +111,257 @@ impl Default for SyndicateConfig {
             id: String::new(),
             name: "Default Syndicate".into(),
             description: String::new(),
+            tags: Vec::new(),
+            website: None,
+            visibility: SyndicateVisibility::default(),
             min_reputation: 300,
+            require_removal_evidence: false,
             requires_performance_proof: true,
             min_pnl_bps: 0,
             max_members: 100,
             voting_threshold_bps: 5000, // 50%
+            threshold_overrides: HashMap::new(),
+            quorum_bps: default_quorum_bps(),
+            quorum_excludes_proposer: false,
+            voting_model: VotingModel::default(),
+            voting_power_formula: VotingPowerFormula::default(),
+            voting_power_half_life_secs: None,
+            min_voting_power: 0,
             proposal_duration: 86_400,   // 24 hours
+            execution_delay_secs: HashMap::new(),
+            members_can_propose_custom: true,
+            max_active_proposals_per_member: None,
+            proposal_cooldown_secs: 0,
+            max_vetoes_per_period: None,
+            veto_period_secs: default_veto_period_secs(),
+            rejoin_cooldown_secs: 0,
+            removal_ban_secs: None,
             syndicate_fee_bps: 500,      // 5%
             treasury_address: None,
+            max_events: default_max_events(),
+            max_custom_payload_bytes: default_max_custom_payload_bytes(),
+            max_config_versions: default_max_config_versions(),
         }
     }
 }
 
+/// Syndicate discoverability
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SyndicateVisibility {
+    /// Listed publicly, anyone may request membership
+    #[default]
+    Public,
+    /// Not listed, but anyone may request membership
+    Private,
+    /// Membership requests require an invitation
+    InviteOnly,
+}
+
+/// Voting power model
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VotingModel {
+    /// Voting power equals reputation
+    #[default]
+    ReputationLinear,
+    /// Voting power is the integer square root of reputation
+    Quadratic,
+    /// Every member has a voting power of 1
+    OnePersonOneVote,
+    /// Voting power equals reputation, up to a cap
+    Capped { max: u32 },
+}
+
+impl VotingModel {
+    /// Voting power for a given reputation
+    pub fn voting_power(&self, reputation: u32) -> u32 {
+        match self {
+            Self::ReputationLinear => reputation,
+            Self::Quadratic => isqrt(reputation),
+            Self::OnePersonOneVote => 1,
+            Self::Capped { max } => reputation.min(*max),
+        }
+    }
+}
+
+/// Weights combining reputation and contribution into voting power
+///
+/// `power = (model(reputation) * reputation_weight_bps
+///          + min(contribution / contribution_divisor, contribution_cap) * contribution_weight_bps) / 10000`
+///
+/// The default counts reputation alone, as the voting model defines it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VotingPowerFormula {
+    /// Weight of reputation-derived power (basis points)
+    pub reputation_weight_bps: u32,
+    /// Weight of contribution points (basis points)
+    pub contribution_weight_bps: u32,
+    /// Contribution score per contribution point
+    pub contribution_divisor: u64,
+    /// Maximum contribution points counted
+    pub contribution_cap: u64,
+}
+
+impl Default for VotingPowerFormula {
+    fn default() -> Self {
+        Self {
+            reputation_weight_bps: 10_000,
+            contribution_weight_bps: 0,
+            contribution_divisor: 1,
+            contribution_cap: u64::MAX,
+        }
+    }
+}
+
+impl VotingPowerFormula {
+    /// Combine reputation-derived power with a contribution score
+    pub fn power(&self, reputation_power: u32, contribution_score: u64) -> u32 {
+        let points = (contribution_score / self.contribution_divisor.max(1)).min(self.contribution_cap);
+        let weighted = reputation_power as u128 * self.reputation_weight_bps as u128
+            + points as u128 * self.contribution_weight_bps as u128;
+        (weighted / 10_000).min(u32::MAX as u128) as u32
+    }
+}
+
+/// Integer square root (floor)
+fn isqrt(n: u32) -> u32 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+fn default_quorum_bps() -> u32 {
+    2000 // 20%
+}
+
+fn default_veto_period_secs() -> u64 {
+    30 * 86_400 // 30 days
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_events() -> usize {
+    10_000
+}
+
+fn default_max_custom_payload_bytes() -> usize {
+    16 * 1024
+}
+
+fn default_max_config_versions() -> usize {
+    100
+}
+
+/// Sequence number from a legacy `{syndicate_id}-{n}` proposal ID
+fn legacy_proposal_seq(proposal_id: &str) -> u64 {
+    proposal_id.rsplit('-').next().and_then(|n| n.parse().ok()).unwrap_or(0)
+}
+
+/// Collision-resistant proposal ID
+///
+/// A random nonce is mixed in so that instances restored from the same
+/// snapshot don't generate the same IDs.
+fn generate_proposal_id(syndicate_id: &str, proposer: &str, created_at: u64, sequence: u64) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(syndicate_id.as_bytes());
+    hasher.update(proposer.as_bytes());
+    hasher.update(created_at.to_le_bytes());
+    hasher.update(sequence.to_le_bytes());
+    hasher.update(rand::random::<[u8; 16]>());
+    format!("{}-{}", syndicate_id, hex::encode(&hasher.finalize()[..8]))
+}
+
+/// Actor recorded for actions taken by the syndicate itself
+const SYSTEM_ACTOR: &str = "system";
+
+/// Domain tag opening every vote signing message
+const VOTE_TAG: &[u8] = b"syndicate-vote:v2";
+
+/// How far a contribution report's timestamp can be from the syndicate's
+/// clock
+const CONTRIBUTION_WINDOW_SECS: u64 = 600;
+
+impl SyndicateConfig {
+    /// Voting threshold that applies to a proposal kind
+    pub fn threshold_for(&self, kind: ProposalKind) -> u32 {
+        self.threshold_overrides.get(&kind).copied().unwrap_or(self.voting_threshold_bps)
+    }
+
+    /// Voting power of a member before decay
+    ///
+    /// Observers always have zero power.
+    pub fn member_voting_power(&self, member: &SyndicateMember) -> u32 {
+        if member.role.can_vote() {
+            let reputation_power = self.voting_model.voting_power(member.reputation);
+            self.voting_power_formula.power(reputation_power, member.contribution_score)
+        } else {
+            0
+        }
+    }
+
+    /// Apply the decay policy to `power` after `idle_secs` without activity
+    ///
+    /// Power halves every `voting_power_half_life_secs`, but never drops
+    /// below `min_voting_power` (or the undecayed power, if that is lower).
+    pub fn decayed_voting_power(&self, power: u32, idle_secs: u64) -> u32 {
+        let Some(half_life) = self.voting_power_half_life_secs else {
+            return power;
+        };
+        let decayed = (power as f64 * 0.5_f64.powf(idle_secs as f64 / half_life as f64)).round() as u32;
+        decayed.max(self.min_voting_power.min(power))
+    }
+
+    /// Timelock that applies to a proposal kind (zero if none)
+    pub fn execution_delay_for(&self, kind: ProposalKind) -> u64 {
+        self.execution_delay_secs.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Validate config values
+    pub fn validate(&self) -> Result<(), SyndicateError> {
+        for (field, bps) in [
+            ("voting_threshold_bps", self.voting_threshold_bps),
+            ("quorum_bps", self.quorum_bps),
+            ("syndicate_fee_bps", self.syndicate_fee_bps),
+        ] {
+            if bps > 10_000 {
+                return Err(SyndicateError::InvalidConfig(format!(
+                    "{} must be at most 10000, got {}",
+                    field, bps
+                )));
+            }
+        }
+        for (kind, bps) in &self.threshold_overrides {
+            if *bps > 10_000 {
+                return Err(SyndicateError::InvalidConfig(format!(
+                    "threshold override for {:?} must be at most 10000, got {}",
+                    kind, bps
+                )));
+            }
+        }
+        if self.max_events == 0 {
+            return Err(SyndicateError::InvalidConfig("max_events must be positive".into()));
+        }
+        if self.max_config_versions == 0 {
+            return Err(SyndicateError::InvalidConfig("max_config_versions must be positive".into()));
+        }
+        if self.voting_power_formula.contribution_divisor == 0 {
+            return Err(SyndicateError::InvalidConfig("contribution_divisor must be positive".into()));
+        }
+        if self.voting_power_half_life_secs == Some(0) {
+            return Err(SyndicateError::InvalidConfig("voting_power_half_life_secs must be positive".into()));
+        }
+        Ok(())
+    }
+}
+
 /// Syndicate member
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyndicateMember {
@@ -66,17 +375,172 @@ pub struct SyndicateMember {
     pub contribution_score: u64,
     /// Performance proof (if any)
     pub performance_proof: Option<String>, // Proof ID
+    /// When the attached proof was generated
+    #[serde(default)]
+    pub proof_generated_at: Option<u64>,
+    /// When the attached proof expires
+    #[serde(default)]
+    pub proof_expires_at: Option<u64>,
+    /// When the attached proof was last replaced
+    #[serde(default)]
+    pub proof_updated_at: Option<u64>,
     /// Role in syndicate
     pub role: MemberRole,
     /// Voting power (calculated from reputation + contribution)
     pub voting_power: u32,
     /// Is active
     pub active: bool,
+    /// When the member voluntarily left (if they did)
+    #[serde(default)]
+    pub left_at: Option<u64>,
+    /// Last vote, contribution, or proposal by this member
+    #[serde(default)]
+    pub last_activity: u64,
+}
+
+/// Invitation to join an invite-only syndicate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Invitation {
+    /// Invited agent ID
+    pub agent_id: String,
+    /// Member who issued the invitation
+    pub invited_by: String,
+    /// When the invitation was issued
+    pub invited_at: u64,
+}
+
+/// Signed, single-use invite that lets an agent skip the entry gates
+///
+/// `issuer_id` is the issuer's SDKey agent ID, which is derived from their
+/// public key, so the embedded key can be checked against it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyndicateInvite {
+    /// Syndicate the invite is for
+    pub syndicate_id: String,
+    /// Agent allowed to use the invite
+    pub invitee_agent_id: String,
+    /// Invite is rejected at or after this time
+    pub expires_at: u64,
+    /// Member who issued the invite
+    pub issuer_id: String,
+    /// Issuer's Ed25519 public key (hex)
+    pub issuer_public_key: String,
+    /// Issuer's signature over `signing_message()` (hex)
+    pub signature: String,
+}
+
+impl SyndicateInvite {
+    /// Canonical bytes the issuer signs
+    pub fn signing_message(&self) -> Vec<u8> {
+        serde_json::to_vec(&(
+            "syndicate-invite",
+            &self.syndicate_id,
+            &self.invitee_agent_id,
+            self.expires_at,
+            &self.issuer_id,
+        )).unwrap_or_default()
+    }
+
+    /// Check the signature and that the key belongs to `issuer_id`
+    pub fn verify(&self) -> bool {
+        let Some(key) = hex::decode(&self.issuer_public_key).ok()
+            .and_then(|b| <[u8; 32]>::try_from(b).ok()) else {
+            return false;
+        };
+        let Some(sig) = hex::decode(&self.signature).ok()
+            .and_then(|b| <[u8; 64]>::try_from(b).ok()) else {
+            return false;
+        };
+        hex::encode(Sha256::digest(key)) == self.issuer_id
+            && sdkey_manager::verify_signature(&key, &self.signing_message(), &sig).is_ok()
+    }
+
+    /// Identifier used to track consumed invites
+    ///
+    /// Covers only the signed fields, so re-encoding the signature (hex
+    /// case, say) does not yield a fresh invite.
+    fn hash(&self) -> String {
+        hex::encode(Sha256::digest(self.signing_message()))
+    }
+}
+
+/// Membership request awaiting its AddMember proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingMembership {
+    /// Applicant agent ID
+    pub agent_id: String,
+    /// Reputation submitted with the request
+    pub reputation: u32,
+    /// Performance proof submitted with the request
+    pub proof_id: Option<String>,
+    /// When the submitted proof was generated
+    #[serde(default)]
+    pub proof_generated_at: Option<u64>,
+    /// When the submitted proof expires
+    #[serde(default)]
+    pub proof_expires_at: Option<u64>,
+}
+
+/// Bar on an agent joining the syndicate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BanRecord {
+    /// Banned agent ID
+    pub agent_id: String,
+    /// Member who imposed the ban ("system" for removals by proposal)
+    pub banned_by: String,
+    /// Why the agent was banned
+    pub reason: String,
+    /// When the ban started
+    pub banned_at: u64,
+    /// When the ban lapses (`None` for permanent bans)
+    pub until: Option<u64>,
+}
+
+impl BanRecord {
+    /// Whether the ban is still in force at `now`
+    pub fn is_active(&self, now: u64) -> bool {
+        self.until.map_or(true, |until| now < until)
+    }
+}
+
+/// Record of a member who was removed from the syndicate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemovedMember {
+    /// Agent ID
+    pub agent_id: String,
+    /// Role held at removal
+    pub role: MemberRole,
+    /// Reason for removal
+    pub reason: String,
+    /// Removal timestamp
+    pub removed_at: u64,
+}
+
+/// Delegation of one member's voting power to another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteDelegation {
+    /// Member giving up their vote
+    pub delegator: String,
+    /// Member voting on their behalf
+    pub delegatee: String,
+    /// Creation timestamp
+    pub created_at: u64,
+    /// Expiration timestamp
+    pub expires_at: u64,
+}
+
+impl VoteDelegation {
+    /// Check if delegation is in force at the given time
+    pub fn is_active_at(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
 }
 
 /// Member role
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MemberRole {
+    /// Can see proposals and members but never votes
+    Observer,
     /// Regular member
     Member,
     /// Can approve new members
@@ -88,6 +552,21 @@ pub enum MemberRole {
 }
 
 impl MemberRole {
+    /// Rank by privilege, lowest first
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Observer => 0,
+            Self::Member => 1,
+            Self::Approver => 2,
+            Self::Admin => 3,
+            Self::Founder => 4,
+        }
+    }
+
+    pub fn can_vote(&self) -> bool {
+        !matches!(self, Self::Observer)
+    }
+
     pub fn can_approve_members(&self) -> bool {
         matches!(self, Self::Approver | Self::Admin | Self::Founder)
     }
@@ -99,6 +578,25 @@ impl MemberRole {
     pub fn can_modify_config(&self) -> bool {
         matches!(self, Self::Founder)
     }
+
+    /// Whether this role may move another member from `from` to `to`
+    ///
+    /// Founders can assign any non-founder role; admins can only move
+    /// members between `Observer`, `Member` and `Approver`. The founder
+    /// role itself is never assigned or taken away.
+    pub fn can_assign_role(&self, from: MemberRole, to: MemberRole) -> bool {
+        if from == Self::Founder || to == Self::Founder {
+            return false;
+        }
+        match self {
+            Self::Founder => true,
+            Self::Admin => {
+                matches!(from, Self::Observer | Self::Member | Self::Approver)
+                    && matches!(to, Self::Observer | Self::Member | Self::Approver)
+            }
+            _ => false,
+        }
+    }
 }
 
 /// Proposal type
@@ -107,15 +605,277 @@ pub enum ProposalType {
     /// Add a new member
     AddMember { agent_id: String },
     /// Remove a member
-    RemoveMember { agent_id: String },
+    RemoveMember {
+        agent_id: String,
+        #[serde(default)]
+        evidence: Vec<EvidenceItem>,
+    },
     /// Update syndicate config
-    UpdateConfig { field: String, value: String },
+    UpdateConfig(#[serde(deserialize_with = "deserialize_config_change")] ConfigChange),
     /// Execute a trade/action
     ExecuteAction { action_type: String, params: HashMap<String, String> },
     /// Distribute profits
     DistributeProfits { amount: u64 },
     /// Custom proposal
     Custom { title: String, description: String },
+    /// Custom proposal carrying machine-readable data
+    ///
+    /// Governed like `Custom`. The payload must be a JSON object within
+    /// `max_custom_payload_bytes`.
+    StructuredCustom { title: String, description: String, payload: serde_json::Value },
+}
+
+impl ProposalType {
+    /// Discriminant of this proposal type
+    pub fn kind(&self) -> ProposalKind {
+        match self {
+            Self::AddMember { .. } => ProposalKind::AddMember,
+            Self::RemoveMember { .. } => ProposalKind::RemoveMember,
+            Self::UpdateConfig(_) => ProposalKind::UpdateConfig,
+            Self::ExecuteAction { .. } => ProposalKind::ExecuteAction,
+            Self::DistributeProfits { .. } => ProposalKind::DistributeProfits,
+            Self::Custom { .. } | Self::StructuredCustom { .. } => ProposalKind::Custom,
+        }
+    }
+}
+
+/// Evidence supporting a RemoveMember proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EvidenceItem {
+    /// Reference to a reputation slashing event
+    SlashedEvent { event_id: String },
+    /// A proof verification that failed
+    FailedVerification(VerificationResult),
+    /// Free-form statement signed by the reporter's SDKey
+    SignedStatement {
+        reporter: String,
+        text: String,
+        /// Reporter's Ed25519 public key (hex)
+        public_key: String,
+        /// Signature over `statement_message(accused, text)` (hex)
+        signature: String,
+    },
+}
+
+impl EvidenceItem {
+    /// Sign a statement about `accused` with the reporter's SDKey
+    pub fn signed_statement(
+        sdkey: &AgentSDKey,
+        reporter: impl Into<String>,
+        accused: &str,
+        text: impl Into<String>,
+    ) -> Result<Self, SDKeyError> {
+        let text = text.into();
+        let signature = sdkey.sign_hex(&Self::statement_message(accused, &text))?;
+        Ok(Self::SignedStatement {
+            reporter: reporter.into(),
+            text,
+            public_key: sdkey.public_key_hex(),
+            signature,
+        })
+    }
+
+    /// Bytes covered by a statement signature
+    pub fn statement_message(accused: &str, text: &str) -> Vec<u8> {
+        format!("syndicate-evidence:{}:{}", accused, text).into_bytes()
+    }
+
+    /// Check that evidence against `accused` is well-formed
+    ///
+    /// Signed statements must verify against the embedded public key and
+    /// failed verifications must actually be failures.
+    pub fn check(&self, accused: &str) -> bool {
+        match self {
+            Self::SlashedEvent { event_id } => !event_id.is_empty(),
+            Self::FailedVerification(result) => !result.valid,
+            Self::SignedStatement { text, public_key, signature, .. } => {
+                let key: Option<[u8; 32]> = hex::decode(public_key).ok().and_then(|b| b.try_into().ok());
+                let sig: Option<[u8; 64]> = hex::decode(signature).ok().and_then(|b| b.try_into().ok());
+                match (key, sig) {
+                    (Some(key), Some(sig)) => sdkey_manager::verify_signature(
+                        &key,
+                        &Self::statement_message(accused, text),
+                        &sig,
+                    ).is_ok(),
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    /// Short human-readable description
+    pub fn summary(&self) -> String {
+        match self {
+            Self::SlashedEvent { event_id } => format!("slashed event {}", event_id),
+            Self::FailedVerification(result) => format!("failed verification of proof {}", result.proof_id),
+            Self::SignedStatement { reporter, .. } => format!("statement by {}", reporter),
+        }
+    }
+}
+
+/// Proposal type without its payload, for filtering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProposalKind {
+    AddMember,
+    RemoveMember,
+    UpdateConfig,
+    ExecuteAction,
+    DistributeProfits,
+    Custom,
+}
+
+/// Typed change to a mutable `SyndicateConfig` field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConfigChange {
+    SetName(String),
+    SetDescription(String),
+    SetTags(Vec<String>),
+    SetWebsite(Option<String>),
+    SetVisibility(SyndicateVisibility),
+    SetMinReputation(u32),
+    SetRequiresPerformanceProof(bool),
+    SetRequireRemovalEvidence(bool),
+    SetMinPnlBps(i64),
+    SetMaxMembers(usize),
+    SetVotingThresholdBps(u32),
+    /// Set (`Some`) or clear (`None`) the threshold for one proposal kind
+    SetThresholdOverride { kind: ProposalKind, threshold_bps: Option<u32> },
+    SetQuorumBps(u32),
+    SetQuorumExcludesProposer(bool),
+    SetVotingModel(VotingModel),
+    SetVotingPowerFormula(VotingPowerFormula),
+    SetVotingPowerHalfLife(Option<u64>),
+    SetMinVotingPower(u32),
+    SetProposalDuration(u64),
+    /// Set (or clear, with `None`) the timelock for a proposal kind
+    SetExecutionDelay { kind: ProposalKind, delay_secs: Option<u64> },
+    SetMembersCanProposeCustom(bool),
+    SetMaxActiveProposalsPerMember(Option<usize>),
+    SetProposalCooldownSecs(u64),
+    SetMaxVetoesPerPeriod(Option<u32>),
+    SetVetoPeriodSecs(u64),
+    SetRejoinCooldownSecs(u64),
+    SetRemovalBanSecs(Option<u64>),
+    SetSyndicateFeeBps(u32),
+    SetTreasuryAddress(Option<String>),
+    SetMaxEvents(usize),
+    SetMaxCustomPayloadBytes(usize),
+    SetMaxConfigVersions(usize),
+    /// String-based change from before typed changes existed; cannot be executed
+    Legacy { field: String, value: String },
+}
+
+impl ConfigChange {
+    /// Name of the config field being changed
+    pub fn field(&self) -> &str {
+        match self {
+            Self::SetName(_) => "name",
+            Self::SetDescription(_) => "description",
+            Self::SetTags(_) => "tags",
+            Self::SetWebsite(_) => "website",
+            Self::SetVisibility(_) => "visibility",
+            Self::SetMinReputation(_) => "min_reputation",
+            Self::SetRequiresPerformanceProof(_) => "requires_performance_proof",
+            Self::SetRequireRemovalEvidence(_) => "require_removal_evidence",
+            Self::SetMinPnlBps(_) => "min_pnl_bps",
+            Self::SetMaxMembers(_) => "max_members",
+            Self::SetVotingThresholdBps(_) => "voting_threshold_bps",
+            Self::SetThresholdOverride { .. } => "threshold_overrides",
+            Self::SetQuorumBps(_) => "quorum_bps",
+            Self::SetQuorumExcludesProposer(_) => "quorum_excludes_proposer",
+            Self::SetVotingModel(_) => "voting_model",
+            Self::SetVotingPowerFormula(_) => "voting_power_formula",
+            Self::SetVotingPowerHalfLife(_) => "voting_power_half_life_secs",
+            Self::SetMinVotingPower(_) => "min_voting_power",
+            Self::SetProposalDuration(_) => "proposal_duration",
+            Self::SetExecutionDelay { .. } => "execution_delay_secs",
+            Self::SetMembersCanProposeCustom(_) => "members_can_propose_custom",
+            Self::SetMaxActiveProposalsPerMember(_) => "max_active_proposals_per_member",
+            Self::SetProposalCooldownSecs(_) => "proposal_cooldown_secs",
+            Self::SetMaxVetoesPerPeriod(_) => "max_vetoes_per_period",
+            Self::SetVetoPeriodSecs(_) => "veto_period_secs",
+            Self::SetRejoinCooldownSecs(_) => "rejoin_cooldown_secs",
+            Self::SetRemovalBanSecs(_) => "removal_ban_secs",
+            Self::SetSyndicateFeeBps(_) => "syndicate_fee_bps",
+            Self::SetTreasuryAddress(_) => "treasury_address",
+            Self::SetMaxEvents(_) => "max_events",
+            Self::SetMaxCustomPayloadBytes(_) => "max_custom_payload_bytes",
+            Self::SetMaxConfigVersions(_) => "max_config_versions",
+            Self::Legacy { field, .. } => field,
+        }
+    }
+
+    /// Apply the change to a config
+    fn apply_to(&self, config: &mut SyndicateConfig) -> Result<(), SyndicateError> {
+        match self.clone() {
+            Self::SetName(v) => config.name = v,
+            Self::SetDescription(v) => config.description = v,
+            Self::SetTags(v) => config.tags = v,
+            Self::SetWebsite(v) => config.website = v,
+            Self::SetVisibility(v) => config.visibility = v,
+            Self::SetMinReputation(v) => config.min_reputation = v,
+            Self::SetRequiresPerformanceProof(v) => config.requires_performance_proof = v,
+            Self::SetRequireRemovalEvidence(v) => config.require_removal_evidence = v,
+            Self::SetMinPnlBps(v) => config.min_pnl_bps = v,
+            Self::SetMaxMembers(v) => config.max_members = v,
+            Self::SetVotingThresholdBps(v) => config.voting_threshold_bps = v,
+            Self::SetThresholdOverride { kind, threshold_bps } => match threshold_bps {
+                Some(bps) => {
+                    config.threshold_overrides.insert(kind, bps);
+                }
+                None => {
+                    config.threshold_overrides.remove(&kind);
+                }
+            },
+            Self::SetQuorumBps(v) => config.quorum_bps = v,
+            Self::SetQuorumExcludesProposer(v) => config.quorum_excludes_proposer = v,
+            Self::SetVotingModel(v) => config.voting_model = v,
+            Self::SetVotingPowerFormula(v) => config.voting_power_formula = v,
+            Self::SetVotingPowerHalfLife(v) => config.voting_power_half_life_secs = v,
+            Self::SetMinVotingPower(v) => config.min_voting_power = v,
+            Self::SetProposalDuration(v) => config.proposal_duration = v,
+            Self::SetExecutionDelay { kind, delay_secs } => match delay_secs {
+                Some(secs) => {
+                    config.execution_delay_secs.insert(kind, secs);
+                }
+                None => {
+                    config.execution_delay_secs.remove(&kind);
+                }
+            },
+            Self::SetMembersCanProposeCustom(v) => config.members_can_propose_custom = v,
+            Self::SetMaxActiveProposalsPerMember(v) => config.max_active_proposals_per_member = v,
+            Self::SetProposalCooldownSecs(v) => config.proposal_cooldown_secs = v,
+            Self::SetMaxVetoesPerPeriod(v) => config.max_vetoes_per_period = v,
+            Self::SetVetoPeriodSecs(v) => config.veto_period_secs = v,
+            Self::SetRejoinCooldownSecs(v) => config.rejoin_cooldown_secs = v,
+            Self::SetRemovalBanSecs(v) => config.removal_ban_secs = v,
+            Self::SetSyndicateFeeBps(v) => config.syndicate_fee_bps = v,
+            Self::SetTreasuryAddress(v) => config.treasury_address = v,
+            Self::SetMaxEvents(v) => config.max_events = v,
+            Self::SetMaxCustomPayloadBytes(v) => config.max_custom_payload_bytes = v,
+            Self::SetMaxConfigVersions(v) => config.max_config_versions = v,
+            Self::Legacy { field, .. } => return Err(SyndicateError::LegacyConfigChange(field)),
+        }
+        Ok(())
+    }
+}
+
+/// Accept config changes written as `{ field, value }` strings
+fn deserialize_config_change<'de, D>(deserializer: D) -> Result<ConfigChange, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StoredChange {
+        Typed(ConfigChange),
+        Legacy { field: String, value: String },
+    }
+
+    Ok(match StoredChange::deserialize(deserializer)? {
+        StoredChange::Typed(change) => change,
+        StoredChange::Legacy { field, value } => ConfigChange::Legacy { field, value },
+    })
 }
 
 /// A governance proposal
@@ -123,6 +883,9 @@ pub enum ProposalType {
 pub struct Proposal {
     /// Proposal ID
     pub id: String,
+    /// Creation order within the syndicate (starts at 1)
+    #[serde(default)]
+    pub sequence: u64,
     /// Proposal type
     pub proposal_type: ProposalType,
     /// Proposer agent ID
@@ -135,428 +898,5974 @@ pub struct Proposal {
     pub votes_for: u64,
     /// Against votes (voting power)
     pub votes_against: u64,
-    /// Voters (agent_id -> vote)
-    pub voters: HashMap<String, bool>,
+    /// Abstain votes (voting power)
+    #[serde(default)]
+    pub votes_abstain: u64,
+    /// Votes cast, in casting order; authoritative for tallying
+    #[serde(default)]
+    pub votes: Vec<VoteRecord>,
+    /// Voters (agent_id -> vote), derived from `votes`
+    ///
+    /// Kept in sync for readers of the older format; will be removed.
+    #[serde(deserialize_with = "deserialize_voters")]
+    pub voters: HashMap<String, VoteChoice>,
+    /// Voting power each voter cast, derived from `votes`
+    #[serde(default)]
+    pub voter_power: HashMap<String, u64>,
+    /// Members whose power was cast by a delegate (delegator -> delegatee)
+    #[serde(default)]
+    pub delegated_voters: HashMap<String, String>,
+    /// Voting power of each active member when the proposal was created
+    #[serde(default)]
+    pub power_snapshot: HashMap<String, u64>,
+    /// Signed receipts for votes cast through `vote_signed`
+    #[serde(default)]
+    pub vote_receipts: HashMap<String, SignedVote>,
+    /// Member co-signatures authorizing an `ExecuteAction`, in signing order
+    #[serde(default)]
+    pub action_signatures: Vec<ActionSignature>,
     /// Status
     pub status: ProposalStatus,
+    /// Veto details (if vetoed)
+    #[serde(default)]
+    pub veto: Option<VetoRecord>,
+    /// Earliest time a passed proposal may be executed
+    #[serde(default)]
+    pub executable_at: Option<u64>,
+    /// Number of times the proposal was amended before voting began
+    #[serde(default)]
+    pub amendment_count: u32,
+    /// Whether the proposal was finalized before its deadline
+    #[serde(default)]
+    pub early_finalized: bool,
+    /// Proposals that must be executed before this one can be
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// Voting threshold in force when the proposal was created (basis points)
+    ///
+    /// Proposals from before this was recorded use the current config.
+    #[serde(default)]
+    pub threshold_bps: Option<u32>,
     /// Execution result (if executed)
     pub execution_result: Option<String>,
 }
 
-/// Proposal status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum ProposalStatus {
-    Active,
-    Passed,
-    Rejected,
-    Executed,
-    Cancelled,
-}
+impl Proposal {
+    /// Decode a structured custom proposal's payload
+    ///
+    /// Returns `None` for proposals without a payload.
+    pub fn payload_as<T: DeserializeOwned>(&self) -> Option<Result<T, SyndicateError>> {
+        match &self.proposal_type {
+            ProposalType::StructuredCustom { payload, .. } => Some(
+                T::deserialize(payload).map_err(|e| SyndicateError::InvalidPayload(e.to_string())),
+            ),
+            _ => None,
+        }
+    }
 
-/// Agent Syndicate
-pub struct Syndicate {
-    /// Configuration
-    config: SyndicateConfig,
-    /// Members
-    members: HashMap<String, SyndicateMember>,
-    /// Active proposals
-    proposals: HashMap<String, Proposal>,
-    /// Delegation chains
-    delegations: HashMap<String, DelegationChain>,
-    /// Proposal counter
-    proposal_counter: u64,
-}
+    /// Canonical bytes members sign to authorize an `ExecuteAction`
+    ///
+    /// Params are encoded in key order so every signer signs the same bytes.
+    /// Returns `None` for other proposal types.
+    pub fn action_signing_message(&self) -> Option<Vec<u8>> {
+        let ProposalType::ExecuteAction { action_type, params } = &self.proposal_type else {
+            return None;
+        };
+        let params: BTreeMap<_, _> = params.iter().collect();
+        serde_json::to_vec(&("syndicate-action", &self.id, action_type, params)).ok()
+    }
 
-impl Syndicate {
-    /// Create new syndicate
-    pub fn new(config: SyndicateConfig) -> Self {
-        Self {
-            config,
-            members: HashMap::new(),
-            proposals: HashMap::new(),
-            delegations: HashMap::new(),
-            proposal_counter: 0,
-        }
+    /// Record a vote and add it to the tally
+    fn record_vote(&mut self, record: VoteRecord) {
+        self.add_votes(record.choice, record.power);
+        self.voters.insert(record.voter.clone(), record.choice);
+        self.voter_power.insert(record.voter.clone(), record.power);
+        self.votes.push(record);
     }
 
-    /// Get syndicate ID
-    pub fn id(&self) -> &str {
-        &self.config.id
+    /// Move a recorded vote, and the power it carries, to another choice
+    ///
+    /// Members whose power the voter carries stay counted through them.
+    fn change_recorded_vote(&mut self, voter: &str, choice: VoteChoice, now: u64) -> Option<()> {
+        let record = self.votes.iter_mut().find(|r| r.voter == voter)?;
+        let (old_choice, power) = (record.choice, record.power);
+        record.choice = choice;
+        record.timestamp = now;
+        self.remove_votes(old_choice, power);
+        self.add_votes(choice, power);
+        self.voters.insert(voter.to_string(), choice);
+        Some(())
     }
 
-    /// Get config
-    pub fn config(&self) -> &SyndicateConfig {
-        &self.config
+    /// Remove a voter's vote from the records and the tally
+    ///
+    /// Members whose power the voter carried are free to vote again.
+    fn withdraw_vote(&mut self, voter: &str) -> Option<VoteRecord> {
+        let index = self.votes.iter().position(|r| r.voter == voter)?;
+        let record = self.votes.remove(index);
+        self.remove_votes(record.choice, record.power);
+        self.voters.remove(voter);
+        self.voter_power.remove(voter);
+        self.delegated_voters.retain(|_, delegatee| delegatee != voter);
+        Some(record)
     }
 
-    /// Add founding member
-    pub fn add_founder(&mut self, agent_id: String) -> Result<(), SyndicateError> {
-        if !self.members.is_empty() {
-            return Err(SyndicateError::FounderAlreadySet);
+    /// Rebuild `votes` for proposals stored before vote records existed
+    ///
+    /// Power falls back to the snapshot entry; timestamps are unknown and
+    /// set to the proposal's creation time.
+    fn backfill_vote_records(&mut self) {
+        if !self.votes.is_empty() {
+            return;
         }
+        let mut voters: Vec<_> = self.voters.iter().collect();
+        voters.sort_by_key(|(voter, _)| *voter);
+        self.votes = voters.into_iter()
+            .map(|(voter, choice)| VoteRecord {
+                voter: voter.clone(),
+                choice: *choice,
+                power: self.voter_power.get(voter)
+                    .or_else(|| self.power_snapshot.get(voter))
+                    .copied()
+                    .unwrap_or(0),
+                timestamp: self.created_at,
+            })
+            .collect();
+        for record in &self.votes {
+            self.voter_power.insert(record.voter.clone(), record.power);
+        }
+    }
 
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+    /// Add voting power to the tally for a choice
+    fn add_votes(&mut self, choice: VoteChoice, power: u64) {
+        match choice {
+            VoteChoice::For => self.votes_for += power,
+            VoteChoice::Against => self.votes_against += power,
+            VoteChoice::Abstain => self.votes_abstain += power,
+        }
+    }
 
-        let member = SyndicateMember {
-            agent_id: agent_id.clone(),
-            joined_at: now,
-            reputation: 1000, // Max reputation for founder
-            contribution_score: 0,
-            performance_proof: None,
-            role: MemberRole::Founder,
-            voting_power: 1000,
-            active: true,
+    /// Remove voting power from the tally for a choice
+    fn remove_votes(&mut self, choice: VoteChoice, power: u64) {
+        let tally = match choice {
+            VoteChoice::For => &mut self.votes_for,
+            VoteChoice::Against => &mut self.votes_against,
+            VoteChoice::Abstain => &mut self.votes_abstain,
         };
-
-        self.members.insert(agent_id, member);
-        Ok(())
+        *tally = tally.saturating_sub(power);
     }
+}
 
-    /// Request to join syndicate
-    pub fn request_membership(
-        &mut self,
-        agent_id: String,
-        reputation: u32,
-        proof: Option<&PerformanceProof>,
-    ) -> Result<String, SyndicateError> {
-        // Check if already member
-        if self.members.contains_key(&agent_id) {
-            return Err(SyndicateError::AlreadyMember(agent_id));
-        }
+/// A vote on a proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VoteChoice {
+    For,
+    Against,
+    /// Counts toward quorum but not toward the pass threshold
+    Abstain,
+}
 
-        // Check member limit
-        if self.members.len() >= self.config.max_members {
-            return Err(SyndicateError::MemberLimitReached);
-        }
+impl From<bool> for VoteChoice {
+    fn from(approve: bool) -> Self {
+        if approve { Self::For } else { Self::Against }
+    }
+}
 
-        // Check reputation
-        if reputation < self.config.min_reputation {
-            return Err(SyndicateError::InsufficientReputation {
-                required: self.config.min_reputation,
-                actual: reputation,
-            });
+impl VoteChoice {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::For => "for",
+            Self::Against => "against",
+            Self::Abstain => "abstain",
         }
+    }
+}
 
-        // Check performance proof if required
-        if self.config.requires_performance_proof && proof.is_none() {
-            return Err(SyndicateError::ProofRequired);
-        }
+/// A single vote on a proposal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteRecord {
+    /// Agent who cast the vote
+    pub voter: String,
+    /// Choice
+    pub choice: VoteChoice,
+    /// Voting power carried, including delegated power
+    pub power: u64,
+    /// When the vote was cast (or last changed)
+    pub timestamp: u64,
+}
 
-        // Create add member proposal
-        let proposal = self.create_proposal(
-            "system".into(),
-            ProposalType::AddMember { agent_id: agent_id.clone() },
-        )?;
+/// A vote signed with the voter's SDKey
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedVote {
+    /// Proposal being voted on
+    pub proposal_id: String,
+    /// Voter agent ID
+    pub voter: String,
+    /// Vote choice
+    pub choice: VoteChoice,
+    /// When the vote was signed
+    pub timestamp: u64,
+    /// Ed25519 signature over `signing_message()` (hex)
+    pub signature: String,
+}
 
-        Ok(proposal.id.clone())
+impl SignedVote {
+    /// Sign a vote with the voter's SDKey
+    pub fn sign(
+        sdkey: &AgentSDKey,
+        proposal_id: impl Into<String>,
+        voter: impl Into<String>,
+        choice: VoteChoice,
+        timestamp: u64,
+    ) -> Result<Self, SDKeyError> {
+        let mut vote = Self {
+            proposal_id: proposal_id.into(),
+            voter: voter.into(),
+            choice,
+            timestamp,
+            signature: String::new(),
+        };
+        vote.signature = sdkey.sign_hex(&vote.signing_message())?;
+        Ok(vote)
     }
 
-    /// Add member (after approval)
-    pub fn add_member(&mut self, agent_id: String, reputation: u32) -> Result<(), SyndicateError> {
-        if self.members.contains_key(&agent_id) {
-            return Err(SyndicateError::AlreadyMember(agent_id));
+    /// Bytes covered by the signature
+    ///
+    /// Each field is length-prefixed, so no two votes share a message.
+    pub fn signing_message(&self) -> Vec<u8> {
+        let mut message = VOTE_TAG.to_vec();
+        for field in [&self.proposal_id, &self.voter, self.choice.as_str()] {
+            message.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            message.extend_from_slice(field.as_bytes());
         }
+        message.extend_from_slice(&self.timestamp.to_le_bytes());
+        message
+    }
 
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+    /// Check the signature against a public key
+    pub fn verify(&self, public_key: &[u8; 32]) -> Result<(), SDKeyError> {
+        let signature: [u8; 64] = hex::decode(&self.signature)
+            .map_err(|_| SDKeyError::InvalidHex)?
+            .try_into()
+            .map_err(|_| SDKeyError::InvalidSignature)?;
+        sdkey_manager::verify_signature(public_key, &self.signing_message(), &signature)
+    }
+}
 
-        let member = SyndicateMember {
-            agent_id: agent_id.clone(),
-            joined_at: now,
-            reputation,
-            contribution_score: 0,
-            performance_proof: None,
-            role: MemberRole::Member,
-            voting_power: reputation,
-            active: true,
+/// A contribution reported and signed by the contributing agent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContributionReport {
+    /// Contributing agent ID
+    pub agent_id: String,
+    /// Amount contributed
+    pub amount: u64,
+    /// Per-agent value that makes the report single-use
+    pub nonce: u64,
+    /// When the report was signed
+    pub timestamp: u64,
+    /// Ed25519 signature over `signing_message()` (hex)
+    pub signature: String,
+}
+
+impl ContributionReport {
+    /// Sign a report with the contributor's SDKey
+    pub fn sign(
+        sdkey: &AgentSDKey,
+        amount: u64,
+        nonce: u64,
+        timestamp: u64,
+    ) -> Result<Self, SDKeyError> {
+        let mut report = Self {
+            agent_id: sdkey.agent_id(),
+            amount,
+            nonce,
+            timestamp,
+            signature: String::new(),
         };
+        report.signature = sdkey.sign_hex(&report.signing_message())?;
+        Ok(report)
+    }
 
-        self.members.insert(agent_id, member);
-        Ok(())
+    /// Bytes covered by the signature
+    pub fn signing_message(&self) -> Vec<u8> {
+        format!(
+            "syndicate-contribution:{}:{}:{}:{}",
+            self.agent_id,
+            self.amount,
+            self.nonce,
+            self.timestamp
+        )
+        .into_bytes()
     }
 
-    /// Create a proposal
-    pub fn create_proposal(
-        &mut self,
-        proposer: String,
-        proposal_type: ProposalType,
-    ) -> Result<&Proposal, SyndicateError> {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+    /// Check the signature against a public key
+    pub fn verify(&self, public_key: &[u8; 32]) -> Result<(), SDKeyError> {
+        let signature: [u8; 64] = hex::decode(&self.signature)
+            .map_err(|_| SDKeyError::InvalidHex)?
+            .try_into()
+            .map_err(|_| SDKeyError::InvalidSignature)?;
+        sdkey_manager::verify_signature(public_key, &self.signing_message(), &signature)
+    }
+}
 
-        self.proposal_counter += 1;
-        let proposal_id = format!("{}-{}", self.config.id, self.proposal_counter);
+/// A member's authorization of a passed `ExecuteAction`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionSignature {
+    /// Signing member
+    pub signer: String,
+    /// Signer's Ed25519 public key (hex)
+    pub public_key: String,
+    /// Signature over `Proposal::action_signing_message()` (hex)
+    pub signature: String,
+    /// When the co-sign was recorded
+    pub signed_at: u64,
+}
 
-        let proposal = Proposal {
-            id: proposal_id.clone(),
-            proposal_type,
-            proposer,
-            created_at: now,
-            deadline: now + self.config.proposal_duration,
-            votes_for: 0,
-            votes_against: 0,
-            voters: HashMap::new(),
-            status: ProposalStatus::Active,
-            execution_result: None,
-        };
+/// Accept voter maps written before abstain existed (agent_id -> bool)
+fn deserialize_voters<'de, D>(deserializer: D) -> Result<HashMap<String, VoteChoice>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StoredVote {
+        Choice(VoteChoice),
+        Legacy(bool),
+    }
 
-        self.proposals.insert(proposal_id.clone(), proposal);
-        Ok(self.proposals.get(&proposal_id).unwrap())
+    let stored = HashMap::<String, StoredVote>::deserialize(deserializer)?;
+    Ok(stored
+        .into_iter()
+        .map(|(voter, vote)| {
+            let choice = match vote {
+                StoredVote::Choice(choice) => choice,
+                StoredVote::Legacy(approve) => approve.into(),
+            };
+            (voter, choice)
+        })
+        .collect())
+}
+
+/// Order of `list_proposals` results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProposalSort {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+}
+
+/// Criteria for `list_proposals`
+///
+/// Unset fields match everything. `created_after` is inclusive and
+/// `created_before` exclusive; `limit: None` returns all remaining items.
+#[derive(Debug, Clone, Default)]
+pub struct ProposalFilter {
+    pub status: Option<ProposalStatus>,
+    pub proposer: Option<String>,
+    pub created_after: Option<u64>,
+    pub created_before: Option<u64>,
+    pub kind: Option<ProposalKind>,
+    pub sort: ProposalSort,
+    pub offset: usize,
+    pub limit: Option<usize>,
+}
+
+impl ProposalFilter {
+    fn matches(&self, proposal: &Proposal) -> bool {
+        self.status.map_or(true, |s| proposal.status == s)
+            && self.proposer.as_ref().map_or(true, |p| &proposal.proposer == p)
+            && self.created_after.map_or(true, |t| proposal.created_at >= t)
+            && self.created_before.map_or(true, |t| proposal.created_at < t)
+            && self.kind.map_or(true, |k| proposal.proposal_type.kind() == k)
     }
+}
 
-    /// Vote on a proposal
-    pub fn vote(
-        &mut self,
-        proposal_id: &str,
-        voter: &str,
-        approve: bool,
-    ) -> Result<(), SyndicateError> {
-        // Get voter info
-        let member = self.members.get(voter)
-            .ok_or_else(|| SyndicateError::NotMember(voter.to_string()))?;
+/// One page of `list_proposals` results
+#[derive(Debug, Clone)]
+pub struct ProposalPage<'a> {
+    /// Proposals on this page
+    pub items: Vec<&'a Proposal>,
+    /// Number of proposals matching the filter across all pages
+    pub total: usize,
+    /// Offset of the next page, if there is one
+    pub next_offset: Option<usize>,
+}
 
-        if !member.active {
-            return Err(SyndicateError::MemberInactive);
-        }
+/// Proposal status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    Executed,
+    /// Passed, but applying its effect failed
+    ExecutionFailed,
+    Cancelled,
+    /// Blocked by a member with veto power
+    Vetoed,
+}
 
-        let voting_power = member.voting_power as u64;
+/// Record of a veto against a proposal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VetoRecord {
+    /// Member who vetoed
+    pub vetoed_by: String,
+    /// Stated reason
+    pub reason: String,
+    /// When the veto was applied
+    pub vetoed_at: u64,
+}
 
-        // Get proposal
-        let proposal = self.proposals.get_mut(proposal_id)
-            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+/// Syndicate-level performance built from member proofs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyndicatePerformanceSummary {
+    /// Syndicate ID
+    pub syndicate_id: String,
+    /// Active members at the time of aggregation
+    pub active_members: usize,
+    /// Active members with a valid proof
+    pub proven_members: usize,
+    /// Share of active members with a valid proof (basis points)
+    pub coverage_bps: u32,
+    /// Number of members proving each PnL threshold (bps)
+    pub pnl_thresholds: BTreeMap<i64, usize>,
+    /// Combined score (0-1000)
+    pub combined_score: u32,
+    /// Proofs discarded as invalid, expired, duplicate, or from non-members
+    pub discarded_proofs: usize,
+    /// When the summary was computed
+    pub computed_at: u64,
+}
 
-        // Check if voting is still open
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+/// How to resolve a member present in both merged syndicates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DuplicateMemberResolution {
+    /// Use the record with the higher reputation
+    #[default]
+    KeepHigherReputation,
+    /// Use the surviving syndicate's record
+    KeepExisting,
+}
 
-        if now > proposal.deadline {
-            return Err(SyndicateError::VotingClosed);
-        }
+/// Which configuration the merged syndicate uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConfigResolution {
+    /// Keep the surviving syndicate's config
+    #[default]
+    KeepSurviving,
+    /// Adopt the absorbed syndicate's config (the surviving ID is kept)
+    TakeAbsorbed,
+}
 
-        // Check if already voted
-        if proposal.voters.contains_key(voter) {
-            return Err(SyndicateError::AlreadyVoted);
-        }
+/// Options for `Syndicate::merge_from`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergePolicy {
+    /// Duplicate member handling
+    pub duplicate_members: DuplicateMemberResolution,
+    /// Config handling
+    pub config: ConfigResolution,
+    /// Import the absorbed syndicate's executed proposals into the audit log
+    pub import_history: bool,
+}
 
-        // Record vote
-        proposal.voters.insert(voter.to_string(), approve);
-        if approve {
-            proposal.votes_for += voting_power;
-        } else {
-            proposal.votes_against += voting_power;
+impl Default for MergePolicy {
+    fn default() -> Self {
+        Self {
+            duplicate_members: DuplicateMemberResolution::default(),
+            config: ConfigResolution::default(),
+            import_history: true,
         }
+    }
+}
+
+/// Outcome of a merge
+#[derive(Debug, Clone)]
+pub struct MergeReport {
+    /// ID of the absorbed syndicate
+    pub absorbed_id: String,
+    /// Members new to the surviving syndicate
+    pub members_added: Vec<String>,
+    /// Members present in both syndicates
+    pub members_merged: Vec<String>,
+    /// Absorbed proposals that were still active or passed but not yet
+    /// executed, now cancelled
+    pub cancelled_proposals: Vec<Proposal>,
+    /// Executed proposals imported into the audit log
+    pub imported_proposals: usize,
+    /// IDs of absorbed proposals that were already closed and weren't
+    /// imported (rejected, vetoed, cancelled, failed, or executed without
+    /// `import_history`)
+    pub discarded_proposals: Vec<String>,
+    /// Vote delegations carried over
+    pub vote_delegations_added: Vec<VoteDelegation>,
+    /// Vote delegations not carried over: expired, from a member already
+    /// delegating here, or that would form a cycle
+    pub vote_delegations_dropped: Vec<VoteDelegation>,
+    /// Membership requests dropped with their cancelled AddMember
+    /// proposals
+    pub dropped_membership_requests: Vec<PendingMembership>,
+}
+
+/// Syndicate treasury ledger
+///
+/// Funds enter the shared pool through deposits and contribution fees,
+/// move from the pool to member balances through profit distributions,
+/// and leave through member withdrawals. At all times
+/// `pool + sum(balances) == total_inflow - total_withdrawn`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Treasury {
+    /// Funds held by the syndicate
+    pool: u64,
+    /// Claimable balances per member
+    balances: HashMap<String, u64>,
+    /// Total deposited per agent
+    deposits: HashMap<String, u64>,
+    /// Total fees accrued from contributions
+    fees_accrued: u64,
+    /// Total funds that entered the treasury
+    total_inflow: u64,
+    /// Total funds withdrawn by members
+    total_withdrawn: u64,
+}
+
+impl Treasury {
+    /// Create empty treasury
+    pub fn new() -> Self {
+        Self::default()
+    }
 
+    /// Deposit funds into the pool on behalf of an agent
+    ///
+    /// Nothing changes if a total would overflow.
+    pub fn deposit(&mut self, agent_id: &str, amount: u64) -> Result<(), SyndicateError> {
+        let overflow = || SyndicateError::TreasuryOverflow(amount);
+        let pool = self.pool.checked_add(amount).ok_or_else(overflow)?;
+        let total_inflow = self.total_inflow.checked_add(amount).ok_or_else(overflow)?;
+        let deposited = self.deposited_by(agent_id).checked_add(amount).ok_or_else(overflow)?;
+        self.pool = pool;
+        self.total_inflow = total_inflow;
+        self.deposits.insert(agent_id.to_string(), deposited);
         Ok(())
     }
 
-    /// Finalize a proposal
-    pub fn finalize_proposal(&mut self, proposal_id: &str) -> Result<ProposalStatus, SyndicateError> {
-        let proposal = self.proposals.get_mut(proposal_id)
-            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+    /// Accrue a fee into the pool
+    ///
+    /// Nothing changes if a total would overflow.
+    pub fn accrue_fee(&mut self, fee: u64) -> Result<(), SyndicateError> {
+        let overflow = || SyndicateError::TreasuryOverflow(fee);
+        let pool = self.pool.checked_add(fee).ok_or_else(overflow)?;
+        let fees_accrued = self.fees_accrued.checked_add(fee).ok_or_else(overflow)?;
+        let total_inflow = self.total_inflow.checked_add(fee).ok_or_else(overflow)?;
+        self.pool = pool;
+        self.fees_accrued = fees_accrued;
+        self.total_inflow = total_inflow;
+        Ok(())
+    }
 
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+    /// Fold another treasury's funds and history into this one
+    pub fn absorb(&mut self, other: Treasury) {
+        self.pool += other.pool;
+        for (agent_id, amount) in other.balances {
+            *self.balances.entry(agent_id).or_default() += amount;
+        }
+        for (agent_id, amount) in other.deposits {
+            *self.deposits.entry(agent_id).or_default() += amount;
+        }
+        self.fees_accrued += other.fees_accrued;
+        self.total_inflow += other.total_inflow;
+        self.total_withdrawn += other.total_withdrawn;
+    }
 
-        if now <= proposal.deadline && proposal.status == ProposalStatus::Active {
-            return Err(SyndicateError::VotingStillOpen);
+    /// Move funds from the pool to member balances
+    pub fn distribute(&mut self, shares: &[(String, u64)]) -> Result<(), SyndicateError> {
+        let total: u64 = shares.iter().map(|(_, amount)| amount).sum();
+        if total > self.pool {
+            return Err(SyndicateError::InsufficientTreasuryFunds {
+                available: self.pool,
+                requested: total,
+            });
         }
 
-        let total_votes = proposal.votes_for + proposal.votes_against;
-        let total_voting_power: u64 = self.members.values()
-            .filter(|m| m.active)
-            .map(|m| m.voting_power as u64)
-            .sum();
+        self.pool -= total;
+        for (agent_id, amount) in shares {
+            *self.balances.entry(agent_id.clone()).or_default() += amount;
+        }
+        Ok(())
+    }
 
-        // Check quorum (at least 20% participation)
-        let quorum_threshold = total_voting_power / 5;
-        if total_votes < quorum_threshold {
-            proposal.status = ProposalStatus::Rejected;
-            return Ok(ProposalStatus::Rejected);
+    /// Withdraw from a member's claimable balance
+    pub fn withdraw(&mut self, agent_id: &str, amount: u64) -> Result<u64, SyndicateError> {
+        let available = self.balance(agent_id);
+        if amount > available {
+            return Err(SyndicateError::InsufficientBalance {
+                available,
+                requested: amount,
+            });
         }
 
-        // Check if passed
-        let threshold = (total_votes * self.config.voting_threshold_bps as u64) / 10000;
-        if proposal.votes_for >= threshold {
-            proposal.status = ProposalStatus::Passed;
-            Ok(ProposalStatus::Passed)
+        let remaining = available - amount;
+        if remaining == 0 {
+            self.balances.remove(agent_id);
         } else {
-            proposal.status = ProposalStatus::Rejected;
-            Ok(ProposalStatus::Rejected)
+            self.balances.insert(agent_id.to_string(), remaining);
         }
+        self.total_withdrawn += amount;
+        Ok(remaining)
     }
 
-    /// Get member count
-    pub fn member_count(&self) -> usize {
-        self.members.len()
+    /// Funds held by the syndicate
+    pub fn pool(&self) -> u64 {
+        self.pool
     }
 
-    /// Get active members
-    pub fn active_members(&self) -> Vec<&SyndicateMember> {
-        self.members.values().filter(|m| m.active).collect()
+    /// Claimable balance of a member
+    pub fn balance(&self, agent_id: &str) -> u64 {
+        self.balances.get(agent_id).copied().unwrap_or(0)
     }
 
-    /// Get member by ID
-    pub fn get_member(&self, agent_id: &str) -> Option<&SyndicateMember> {
-        self.members.get(agent_id)
+    /// Total deposited by an agent
+    pub fn deposited_by(&self, agent_id: &str) -> u64 {
+        self.deposits.get(agent_id).copied().unwrap_or(0)
     }
 
-    /// Get proposal by ID
-    pub fn get_proposal(&self, proposal_id: &str) -> Option<&Proposal> {
-        self.proposals.get(proposal_id)
+    /// Total fees accrued
+    pub fn fees_accrued(&self) -> u64 {
+        self.fees_accrued
     }
 
-    /// List active proposals
-    pub fn active_proposals(&self) -> Vec<&Proposal> {
-        self.proposals.values()
-            .filter(|p| p.status == ProposalStatus::Active)
-            .collect()
+    /// Pool plus all claimable balances
+    pub fn total_holdings(&self) -> u64 {
+        self.pool + self.balances.values().sum::<u64>()
     }
 
-    /// Update member reputation
-    pub fn update_reputation(
-        &mut self,
-        agent_id: &str,
-        delta: i32,
-    ) -> Result<u32, SyndicateError> {
-        let member = self.members.get_mut(agent_id)
-            .ok_or_else(|| SyndicateError::NotMember(agent_id.to_string()))?;
-
-        let new_rep = (member.reputation as i32 + delta).clamp(0, 1000) as u32;
-        member.reputation = new_rep;
-        member.voting_power = new_rep;
+    /// Total funds that entered the treasury
+    pub fn total_inflow(&self) -> u64 {
+        self.total_inflow
+    }
 
-        Ok(new_rep)
+    /// Total funds withdrawn by members
+    pub fn total_withdrawn(&self) -> u64 {
+        self.total_withdrawn
     }
+}
 
-    /// Record contribution
-    pub fn record_contribution(
-        &mut self,
-        agent_id: &str,
+/// Action recorded in the syndicate audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SyndicateEvent {
+    /// Founder created the syndicate
+    FounderAdded { agent_id: String },
+    /// Agent invited to request membership
+    MemberInvited { agent_id: String },
+    /// Agent asked to join
+    MembershipRequested { agent_id: String, proposal_id: String },
+    /// Member admitted
+    MemberAdded { agent_id: String, reputation: u32 },
+    /// Member removed
+    MemberRemoved { agent_id: String, reason: String },
+    /// Member left voluntarily
+    MemberLeft { agent_id: String },
+    /// Member deactivated for inactivity
+    MemberDeactivated { agent_id: String },
+    /// Deactivated member reactivated
+    MemberReactivated { agent_id: String },
+    /// Member replaced their performance proof
+    MemberProofUpdated { proof_id: String },
+    /// Agent barred from joining
+    MemberBanned { agent_id: String, until: Option<u64> },
+    /// Ban lifted
+    MemberUnbanned { agent_id: String },
+    /// Member role changed
+    RoleChanged { agent_id: String, from: MemberRole, to: MemberRole },
+    /// Proposal opened
+    ProposalCreated { proposal_id: String },
+    /// Vote cast (power includes delegated power)
+    VoteCast { proposal_id: String, choice: VoteChoice, power: u64 },
+    /// Existing vote changed
+    VoteChanged { proposal_id: String, from: VoteChoice, to: VoteChoice },
+    /// Voting power delegated
+    VoteDelegated { delegatee: String, expires_at: u64 },
+    /// Vote delegation withdrawn
+    VoteDelegationRevoked { delegatee: String },
+    /// Proposal amended before voting
+    ProposalAmended { proposal_id: String },
+    /// Proposal cancelled
+    ProposalCancelled { proposal_id: String },
+    /// Proposal vetoed
+    ProposalVetoed { proposal_id: String, reason: String },
+    /// Voting closed with an outcome
+    ProposalFinalized { proposal_id: String, status: ProposalStatus },
+    /// Passed proposal executed (or failed to)
+    ProposalExecuted { proposal_id: String, status: ProposalStatus },
+    /// Config field updated
+    ConfigChanged { change: ConfigChange },
+    /// Member reputation changed
+    ReputationUpdated { agent_id: String, old: u32, new: u32 },
+    /// Contribution recorded for a member
+    ContributionRecorded {
+        agent_id: String,
         amount: u64,
-    ) -> Result<(), SyndicateError> {
-        let member = self.members.get_mut(agent_id)
-            .ok_or_else(|| SyndicateError::NotMember(agent_id.to_string()))?;
+        #[serde(default)]
+        fee: u64,
+        /// Report nonce when self-reported and signed; `None` for trusted callers
+        #[serde(default)]
+        nonce: Option<u64>,
+    },
+    /// Member co-signed a passed action
+    ActionCoSigned { proposal_id: String },
+    /// Funds deposited into the treasury
+    Deposited { amount: u64 },
+    /// Funds withdrawn from a member balance
+    Withdrawn { amount: u64 },
+    /// SDKey delegation chain attached
+    DelegationChainSet { agent_id: String },
+    /// Another syndicate was merged into this one
+    SyndicateMerged { absorbed_id: String },
+    /// Executed proposal carried over from a merged syndicate
+    ProposalImported { source_syndicate: String, proposal_id: String, result: Option<String> },
+}
 
-        member.contribution_score += amount;
-        Ok(())
+impl SyndicateEvent {
+    /// Agent the event is about, if any (besides the actor)
+    pub fn subject(&self) -> Option<&str> {
+        match self {
+            Self::FounderAdded { agent_id }
+            | Self::MemberInvited { agent_id }
+            | Self::MembershipRequested { agent_id, .. }
+            | Self::MemberAdded { agent_id, .. }
+            | Self::MemberRemoved { agent_id, .. }
+            | Self::MemberLeft { agent_id }
+            | Self::MemberDeactivated { agent_id }
+            | Self::MemberReactivated { agent_id }
+            | Self::RoleChanged { agent_id, .. }
+            | Self::MemberBanned { agent_id, .. }
+            | Self::MemberUnbanned { agent_id }
+            | Self::ReputationUpdated { agent_id, .. }
+            | Self::ContributionRecorded { agent_id, .. }
+            | Self::DelegationChainSet { agent_id } => Some(agent_id),
+            Self::VoteDelegated { delegatee, .. } | Self::VoteDelegationRevoked { delegatee } => Some(delegatee),
+            _ => None,
+        }
     }
+}
 
-    /// Set delegation chain for agent
-    pub fn set_delegation(&mut self, agent_id: String, chain: DelegationChain) {
-        self.delegations.insert(agent_id, chain);
-    }
+/// Audit log entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyndicateEventRecord {
+    /// What happened
+    pub event: SyndicateEvent,
+    /// Who did it ("system" for automatic actions)
+    pub actor: String,
+    /// When it happened
+    pub timestamp: u64,
+}
 
-    /// Get delegation chain
-    pub fn get_delegation(&self, agent_id: &str) -> Option<&DelegationChain> {
-        self.delegations.get(agent_id)
+impl SyndicateEventRecord {
+    /// Whether the agent acted in or is the subject of this entry
+    pub fn involves(&self, agent_id: &str) -> bool {
+        self.actor == agent_id || self.event.subject() == Some(agent_id)
     }
 }
 
-/// Syndicate errors
-#[derive(Debug, Clone, thiserror::Error)]
-pub enum SyndicateError {
-    #[error("Agent is already a member: {0}")]
-    AlreadyMember(String),
+/// Agent Syndicate
+pub struct Syndicate {
+    /// Configuration
+    config: SyndicateConfig,
+    /// Members
+    members: HashMap<String, SyndicateMember>,
+    /// Active proposals
+    proposals: HashMap<String, Proposal>,
+    /// Delegation chains
+    delegations: HashMap<String, DelegationChain>,
+    /// Vote delegations by delegator
+    vote_delegations: HashMap<String, VoteDelegation>,
+    /// Proposal counter
+    proposal_counter: u64,
+    /// History of removed members
+    removed_members: Vec<RemovedMember>,
+    /// Treasury ledger
+    treasury: Treasury,
+    /// Membership requests by AddMember proposal ID
+    pending_memberships: HashMap<String, PendingMembership>,
+    /// Audit log, oldest first
+    events: VecDeque<SyndicateEventRecord>,
+    /// Outstanding invitations by invited agent ID
+    invitations: HashMap<String, Invitation>,
+    /// Hashes of signed invites already used
+    consumed_invites: HashSet<String>,
+    /// Agents barred from joining, by agent ID
+    banned: HashMap<String, BanRecord>,
+    /// Contribution report nonces still inside the accepted window, with
+    /// their report timestamps, by agent ID
+    contribution_nonces: HashMap<String, HashMap<u64, u64>>,
+    /// Configs by the time they took effect, oldest first
+    config_history: Vec<(u64, SyndicateConfig)>,
+    /// Config versions dropped from the front of `config_history`
+    compacted_config_versions: u64,
+    /// Time source
+    clock: Box<dyn Clock>,
+    /// Checks performance proofs submitted with membership requests
+    verifier: ProofVerifier,
+}
 
-    #[error("Agent is not a member: {0}")]
-    NotMember(String),
+/// Serializable copy of a syndicate's full state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyndicateSnapshot {
+    /// Configuration
+    pub config: SyndicateConfig,
+    /// Members
+    pub members: HashMap<String, SyndicateMember>,
+    /// Proposals
+    pub proposals: HashMap<String, Proposal>,
+    /// Delegation chains
+    #[serde(default)]
+    pub delegations: HashMap<String, DelegationChain>,
+    /// Vote delegations by delegator
+    #[serde(default)]
+    pub vote_delegations: HashMap<String, VoteDelegation>,
+    /// Proposal counter
+    pub proposal_counter: u64,
+    /// History of removed members
+    #[serde(default)]
+    pub removed_members: Vec<RemovedMember>,
+    /// Treasury ledger
+    #[serde(default)]
+    pub treasury: Treasury,
+    /// Membership requests by AddMember proposal ID
+    #[serde(default)]
+    pub pending_memberships: HashMap<String, PendingMembership>,
+    /// Audit log, oldest first
+    #[serde(default)]
+    pub events: VecDeque<SyndicateEventRecord>,
+    /// Outstanding invitations by invited agent ID
+    #[serde(default)]
+    pub invitations: HashMap<String, Invitation>,
+    /// Hashes of signed invites already used
+    #[serde(default)]
+    pub consumed_invites: HashSet<String>,
+    /// Agents barred from joining, by agent ID
+    #[serde(default)]
+    pub banned: HashMap<String, BanRecord>,
+    /// Contribution report nonces still inside the accepted window, with
+    /// their report timestamps, by agent ID
+    #[serde(default)]
+    pub contribution_nonces: HashMap<String, HashMap<u64, u64>>,
+    /// Configs by the time they took effect, oldest first
+    #[serde(default)]
+    pub config_history: Vec<(u64, SyndicateConfig)>,
+    /// Config versions dropped from the front of `config_history`
+    #[serde(default)]
+    pub compacted_config_versions: u64,
+}
 
-    #[error("Member is inactive")]
-    MemberInactive,
+impl Syndicate {
+    /// Create new syndicate
+    pub fn new(config: SyndicateConfig) -> Result<Self, SyndicateError> {
+        config.validate()?;
 
-    #[error("Member limit reached")]
-    MemberLimitReached,
+        Ok(Self {
+            // The initial config applies from the start of time
+            config_history: vec![(0, config.clone())],
+            compacted_config_versions: 0,
+            config,
+            members: HashMap::new(),
+            proposals: HashMap::new(),
+            delegations: HashMap::new(),
+            vote_delegations: HashMap::new(),
+            proposal_counter: 0,
+            removed_members: Vec::new(),
+            treasury: Treasury::new(),
+            pending_memberships: HashMap::new(),
+            events: VecDeque::new(),
+            invitations: HashMap::new(),
+            consumed_invites: HashSet::new(),
+            banned: HashMap::new(),
+            contribution_nonces: HashMap::new(),
+            clock: Box::new(SystemClock),
+            verifier: ProofVerifier::default(),
+        })
+    }
 
-    #[error("Insufficient reputation: required {required}, actual {actual}")]
-    InsufficientReputation { required: u32, actual: u32 },
+    /// Use a different time source (e.g. `MockClock` in tests)
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
 
-    #[error("Performance proof required")]
-    ProofRequired,
+    /// Use a different proof verifier for membership requests
+    pub fn with_verifier(mut self, verifier: ProofVerifier) -> Self {
+        self.verifier = verifier;
+        self
+    }
 
-    #[error("Proposal not found: {0}")]
-    ProposalNotFound(String),
+    /// Get syndicate ID
+    pub fn id(&self) -> &str {
+        &self.config.id
+    }
 
-    #[error("Voting is closed")]
-    VotingClosed,
+    /// Get config
+    pub fn config(&self) -> &SyndicateConfig {
+        &self.config
+    }
 
-    #[error("Voting is still open")]
-    VotingStillOpen,
+    /// Config that was in force at a given time
+    ///
+    /// `None` if that version has since been compacted away.
+    pub fn config_at(&self, timestamp: u64) -> Option<&SyndicateConfig> {
+        let later = self.config_history.partition_point(|(changed_at, _)| *changed_at <= timestamp);
+        if later == 0 {
+            return None;
+        }
+        Some(&self.config_history[later - 1].1)
+    }
 
-    #[error("Already voted on this proposal")]
-    AlreadyVoted,
+    /// Retained configs by the time they took effect, oldest first
+    pub fn config_versions(&self) -> &[(u64, SyndicateConfig)] {
+        &self.config_history
+    }
 
-    #[error("Founder already set")]
-    FounderAlreadySet,
+    /// Number of old config versions no longer retained
+    pub fn compacted_config_versions(&self) -> u64 {
+        self.compacted_config_versions
+    }
 
-    #[error("Permission denied")]
-    PermissionDenied,
-}
+    /// Record the current config as a new version
+    fn push_config_version(&mut self) {
+        self.config_history.push((self.clock.now(), self.config.clone()));
+        if self.config_history.len() > self.config.max_config_versions {
+            let excess = self.config_history.len() - self.config.max_config_versions;
+            self.config_history.drain(..excess);
+            self.compacted_config_versions += excess as u64;
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Capture the syndicate's full state
+    pub fn snapshot(&self) -> SyndicateSnapshot {
+        SyndicateSnapshot {
+            config: self.config.clone(),
+            members: self.members.clone(),
+            proposals: self.proposals.clone(),
+            delegations: self.delegations.clone(),
+            vote_delegations: self.vote_delegations.clone(),
+            proposal_counter: self.proposal_counter,
+            removed_members: self.removed_members.clone(),
+            treasury: self.treasury.clone(),
+            pending_memberships: self.pending_memberships.clone(),
+            events: self.events.clone(),
+            invitations: self.invitations.clone(),
+            consumed_invites: self.consumed_invites.clone(),
+            banned: self.banned.clone(),
+            contribution_nonces: self.contribution_nonces.clone(),
+            config_history: self.config_history.clone(),
+            compacted_config_versions: self.compacted_config_versions,
+        }
+    }
 
-    #[test]
-    fn test_syndicate_creation() {
-        let config = SyndicateConfig {
-            id: "syndicate-001".into(),
-            name: "Test Syndicate".into(),
-            ..Default::default()
-        };
+    /// Rebuild a syndicate from a snapshot
+    ///
+    /// Fails if the snapshot is internally inconsistent, e.g. a proposal
+    /// references a voter who is neither a member nor a removed member.
+    pub fn restore(snapshot: SyndicateSnapshot) -> Result<Self, SyndicateError> {
+        snapshot.config.validate()?;
 
-        let mut syndicate = Syndicate::new(config);
-        syndicate.add_founder("founder-agent".into()).unwrap();
+        let inconsistent = |msg: String| Err(SyndicateError::InconsistentSnapshot(msg));
 
-        assert_eq!(syndicate.member_count(), 1);
-        
-        let founder = syndicate.get_member("founder-agent").unwrap();
-        assert_eq!(founder.role, MemberRole::Founder);
-    }
+        for (key, member) in &snapshot.members {
+            if key != &member.agent_id {
+                return inconsistent(format!("member key {} holds agent {}", key, member.agent_id));
+            }
+        }
 
-    #[test]
-    fn test_voting() {
-        let config = SyndicateConfig {
-            id: "syndicate-001".into(),
-            proposal_duration: 1, // 1 second for testing
-            ..Default::default()
+        let known_agent = |agent_id: &str| {
+            snapshot.members.contains_key(agent_id)
+                || snapshot.removed_members.iter().any(|r| r.agent_id == agent_id)
         };
 
-        let mut syndicate = Syndicate::new(config);
-        syndicate.add_founder("founder".into()).unwrap();
-        syndicate.add_member("member1".into(), 500).unwrap();
-        syndicate.add_member("member2".into(), 500).unwrap();
+        let mut proposals = snapshot.proposals;
+        for (key, proposal) in proposals.iter_mut() {
+            if key != &proposal.id {
+                return inconsistent(format!("proposal key {} holds proposal {}", key, proposal.id));
+            }
+            // Proposals from before stored sequence numbers carry it in the ID
+            if proposal.sequence == 0 {
+                proposal.sequence = legacy_proposal_seq(&proposal.id);
+            }
+            if proposal.sequence > snapshot.proposal_counter {
+                return inconsistent(format!(
+                    "proposal {} is ahead of counter {}",
+                    proposal.id, snapshot.proposal_counter
+                ));
+            }
+            let referenced = proposal.voters.keys()
+                .chain(proposal.votes.iter().map(|r| &r.voter))
+                .chain(proposal.delegated_voters.keys())
+                .chain(proposal.delegated_voters.values());
+            for agent_id in referenced {
+                if !known_agent(agent_id) {
+                    return inconsistent(format!(
+                        "proposal {} references unknown voter {}",
+                        proposal.id, agent_id
+                    ));
+                }
+            }
+        }
 
-        // Create proposal
-        let proposal = syndicate.create_proposal(
-            "founder".into(),
-            ProposalType::Custom {
-                title: "Test".into(),
-                description: "Test proposal".into(),
-            },
-        ).unwrap();
-        let proposal_id = proposal.id.clone();
+        for proposal_id in snapshot.pending_memberships.keys() {
+            if !proposals.contains_key(proposal_id) {
+                return inconsistent(format!("pending membership for unknown proposal {}", proposal_id));
+            }
+        }
 
-        // Vote
-        syndicate.vote(&proposal_id, "founder", true).unwrap();
-        syndicate.vote(&proposal_id, "member1", true).unwrap();
-        syndicate.vote(&proposal_id, "member2", false).unwrap();
+        for (key, delegation) in &snapshot.vote_delegations {
+            if key != &delegation.delegator
+                || !snapshot.members.contains_key(&delegation.delegator)
+                || !snapshot.members.contains_key(&delegation.delegatee)
+            {
+                return inconsistent(format!("invalid vote delegation from {}", key));
+            }
+        }
 
-        // Wait for deadline
-        std::thread::sleep(std::time::Duration::from_secs(2));
+        for proposal in proposals.values_mut() {
+            proposal.backfill_vote_records();
+        }
+
+        let mut events = snapshot.events;
+        while events.len() > snapshot.config.max_events {
+            events.pop_front();
+        }
+
+        // Snapshots from before config history start it with the current config
+        let mut config_history = snapshot.config_history;
+        if config_history.is_empty() {
+            config_history.push((0, snapshot.config.clone()));
+        }
+        let mut compacted_config_versions = snapshot.compacted_config_versions;
+        if config_history.len() > snapshot.config.max_config_versions {
+            let excess = config_history.len() - snapshot.config.max_config_versions;
+            config_history.drain(..excess);
+            compacted_config_versions += excess as u64;
+        }
+
+        Ok(Self {
+            config: snapshot.config,
+            members: snapshot.members,
+            proposals,
+            delegations: snapshot.delegations,
+            vote_delegations: snapshot.vote_delegations,
+            proposal_counter: snapshot.proposal_counter,
+            removed_members: snapshot.removed_members,
+            treasury: snapshot.treasury,
+            pending_memberships: snapshot.pending_memberships,
+            events,
+            invitations: snapshot.invitations,
+            consumed_invites: snapshot.consumed_invites,
+            banned: snapshot.banned,
+            contribution_nonces: snapshot.contribution_nonces,
+            config_history,
+            compacted_config_versions,
+            clock: Box::new(SystemClock),
+            verifier: ProofVerifier::default(),
+        })
+    }
+
+    /// Add founding member
+    pub fn add_founder(&mut self, agent_id: String) -> Result<(), SyndicateError> {
+        if !self.members.is_empty() {
+            return Err(SyndicateError::FounderAlreadySet);
+        }
+
+        let now = self.clock.now();
+
+        let member = SyndicateMember {
+            agent_id: agent_id.clone(),
+            joined_at: now,
+            last_activity: now,
+            reputation: 1000, // Max reputation for founder
+            contribution_score: 0,
+            performance_proof: None,
+            proof_generated_at: None,
+            proof_expires_at: None,
+            proof_updated_at: None,
+            role: MemberRole::Founder,
+            voting_power: self.compute_voting_power(1000),
+            active: true,
+            left_at: None,
+        };
+
+        self.members.insert(agent_id.clone(), member);
+        self.record(&agent_id, SyndicateEvent::FounderAdded { agent_id: agent_id.clone() });
+        Ok(())
+    }
+
+    /// Request to join syndicate
+    pub fn request_membership(
+        &mut self,
+        agent_id: String,
+        reputation: u32,
+        proof: Option<&PerformanceProof>,
+        invite: Option<&SyndicateInvite>,
+    ) -> Result<String, SyndicateError> {
+        self.check_can_join(&agent_id)?;
+        if let Some(invite) = invite {
+            self.check_invite(&agent_id, invite)?;
+        }
+
+        if self.config.visibility == SyndicateVisibility::InviteOnly
+            && invite.is_none()
+            && !self.invitations.contains_key(&agent_id)
+        {
+            return Err(SyndicateError::InvitationRequired);
+        }
+
+        if self.pending_memberships.values().any(|p| p.agent_id == agent_id) {
+            return Err(SyndicateError::MembershipAlreadyRequested(agent_id));
+        }
+
+        // Check member limit, counting slots held by pending requests
+        if !self.has_open_slots() {
+            return Err(SyndicateError::MemberLimitReached);
+        }
+
+        // A valid invite stands in for the reputation and proof gates
+        if invite.is_none() {
+            if reputation < self.config.min_reputation {
+                return Err(SyndicateError::InsufficientReputation {
+                    required: self.config.min_reputation,
+                    actual: reputation,
+                });
+            }
+            if self.config.requires_performance_proof && proof.is_none() {
+                return Err(SyndicateError::ProofRequired);
+            }
+            if let Some(proof) = proof {
+                self.check_membership_proof(&agent_id, proof)?;
+            }
+        }
+
+        // Create add member proposal
+        let proposal_id = self.insert_proposal(
+            SYSTEM_ACTOR.into(),
+            ProposalType::AddMember { agent_id: agent_id.clone() },
+            Vec::new(),
+        )?.id.clone();
+
+        self.pending_memberships.insert(proposal_id.clone(), PendingMembership {
+            agent_id: agent_id.clone(),
+            reputation,
+            proof_id: proof.map(|p| p.id.clone()),
+            proof_generated_at: proof.map(|p| p.generated_at),
+            proof_expires_at: proof.and_then(|p| p.expires_at),
+        });
+        self.invitations.remove(&agent_id);
+        if let Some(invite) = invite {
+            self.consumed_invites.insert(invite.hash());
+        }
+        self.record(&agent_id, SyndicateEvent::MembershipRequested {
+            agent_id: agent_id.clone(),
+            proposal_id: proposal_id.clone(),
+        });
+
+        Ok(proposal_id)
+    }
+
+    /// Issue a signed invite valid for `ttl_secs`
+    ///
+    /// The issuer must be an active member who can approve members.
+    pub fn create_invite(
+        &mut self,
+        issuer: &AgentSDKey,
+        invitee: &str,
+        ttl_secs: u64,
+    ) -> Result<SyndicateInvite, SyndicateError> {
+        let issuer_id = issuer.agent_id();
+        let member = self.members.get(&issuer_id)
+            .ok_or_else(|| SyndicateError::NotMember(issuer_id.clone()))?;
+        if !member.active || !member.role.can_approve_members() {
+            return Err(SyndicateError::PermissionDenied);
+        }
+
+        let mut invite = SyndicateInvite {
+            syndicate_id: self.config.id.clone(),
+            invitee_agent_id: invitee.to_string(),
+            expires_at: self.clock.now().saturating_add(ttl_secs),
+            issuer_id: issuer_id.clone(),
+            issuer_public_key: issuer.public_key_hex(),
+            signature: String::new(),
+        };
+        invite.signature = issuer.sign_hex(&invite.signing_message())?;
+        self.record(&issuer_id, SyndicateEvent::MemberInvited { agent_id: invitee.to_string() });
+        Ok(invite)
+    }
+
+    /// Check that a signed invite admits `agent_id` right now
+    fn check_invite(&self, agent_id: &str, invite: &SyndicateInvite) -> Result<(), SyndicateError> {
+        if !invite.verify() {
+            return Err(SyndicateError::ForgedInvite);
+        }
+        if invite.syndicate_id != self.config.id || invite.invitee_agent_id != agent_id {
+            return Err(SyndicateError::InviteNotApplicable);
+        }
+        if self.clock.now() >= invite.expires_at {
+            return Err(SyndicateError::InviteExpired { expires_at: invite.expires_at });
+        }
+        if self.consumed_invites.contains(&invite.hash()) {
+            return Err(SyndicateError::InviteAlreadyUsed);
+        }
+        // The issuer must still be entitled to approve members
+        let issuer_ok = self.members.get(&invite.issuer_id)
+            .is_some_and(|m| m.active && m.role.can_approve_members());
+        if !issuer_ok {
+            return Err(SyndicateError::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    /// Check a membership proof against the applicant and `min_pnl_bps`
+    fn check_membership_proof(&self, agent_id: &str, proof: &PerformanceProof) -> Result<(), SyndicateError> {
+        let result = self.verifier.verify(proof)
+            .map_err(|e| SyndicateError::InvalidProof(e.to_string()))?;
+        if !result.valid {
+            return Err(SyndicateError::InvalidProof(result.notes.join("; ")));
+        }
+
+        if proof.agent_id != agent_id {
+            return Err(SyndicateError::ProofAgentMismatch {
+                expected: agent_id.to_string(),
+                actual: proof.agent_id.clone(),
+            });
+        }
+
+        // Only a lower bound on PnL says anything about the minimum
+        let lower_bound = matches!(
+            proof.public_inputs.condition,
+            ThresholdCondition::GreaterThan | ThresholdCondition::GreaterOrEqual
+        );
+        if proof.proof_type != ProofType::PnLThreshold || !lower_bound {
+            return Err(SyndicateError::WrongProofType(proof.proof_type));
+        }
+
+        if proof.public_inputs.threshold < self.config.min_pnl_bps {
+            return Err(SyndicateError::InsufficientPerformance {
+                required: self.config.min_pnl_bps,
+                proven: proof.public_inputs.threshold,
+            });
+        }
+        Ok(())
+    }
+
+    /// Replace a member's attached performance proof
+    ///
+    /// The proof must verify, belong to the member, and be no older than
+    /// the proof it replaces.
+    pub fn update_member_proof(
+        &mut self,
+        agent_id: &str,
+        proof: &PerformanceProof,
+        verifier: &ProofVerifier,
+    ) -> Result<(), SyndicateError> {
+        let member = self.members.get(agent_id)
+            .ok_or_else(|| SyndicateError::NotMember(agent_id.to_string()))?;
+
+        let result = verifier.verify(proof)
+            .map_err(|e| SyndicateError::InvalidProof(e.to_string()))?;
+        if !result.valid {
+            return Err(SyndicateError::InvalidProof(result.notes.join("; ")));
+        }
+        if proof.agent_id != agent_id {
+            return Err(SyndicateError::ProofAgentMismatch {
+                expected: agent_id.to_string(),
+                actual: proof.agent_id.clone(),
+            });
+        }
+        if let Some(current) = member.proof_generated_at {
+            if proof.generated_at < current {
+                return Err(SyndicateError::OutdatedProof {
+                    current,
+                    provided: proof.generated_at,
+                });
+            }
+        }
+
+        let now = self.clock.now();
+        let member = self.members.get_mut(agent_id).unwrap();
+        member.performance_proof = Some(proof.id.clone());
+        member.proof_generated_at = Some(proof.generated_at);
+        member.proof_expires_at = proof.expires_at;
+        member.proof_updated_at = Some(now);
+        self.record(agent_id, SyndicateEvent::MemberProofUpdated { proof_id: proof.id.clone() });
+        Ok(())
+    }
+
+    /// Active members whose proof is missing, expired, or older than `max_age_secs`
+    ///
+    /// Ordered by agent ID.
+    pub fn members_with_stale_proofs(&self, max_age_secs: u64) -> Vec<&SyndicateMember> {
+        let now = self.clock.now();
+        let mut stale: Vec<&SyndicateMember> = self.members.values()
+            .filter(|m| m.active)
+            .filter(|m| match m.proof_generated_at {
+                Some(generated_at) => {
+                    m.proof_expires_at.is_some_and(|at| now >= at)
+                        || now.saturating_sub(generated_at) > max_age_secs
+                }
+                None => true,
+            })
+            .collect();
+        stale.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+        stale
+    }
+
+    /// Invite an agent to request membership
+    ///
+    /// Required before `request_membership` on invite-only syndicates.
+    /// The invitation is consumed by the request.
+    pub fn invite(&mut self, actor_id: &str, agent_id: &str) -> Result<(), SyndicateError> {
+        let actor = self.members.get(actor_id)
+            .ok_or_else(|| SyndicateError::NotMember(actor_id.to_string()))?;
+        if !actor.active || !actor.role.can_approve_members() {
+            return Err(SyndicateError::PermissionDenied);
+        }
+        self.check_can_join(agent_id)?;
+
+        let now = self.clock.now();
+        self.invitations.insert(agent_id.to_string(), Invitation {
+            agent_id: agent_id.to_string(),
+            invited_by: actor_id.to_string(),
+            invited_at: now,
+        });
+        self.record(actor_id, SyndicateEvent::MemberInvited { agent_id: agent_id.to_string() });
+        Ok(())
+    }
+
+    /// Outstanding invitations by invited agent ID
+    pub fn invitations(&self) -> &HashMap<String, Invitation> {
+        &self.invitations
+    }
+
+    /// Whether the syndicate has room for another member
+    ///
+    /// Slots reserved by pending membership requests count as taken.
+    pub fn has_open_slots(&self) -> bool {
+        self.occupied_slots() + self.pending_admissions() < self.config.max_members
+    }
+
+    /// Membership requests still holding a reserved slot
+    ///
+    /// A request reserves a slot while its proposal is active or passed
+    /// but unexecuted, and releases it once the proposal is rejected,
+    /// cancelled, vetoed, or executed.
+    pub fn pending_admissions(&self) -> usize {
+        self.pending_memberships.keys()
+            .filter(|id| self.proposals.get(*id).is_some_and(|p| {
+                matches!(p.status, ProposalStatus::Active | ProposalStatus::Passed)
+            }))
+            .count()
+    }
+
+    /// Membership requests awaiting a decision, by proposal ID
+    pub fn pending_memberships(&self) -> &HashMap<String, PendingMembership> {
+        &self.pending_memberships
+    }
+
+    /// Add member (after approval)
+    pub fn add_member(&mut self, agent_id: String, reputation: u32) -> Result<(), SyndicateError> {
+        self.check_can_join(&agent_id)?;
+
+        let now = self.clock.now();
+
+        let member = SyndicateMember {
+            agent_id: agent_id.clone(),
+            joined_at: now,
+            last_activity: now,
+            reputation,
+            contribution_score: 0,
+            performance_proof: None,
+            proof_generated_at: None,
+            proof_expires_at: None,
+            proof_updated_at: None,
+            role: MemberRole::Member,
+            voting_power: self.compute_voting_power(reputation),
+            active: true,
+            left_at: None,
+        };
+
+        self.members.insert(agent_id.clone(), member);
+        self.record(SYSTEM_ACTOR, SyndicateEvent::MemberAdded { agent_id, reputation });
+        Ok(())
+    }
+
+    /// Remove a member from the syndicate
+    ///
+    /// Drops the member's delegation chain and withdraws their votes from
+    /// proposals that are still active. The last remaining founder cannot
+    /// be removed.
+    pub fn remove_member(&mut self, agent_id: &str, reason: &str) -> Result<RemovedMember, SyndicateError> {
+        let member = self.members.get(agent_id)
+            .ok_or_else(|| SyndicateError::NotMember(agent_id.to_string()))?;
+
+        if member.role == MemberRole::Founder {
+            let founders = self.members.values()
+                .filter(|m| m.role == MemberRole::Founder)
+                .count();
+            if founders <= 1 {
+                return Err(SyndicateError::LastFounder);
+            }
+        }
+
+        let member = self.members.remove(agent_id).unwrap();
+        self.delegations.remove(agent_id);
+        self.vote_delegations.retain(|from, d| from != agent_id && d.delegatee != agent_id);
+        self.withdraw_active_votes(agent_id);
+
+        let now = self.clock.now();
+
+        let record = RemovedMember {
+            agent_id: member.agent_id,
+            role: member.role,
+            reason: reason.to_string(),
+            removed_at: now,
+        };
+        self.removed_members.push(record.clone());
+        self.record(SYSTEM_ACTOR, SyndicateEvent::MemberRemoved {
+            agent_id: agent_id.to_string(),
+            reason: reason.to_string(),
+        });
+
+        Ok(record)
+    }
+
+    /// Bar an agent from joining for `duration_secs` (`None` for good)
+    ///
+    /// Requires Admin or Founder. Replaces any existing ban. Doesn't remove
+    /// a current member; that still goes through a `RemoveMember` proposal.
+    pub fn ban(
+        &mut self,
+        actor: &str,
+        agent_id: &str,
+        duration_secs: Option<u64>,
+        reason: &str,
+    ) -> Result<&BanRecord, SyndicateError> {
+        let member = self.members.get(actor)
+            .ok_or_else(|| SyndicateError::NotMember(actor.to_string()))?;
+        if !member.active || !member.role.can_create_proposals() {
+            return Err(SyndicateError::PermissionDenied);
+        }
+        Ok(self.impose_ban(actor, agent_id, duration_secs, reason))
+    }
+
+    /// Lift a ban; only founders may do this
+    pub fn unban(&mut self, actor: &str, agent_id: &str) -> Result<BanRecord, SyndicateError> {
+        let member = self.members.get(actor)
+            .ok_or_else(|| SyndicateError::NotMember(actor.to_string()))?;
+        if !member.active || member.role != MemberRole::Founder {
+            return Err(SyndicateError::PermissionDenied);
+        }
+        let record = self.banned.remove(agent_id)
+            .ok_or_else(|| SyndicateError::NotBanned(agent_id.to_string()))?;
+        self.record(actor, SyndicateEvent::MemberUnbanned { agent_id: agent_id.to_string() });
+        Ok(record)
+    }
+
+    /// Ban record for an agent, including lapsed bans
+    pub fn ban_record(&self, agent_id: &str) -> Option<&BanRecord> {
+        self.banned.get(agent_id)
+    }
+
+    /// Whether an agent is currently barred from joining
+    pub fn is_banned(&self, agent_id: &str) -> bool {
+        self.banned.get(agent_id).is_some_and(|b| b.is_active(self.clock.now()))
+    }
+
+    /// Record a ban without permission checks
+    fn impose_ban(&mut self, actor: &str, agent_id: &str, duration_secs: Option<u64>, reason: &str) -> &BanRecord {
+        let now = self.clock.now();
+        let until = duration_secs.map(|secs| now.saturating_add(secs));
+        self.banned.insert(agent_id.to_string(), BanRecord {
+            agent_id: agent_id.to_string(),
+            banned_by: actor.to_string(),
+            reason: reason.to_string(),
+            banned_at: now,
+            until,
+        });
+        self.record(actor, SyndicateEvent::MemberBanned { agent_id: agent_id.to_string(), until });
+        &self.banned[agent_id]
+    }
+
+    /// Leave the syndicate voluntarily
+    ///
+    /// The member stays on record as inactive with a `left_at` timestamp.
+    /// Their votes on active proposals are withdrawn. The founder can only
+    /// leave once no other members remain.
+    pub fn leave(&mut self, agent_id: &str) -> Result<(), SyndicateError> {
+        let member = self.members.get(agent_id)
+            .ok_or_else(|| SyndicateError::NotMember(agent_id.to_string()))?;
+
+        if member.left_at.is_some() {
+            return Err(SyndicateError::NotMember(agent_id.to_string()));
+        }
+
+        if member.role == MemberRole::Founder
+            && self.members.values().any(|m| m.agent_id != agent_id && m.left_at.is_none())
+        {
+            return Err(SyndicateError::FounderCannotLeave);
+        }
+
+        let now = self.clock.now();
+
+        let member = self.members.get_mut(agent_id).unwrap();
+        member.active = false;
+        member.left_at = Some(now);
+
+        self.vote_delegations.retain(|from, d| from != agent_id && d.delegatee != agent_id);
+        self.withdraw_active_votes(agent_id);
+        self.record(agent_id, SyndicateEvent::MemberLeft { agent_id: agent_id.to_string() });
+
+        Ok(())
+    }
+
+    /// Deactivate members idle for longer than `max_idle_secs`
+    ///
+    /// Deactivated members no longer count toward quorum. Returns the
+    /// deactivated agent IDs, sorted.
+    pub fn deactivate_inactive(&mut self, max_idle_secs: u64) -> Vec<String> {
+        let now = self.clock.now();
+        let mut deactivated: Vec<String> = self.members.values_mut()
+            .filter(|m| m.active && now.saturating_sub(m.last_activity.max(m.joined_at)) > max_idle_secs)
+            .map(|m| {
+                m.active = false;
+                m.agent_id.clone()
+            })
+            .collect();
+        deactivated.sort();
+
+        for agent_id in &deactivated {
+            self.record(SYSTEM_ACTOR, SyndicateEvent::MemberDeactivated { agent_id: agent_id.clone() });
+        }
+        deactivated
+    }
+
+    /// Reactivate a member deactivated for inactivity
+    ///
+    /// Requires an active Approver, Admin, or Founder. Members who left
+    /// voluntarily must rejoin instead.
+    pub fn reactivate(&mut self, actor_id: &str, agent_id: &str) -> Result<(), SyndicateError> {
+        let actor = self.members.get(actor_id)
+            .ok_or_else(|| SyndicateError::NotMember(actor_id.to_string()))?;
+        if !actor.active || !actor.role.can_approve_members() {
+            return Err(SyndicateError::PermissionDenied);
+        }
+
+        let now = self.clock.now();
+        let member = self.members.get_mut(agent_id)
+            .filter(|m| m.left_at.is_none())
+            .ok_or_else(|| SyndicateError::NotMember(agent_id.to_string()))?;
+        if member.active {
+            return Ok(());
+        }
+        member.active = true;
+        member.last_activity = now;
+
+        self.record(actor_id, SyndicateEvent::MemberReactivated { agent_id: agent_id.to_string() });
+        Ok(())
+    }
+
+    /// Record activity by a member
+    fn touch(&mut self, agent_id: &str, now: u64) {
+        if let Some(member) = self.members.get_mut(agent_id) {
+            member.last_activity = now;
+        }
+    }
+
+    /// Check that an agent may join (or rejoin after leaving)
+    fn check_can_join(&self, agent_id: &str) -> Result<(), SyndicateError> {
+        if let Some(ban) = self.banned.get(agent_id) {
+            if ban.is_active(self.clock.now()) {
+                return Err(SyndicateError::Banned { until: ban.until });
+            }
+        }
+        match self.members.get(agent_id) {
+            None => Ok(()),
+            Some(SyndicateMember { left_at: Some(left_at), .. }) => {
+                let now = self.clock.now();
+                let until = left_at + self.config.rejoin_cooldown_secs;
+                if now < until {
+                    Err(SyndicateError::CooldownActive { until })
+                } else {
+                    Ok(())
+                }
+            }
+            Some(_) => Err(SyndicateError::AlreadyMember(agent_id.to_string())),
+        }
+    }
+
+    /// Members holding a slot toward `max_members`
+    fn occupied_slots(&self) -> usize {
+        self.members.values().filter(|m| m.left_at.is_none()).count()
+    }
+
+    /// Withdraw an agent's votes from proposals still open for voting
+    fn withdraw_active_votes(&mut self, agent_id: &str) {
+        for proposal in self.proposals.values_mut() {
+            if proposal.status != ProposalStatus::Active {
+                continue;
+            }
+            if proposal.withdraw_vote(agent_id).is_some() {
+                proposal.vote_receipts.remove(agent_id);
+            }
+        }
+    }
+
+    /// Get history of removed members
+    pub fn removed_members(&self) -> &[RemovedMember] {
+        &self.removed_members
+    }
+
+    /// Change a member's role
+    ///
+    /// Returns the target's previous role.
+    pub fn set_role(
+        &mut self,
+        actor_id: &str,
+        target_id: &str,
+        new_role: MemberRole,
+    ) -> Result<MemberRole, SyndicateError> {
+        if actor_id == target_id {
+            return Err(SyndicateError::PermissionDenied);
+        }
+
+        let actor = self.members.get(actor_id)
+            .ok_or_else(|| SyndicateError::NotMember(actor_id.to_string()))?;
+        if !actor.active {
+            return Err(SyndicateError::MemberInactive);
+        }
+        let actor_role = actor.role;
+
+        let target = self.members.get_mut(target_id)
+            .ok_or_else(|| SyndicateError::NotMember(target_id.to_string()))?;
+
+        if !actor_role.can_assign_role(target.role, new_role) {
+            return Err(SyndicateError::PermissionDenied);
+        }
+
+        let previous = target.role;
+        target.role = new_role;
+        target.voting_power = self.config.member_voting_power(target);
+        self.record(actor_id, SyndicateEvent::RoleChanged {
+            agent_id: target_id.to_string(),
+            from: previous,
+            to: new_role,
+        });
+        Ok(previous)
+    }
+
+    /// Create a proposal
+    ///
+    /// The proposer must be an active member who can create proposals.
+    /// Other members may open `Custom` proposals when
+    /// `members_can_propose_custom` is set.
+    pub fn create_proposal(
+        &mut self,
+        proposer: String,
+        proposal_type: ProposalType,
+    ) -> Result<&Proposal, SyndicateError> {
+        self.create_proposal_with_dependencies(proposer, proposal_type, Vec::new())
+    }
+
+    /// Create a proposal that can only execute after `depends_on` have
+    ///
+    /// Every dependency must already exist, and the dependency graph they
+    /// lead into must be acyclic.
+    pub fn create_proposal_with_dependencies(
+        &mut self,
+        proposer: String,
+        proposal_type: ProposalType,
+        depends_on: Vec<String>,
+    ) -> Result<&Proposal, SyndicateError> {
+        self.check_can_propose(&proposer, &proposal_type)?;
+        self.check_dependencies(&depends_on)?;
+
+        let now = self.clock.now();
+        self.check_proposal_rate(&proposer, now)?;
+        self.insert_proposal(proposer, proposal_type, depends_on)
+    }
+
+    /// Check dependencies exist and don't lead into a cycle
+    fn check_dependencies(&self, depends_on: &[String]) -> Result<(), SyndicateError> {
+        if let Some(missing) = depends_on.iter().find(|id| !self.proposals.contains_key(*id)) {
+            return Err(SyndicateError::UnknownDependency(missing.clone()));
+        }
+
+        // Depth-first walk; reaching a proposal still on the path is a cycle
+        let mut done: HashSet<&str> = HashSet::new();
+        let mut path: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<(&str, usize)> = depends_on.iter().map(|id| (id.as_str(), 0)).collect();
+        while let Some((id, next_child)) = stack.pop() {
+            if next_child == 0 {
+                if done.contains(id) {
+                    continue;
+                }
+                if !path.insert(id) {
+                    return Err(SyndicateError::DependencyCycle(id.to_string()));
+                }
+            }
+            let children = self.proposals.get(id).map_or(&[][..], |p| p.depends_on.as_slice());
+            match children.get(next_child) {
+                Some(child) => {
+                    stack.push((id, next_child + 1));
+                    stack.push((child, 0));
+                }
+                None => {
+                    path.remove(id);
+                    done.insert(id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that a member may put forward a proposal of this type
+    fn check_can_propose(&self, proposer: &str, proposal_type: &ProposalType) -> Result<(), SyndicateError> {
+        let member = self.members.get(proposer)
+            .ok_or_else(|| SyndicateError::NotMember(proposer.to_string()))?;
+        if !member.active {
+            return Err(SyndicateError::MemberInactive);
+        }
+        let custom_allowed = proposal_type.kind() == ProposalKind::Custom
+            && self.config.members_can_propose_custom
+            && member.role.can_vote();
+        if !member.role.can_create_proposals() && !custom_allowed {
+            return Err(SyndicateError::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    /// Check a proposal's payload is acceptable
+    fn check_proposal_type(&self, proposal_type: &ProposalType) -> Result<(), SyndicateError> {
+        match proposal_type {
+            ProposalType::UpdateConfig(change) => {
+                self.check_config_change(change)?;
+            }
+            ProposalType::RemoveMember { agent_id, evidence } => {
+                if evidence.is_empty() && self.config.require_removal_evidence {
+                    return Err(SyndicateError::EvidenceRequired);
+                }
+                if let Some(i) = evidence.iter().position(|e| !e.check(agent_id)) {
+                    return Err(SyndicateError::InvalidEvidence(i));
+                }
+            }
+            ProposalType::StructuredCustom { payload, .. } => {
+                if !payload.is_object() {
+                    return Err(SyndicateError::InvalidPayload("payload must be a JSON object".into()));
+                }
+                let size = serde_json::to_vec(payload)
+                    .map_err(|e| SyndicateError::InvalidPayload(e.to_string()))?
+                    .len();
+                if size > self.config.max_custom_payload_bytes {
+                    return Err(SyndicateError::PayloadTooLarge {
+                        size,
+                        max: self.config.max_custom_payload_bytes,
+                    });
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Replace an active proposal's content before anyone has voted
+    ///
+    /// Allowed for the original proposer and for members who can create
+    /// proposals. The creation time and deadline are kept. Membership
+    /// requests can't be amended.
+    pub fn amend_proposal(
+        &mut self,
+        actor: &str,
+        proposal_id: &str,
+        new_type: ProposalType,
+    ) -> Result<&Proposal, SyndicateError> {
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+
+        if proposal.proposer == actor {
+            self.check_can_propose(actor, &new_type)?;
+        } else {
+            let member = self.members.get(actor)
+                .ok_or_else(|| SyndicateError::NotMember(actor.to_string()))?;
+            if !member.active || !member.role.can_create_proposals() {
+                return Err(SyndicateError::PermissionDenied);
+            }
+        }
+        if self.pending_memberships.contains_key(proposal_id) {
+            return Err(SyndicateError::PermissionDenied);
+        }
+
+        if proposal.status != ProposalStatus::Active {
+            return Err(SyndicateError::ProposalNotActive(proposal.status));
+        }
+        if !proposal.votes.is_empty() || !proposal.delegated_voters.is_empty() {
+            return Err(SyndicateError::AmendmentAfterVoting);
+        }
+        self.check_proposal_type(&new_type)?;
+
+        let threshold_bps = self.config.threshold_for(new_type.kind());
+        let proposal = self.proposals.get_mut(proposal_id).unwrap();
+        proposal.threshold_bps = Some(threshold_bps);
+        proposal.proposal_type = new_type;
+        proposal.amendment_count += 1;
+        self.record(actor, SyndicateEvent::ProposalAmended { proposal_id: proposal_id.to_string() });
+        Ok(self.proposals.get(proposal_id).unwrap())
+    }
+
+    /// Validate and store a proposal without proposer checks
+    ///
+    /// Used directly for proposals the syndicate raises itself.
+    fn insert_proposal(
+        &mut self,
+        proposer: String,
+        proposal_type: ProposalType,
+        depends_on: Vec<String>,
+    ) -> Result<&Proposal, SyndicateError> {
+        let now = self.clock.now();
+        self.check_proposal_type(&proposal_type)?;
+
+        let sequence = self.proposal_counter + 1;
+        let proposal_id = generate_proposal_id(&self.config.id, &proposer, now, sequence);
+        if self.proposals.contains_key(&proposal_id) {
+            return Err(SyndicateError::ProposalIdCollision(proposal_id));
+        }
+        self.proposal_counter = sequence;
+
+        self.record(&proposer, SyndicateEvent::ProposalCreated { proposal_id: proposal_id.clone() });
+        self.touch(&proposer, now);
+
+        let power_snapshot = self.members.values()
+            .filter(|m| m.active && m.role.can_vote())
+            .map(|m| (m.agent_id.clone(), self.current_voting_power(m, now) as u64))
+            .collect();
+
+        let threshold_bps = self.config.threshold_for(proposal_type.kind());
+        let proposal = Proposal {
+            id: proposal_id.clone(),
+            sequence,
+            proposal_type,
+            proposer,
+            created_at: now,
+            deadline: now.saturating_add(self.config.proposal_duration),
+            votes_for: 0,
+            votes_against: 0,
+            votes_abstain: 0,
+            votes: Vec::new(),
+            voters: HashMap::new(),
+            voter_power: HashMap::new(),
+            delegated_voters: HashMap::new(),
+            power_snapshot,
+            vote_receipts: HashMap::new(),
+            action_signatures: Vec::new(),
+            status: ProposalStatus::Active,
+            veto: None,
+            executable_at: None,
+            amendment_count: 0,
+            early_finalized: false,
+            depends_on,
+            threshold_bps: Some(threshold_bps),
+            execution_result: None,
+        };
+
+        self.proposals.insert(proposal_id.clone(), proposal);
+        Ok(self.proposals.get(&proposal_id).unwrap())
+    }
+
+    /// Enforce per-member proposal limits
+    ///
+    /// Only `Active` proposals count toward the open-proposal limit, so
+    /// finalizing or cancelling one frees a slot.
+    fn check_proposal_rate(&self, proposer: &str, now: u64) -> Result<(), SyndicateError> {
+        let own: Vec<&Proposal> = self.proposals.values()
+            .filter(|p| p.proposer == proposer)
+            .collect();
+
+        if let Some(last) = own.iter().map(|p| p.created_at).max() {
+            let retry_after = last + self.config.proposal_cooldown_secs;
+            if now < retry_after {
+                return Err(SyndicateError::ProposalRateLimited { retry_after });
+            }
+        }
+
+        if let Some(max_active) = self.config.max_active_proposals_per_member {
+            let active: Vec<&&Proposal> = own.iter()
+                .filter(|p| p.status == ProposalStatus::Active)
+                .collect();
+            if active.len() >= max_active {
+                // The earliest deadline is when a slot can next be freed by finalizing
+                let retry_after = active.iter().map(|p| p.deadline + 1).min().unwrap_or(now);
+                return Err(SyndicateError::ProposalRateLimited { retry_after });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Vote on a proposal
+    pub fn vote(
+        &mut self,
+        proposal_id: &str,
+        voter: &str,
+        choice: VoteChoice,
+    ) -> Result<(), SyndicateError> {
+        // Get voter info
+        let member = self.members.get(voter)
+            .ok_or_else(|| SyndicateError::NotMember(voter.to_string()))?;
+
+        if !member.active {
+            return Err(SyndicateError::MemberInactive);
+        }
+        if !member.role.can_vote() {
+            return Err(SyndicateError::ObserverCannotVote);
+        }
+
+        // Get proposal
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+
+        // Power is fixed when the proposal is created
+        let mut voting_power = *proposal.power_snapshot.get(voter)
+            .ok_or_else(|| SyndicateError::NotInPowerSnapshot(voter.to_string()))?;
+
+        // Check if voting is still open
+        let now = self.clock.now();
+
+        if proposal.status != ProposalStatus::Active || now > proposal.deadline {
+            return Err(SyndicateError::VotingClosed);
+        }
+
+        // Check if already voted
+        if proposal.voters.contains_key(voter) {
+            return Err(SyndicateError::AlreadyVoted);
+        }
+        if let Some(delegatee) = proposal.delegated_voters.get(voter) {
+            return Err(SyndicateError::VotedByDelegate(delegatee.clone()));
+        }
+
+        // Pick up power from members delegating to this voter
+        let delegated = self.gather_delegated_power(Some(proposal), voter, now);
+
+        // Record vote
+        let proposal = self.proposals.get_mut(proposal_id).unwrap();
+        for (delegator, power) in delegated {
+            proposal.delegated_voters.insert(delegator, voter.to_string());
+            voting_power += power;
+        }
+        proposal.record_vote(VoteRecord {
+            voter: voter.to_string(),
+            choice,
+            power: voting_power,
+            timestamp: now,
+        });
+        self.touch(voter, now);
+
+        self.record(voter, SyndicateEvent::VoteCast {
+            proposal_id: proposal_id.to_string(),
+            choice,
+            power: voting_power,
+        });
+        Ok(())
+    }
+
+    /// Co-sign a passed `ExecuteAction` proposal
+    ///
+    /// The signature must cover `Proposal::action_signing_message()` and
+    /// verify against `public_key`, which must be the signer's own key.
+    /// Repeat co-signs from the same member are ignored.
+    pub fn co_sign_action(
+        &mut self,
+        proposal_id: &str,
+        signer: &str,
+        public_key: &[u8; 32],
+        signature_hex: &str,
+    ) -> Result<(), SyndicateError> {
+        let member = self.members.get(signer)
+            .ok_or_else(|| SyndicateError::NotMember(signer.to_string()))?;
+        if !member.active {
+            return Err(SyndicateError::MemberInactive);
+        }
+        if !key_belongs_to(public_key, signer) {
+            return Err(SyndicateError::SignerKeyMismatch(signer.to_string()));
+        }
+
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+        let message = proposal.action_signing_message()
+            .ok_or_else(|| SyndicateError::NotAnAction(proposal_id.to_string()))?;
+        if !matches!(proposal.status, ProposalStatus::Passed | ProposalStatus::Executed) {
+            return Err(SyndicateError::ProposalNotPassed(proposal.status));
+        }
+
+        if proposal.action_signatures.iter().any(|s| s.signer == signer) {
+            return Ok(());
+        }
+
+        let signature: [u8; 64] = hex::decode(signature_hex)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(SyndicateError::InvalidActionSignature)?;
+        sdkey_manager::verify_signature(public_key, &message, &signature)
+            .map_err(|_| SyndicateError::InvalidActionSignature)?;
+
+        let now = self.clock.now();
+
+        self.proposals.get_mut(proposal_id).unwrap()
+            .action_signatures
+            .push(ActionSignature {
+                signer: signer.to_string(),
+                public_key: hex::encode(public_key),
+                signature: signature_hex.to_string(),
+                signed_at: now,
+            });
+        self.record(signer, SyndicateEvent::ActionCoSigned { proposal_id: proposal_id.to_string() });
+        Ok(())
+    }
+
+    /// Collected co-signatures, once at least `required_signers` have signed
+    pub fn action_ready(&self, proposal_id: &str, required_signers: usize) -> Option<Vec<ActionSignature>> {
+        let proposal = self.proposals.get(proposal_id)?;
+        if proposal.action_signatures.len() < required_signers {
+            return None;
+        }
+        Some(proposal.action_signatures.clone())
+    }
+
+    /// Vote with a signed receipt
+    ///
+    /// Verifies the signature against `voter_public_key`, which must be the
+    /// voter's own key, before recording the vote through `vote`, then
+    /// keeps the receipt on the proposal.
+    /// The unsigned `vote` remains available for trusted callers.
+    pub fn vote_signed(
+        &mut self,
+        proposal_id: &str,
+        signed_vote: &SignedVote,
+        voter_public_key: &[u8; 32],
+    ) -> Result<(), SyndicateError> {
+        if signed_vote.proposal_id != proposal_id {
+            return Err(SyndicateError::VoteProposalMismatch {
+                expected: proposal_id.to_string(),
+                actual: signed_vote.proposal_id.clone(),
+            });
+        }
+
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+
+        if !key_belongs_to(voter_public_key, &signed_vote.voter) {
+            return Err(SyndicateError::SignerKeyMismatch(signed_vote.voter.clone()));
+        }
+        signed_vote.verify(voter_public_key)
+            .map_err(|_| SyndicateError::InvalidVoteSignature)?;
+
+        if signed_vote.timestamp < proposal.created_at {
+            return Err(SyndicateError::StaleVote {
+                signed_at: signed_vote.timestamp,
+                created_at: proposal.created_at,
+            });
+        }
+
+        self.vote(proposal_id, &signed_vote.voter, signed_vote.choice)?;
+
+        self.proposals.get_mut(proposal_id).unwrap()
+            .vote_receipts
+            .insert(signed_vote.voter.clone(), signed_vote.clone());
+        Ok(())
+    }
+
+    /// Change an existing vote before the deadline
+    ///
+    /// Moves the power recorded when the vote was cast, not the voter's
+    /// current power.
+    pub fn change_vote(
+        &mut self,
+        proposal_id: &str,
+        voter: &str,
+        new_choice: VoteChoice,
+    ) -> Result<(), SyndicateError> {
+        let member = self.members.get(voter)
+            .ok_or_else(|| SyndicateError::NotMember(voter.to_string()))?;
+
+        if !member.active {
+            return Err(SyndicateError::MemberInactive);
+        }
+        if !member.role.can_vote() {
+            return Err(SyndicateError::ObserverCannotVote);
+        }
+
+        let proposal = self.proposals.get_mut(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+
+        let now = self.clock.now();
+
+        if proposal.status != ProposalStatus::Active || now > proposal.deadline {
+            return Err(SyndicateError::VotingClosed);
+        }
+
+        let old_choice = *proposal.voters.get(voter)
+            .ok_or(SyndicateError::NotVoted)?;
+        if old_choice == new_choice {
+            return Ok(());
+        }
+
+        // The power recorded with the original vote carries over, along
+        // with the delegators it was gathered from
+        proposal.change_recorded_vote(voter, new_choice, now).unwrap();
+        // The old receipt no longer matches the recorded vote
+        proposal.vote_receipts.remove(voter);
+        self.touch(voter, now);
+
+        self.record(voter, SyndicateEvent::VoteChanged {
+            proposal_id: proposal_id.to_string(),
+            from: old_choice,
+            to: new_choice,
+        });
+        Ok(())
+    }
+
+    /// Cast votes on several proposals at once
+    ///
+    /// Each vote is applied on its own, so failures don't affect the rest;
+    /// results line up with the input. Accepts `bool` or `VoteChoice`
+    /// choices. A batch naming the same proposal twice is rejected before
+    /// any vote is cast.
+    pub fn vote_batch<C: Into<VoteChoice>>(
+        &mut self,
+        voter: &str,
+        votes: Vec<(String, C)>,
+    ) -> Result<Vec<Result<(), SyndicateError>>, SyndicateError> {
+        let mut seen = HashSet::new();
+        if let Some((id, _)) = votes.iter().find(|(id, _)| !seen.insert(id.as_str())) {
+            return Err(SyndicateError::DuplicateBatchVote(id.clone()));
+        }
+
+        Ok(votes
+            .into_iter()
+            .map(|(proposal_id, choice)| self.vote(&proposal_id, voter, choice.into()))
+            .collect())
+    }
+
+    /// Vote for or against a proposal
+    #[deprecated(note = "use `vote` with a `VoteChoice`")]
+    pub fn vote_bool(
+        &mut self,
+        proposal_id: &str,
+        voter: &str,
+        approve: bool,
+    ) -> Result<(), SyndicateError> {
+        self.vote(proposal_id, voter, approve.into())
+    }
+
+    /// Delegate a member's voting power to another member
+    ///
+    /// Replaces any existing delegation from `from`. Chains are followed
+    /// transitively, so delegations that would close a cycle are rejected.
+    pub fn delegate_vote(&mut self, from: &str, to: &str, expires_at: u64) -> Result<(), SyndicateError> {
+        let now = self.clock.now();
+
+        if from == to {
+            return Err(SyndicateError::CircularDelegation);
+        }
+
+        for agent_id in [from, to] {
+            let member = self.members.get(agent_id)
+                .ok_or_else(|| SyndicateError::NotMember(agent_id.to_string()))?;
+            if !member.active {
+                return Err(SyndicateError::MemberInactive);
+            }
+            if !member.role.can_vote() {
+                return Err(SyndicateError::ObserverCannotVote);
+            }
+        }
+
+        if expires_at <= now {
+            return Err(SyndicateError::InvalidDelegationExpiry);
+        }
+
+        if self.delegation_forms_cycle(from, to, now) {
+            return Err(SyndicateError::CircularDelegation);
+        }
+
+        self.vote_delegations.insert(from.to_string(), VoteDelegation {
+            delegator: from.to_string(),
+            delegatee: to.to_string(),
+            created_at: now,
+            expires_at,
+        });
+        self.record(from, SyndicateEvent::VoteDelegated { delegatee: to.to_string(), expires_at });
+
+        Ok(())
+    }
+
+    /// Whether following `to`'s delegation chain leads back to `from`
+    fn delegation_forms_cycle(&self, from: &str, to: &str, now: u64) -> bool {
+        let mut current = to;
+        while let Some(d) = self.vote_delegations.get(current).filter(|d| d.is_active_at(now)) {
+            if d.delegatee == from {
+                return true;
+            }
+            current = &d.delegatee;
+        }
+        false
+    }
+
+    /// Get the vote delegation made by a member
+    pub fn get_vote_delegation(&self, agent_id: &str) -> Option<&VoteDelegation> {
+        self.vote_delegations.get(agent_id)
+    }
+
+    /// Withdraw a member's vote delegation, expired or not
+    ///
+    /// Votes the delegatee already cast with the delegated power stand.
+    pub fn revoke_vote_delegation(&mut self, from: &str) -> Result<VoteDelegation, SyndicateError> {
+        let delegation = self.vote_delegations.remove(from)
+            .ok_or_else(|| SyndicateError::DelegationNotFound(from.to_string()))?;
+        self.record(from, SyndicateEvent::VoteDelegationRevoked { delegatee: delegation.delegatee.clone() });
+        Ok(delegation)
+    }
+
+    /// Delegations currently in force, ordered by delegator
+    pub fn active_delegations(&self) -> Vec<&VoteDelegation> {
+        let now = self.clock.now();
+        let mut active: Vec<&VoteDelegation> = self.vote_delegations.values()
+            .filter(|d| d.is_active_at(now))
+            .collect();
+        active.sort_by(|a, b| a.delegator.cmp(&b.delegator));
+        active
+    }
+
+    /// Voting power of a member including power delegated to them
+    pub fn effective_voting_power(&self, agent_id: &str) -> u64 {
+        let now = self.clock.now();
+
+        let own = match self.members.get(agent_id) {
+            Some(member) if member.active => self.current_voting_power(member, now) as u64,
+            _ => return 0,
+        };
+
+        own + self.gather_delegated_power(None, agent_id, now)
+            .iter()
+            .map(|(_, power)| power)
+            .sum::<u64>()
+    }
+
+    /// Collect power delegated (directly or transitively) to `delegatee`
+    ///
+    /// When a proposal is given, delegators who already voted or were
+    /// already represented on it are skipped, along with their own
+    /// delegators.
+    fn gather_delegated_power(
+        &self,
+        proposal: Option<&Proposal>,
+        delegatee: &str,
+        now: u64,
+    ) -> Vec<(String, u64)> {
+        let mut gathered = Vec::new();
+        let mut pending = vec![delegatee.to_string()];
+
+        while let Some(current) = pending.pop() {
+            for d in self.vote_delegations.values() {
+                if d.delegatee != current || d.delegator == delegatee || !d.is_active_at(now) {
+                    continue;
+                }
+                if let Some(proposal) = proposal {
+                    if proposal.voters.contains_key(&d.delegator)
+                        || proposal.delegated_voters.contains_key(&d.delegator)
+                    {
+                        continue;
+                    }
+                }
+                let Some(member) = self.members.get(&d.delegator).filter(|m| m.active) else {
+                    continue;
+                };
+                let power = match proposal {
+                    Some(proposal) => match proposal.power_snapshot.get(&d.delegator) {
+                        Some(power) => *power,
+                        None => continue,
+                    },
+                    None => self.current_voting_power(member, now) as u64,
+                };
+                gathered.push((d.delegator.clone(), power));
+                pending.push(d.delegator.clone());
+            }
+        }
+
+        gathered
+    }
+
+    /// Cancel an active proposal, or a passed one still in its timelock
+    ///
+    /// Allowed for the original proposer and for members who can create
+    /// proposals. Votes already cast stay on the proposal for auditing.
+    pub fn cancel_proposal(&mut self, actor: &str, proposal_id: &str) -> Result<(), SyndicateError> {
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+
+        if proposal.proposer != actor {
+            let member = self.members.get(actor)
+                .ok_or_else(|| SyndicateError::NotMember(actor.to_string()))?;
+            if !member.active || !member.role.can_create_proposals() {
+                return Err(SyndicateError::PermissionDenied);
+            }
+        }
+
+        let in_timelock = proposal.status == ProposalStatus::Passed
+            && proposal.executable_at.is_some_and(|at| self.clock.now() < at);
+        if proposal.status != ProposalStatus::Active && !in_timelock {
+            return Err(SyndicateError::ProposalNotActive(proposal.status));
+        }
+
+        self.proposals.get_mut(proposal_id).unwrap().status = ProposalStatus::Cancelled;
+        self.pending_memberships.remove(proposal_id);
+        self.record(actor, SyndicateEvent::ProposalCancelled { proposal_id: proposal_id.to_string() });
+        Ok(())
+    }
+
+    /// Veto an active or passed-but-unexecuted proposal
+    ///
+    /// Only members who can modify config may veto, at most
+    /// `max_vetoes_per_period` times per `veto_period_secs`. The veto is
+    /// recorded on the proposal and in the audit log against the actor.
+    pub fn veto(&mut self, actor: &str, proposal_id: &str, reason: &str) -> Result<(), SyndicateError> {
+        let member = self.members.get(actor)
+            .ok_or_else(|| SyndicateError::NotMember(actor.to_string()))?;
+        if !member.active || !member.role.can_modify_config() {
+            return Err(SyndicateError::PermissionDenied);
+        }
+
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+        if !matches!(proposal.status, ProposalStatus::Active | ProposalStatus::Passed) {
+            return Err(SyndicateError::CannotVeto(proposal.status));
+        }
+
+        let now = self.clock.now();
+        if let Some(max) = self.config.max_vetoes_per_period {
+            if self.vetoes_used(actor) >= max {
+                return Err(SyndicateError::VetoLimitReached);
+            }
+        }
+
+        let proposal = self.proposals.get_mut(proposal_id).unwrap();
+        proposal.status = ProposalStatus::Vetoed;
+        proposal.veto = Some(VetoRecord {
+            vetoed_by: actor.to_string(),
+            reason: reason.to_string(),
+            vetoed_at: now,
+        });
+        self.pending_memberships.remove(proposal_id);
+        self.record(actor, SyndicateEvent::ProposalVetoed {
+            proposal_id: proposal_id.to_string(),
+            reason: reason.to_string(),
+        });
+        Ok(())
+    }
+
+    /// Vetoes a member has used in the current veto period
+    pub fn vetoes_used(&self, agent_id: &str) -> u32 {
+        let since = self.clock.now().saturating_sub(self.config.veto_period_secs);
+        self.proposals.values()
+            .filter_map(|p| p.veto.as_ref())
+            .filter(|v| v.vetoed_by == agent_id && v.vetoed_at >= since)
+            .count() as u32
+    }
+
+    /// Finalize a proposal
+    pub fn finalize_proposal(&mut self, proposal_id: &str) -> Result<ProposalStatus, SyndicateError> {
+        let now = self.clock.now();
+
+        self.finalize_at(proposal_id, now)
+    }
+
+    /// Finalize all active proposals whose deadline has passed
+    ///
+    /// Returns the proposals that changed status, oldest first. Proposals
+    /// that were already finalized are skipped, so repeated sweeps are no-ops.
+    pub fn sweep_expired(&mut self, now: u64) -> Vec<(String, ProposalStatus)> {
+        let mut expired: Vec<_> = self.proposals.values()
+            .filter(|p| p.status == ProposalStatus::Active && now > p.deadline)
+            .map(|p| (p.created_at, p.sequence, p.id.clone()))
+            .collect();
+        expired.sort();
+
+        expired
+            .into_iter()
+            .filter_map(|(_, _, id)| {
+                let status = self.finalize_at(&id, now).ok()?;
+                Some((id, status))
+            })
+            .collect()
+    }
+
+    /// Finalize a proposal as of the given time
+    fn finalize_at(&mut self, proposal_id: &str, now: u64) -> Result<ProposalStatus, SyndicateError> {
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+
+        // Already finalized proposals keep their outcome
+        if proposal.status != ProposalStatus::Active {
+            return Ok(proposal.status);
+        }
+
+        if now <= proposal.deadline {
+            return Err(SyndicateError::VotingStillOpen);
+        }
+
+        let total_votes = proposal.votes_for + proposal.votes_against + proposal.votes_abstain
+            - self.excluded_quorum_votes(proposal);
+        // Snapshot power of members who are still active
+        let total_voting_power: u64 = proposal.power_snapshot.iter()
+            .filter(|(agent_id, _)| self.counts_toward_quorum(proposal, agent_id))
+            .map(|(_, power)| power)
+            .sum();
+
+        // Check quorum
+        let quorum_threshold = (total_voting_power * self.config.quorum_bps as u64) / 10000;
+        let meets_quorum = total_votes >= quorum_threshold;
+
+        // Check if passed (abstentions don't count toward the threshold)
+        let decisive_votes = proposal.votes_for + proposal.votes_against;
+        let threshold_bps = self.threshold_bps_for(proposal);
+        let meets_threshold = proposal.votes_for * 10000 >= decisive_votes * threshold_bps as u64;
+
+        let status = if meets_quorum && decisive_votes > 0 && meets_threshold {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Rejected
+        };
+        self.conclude(proposal_id, status, now);
+        Ok(status)
+    }
+
+    /// Finalize an active proposal ahead of its deadline if the outcome is settled
+    ///
+    /// The outcome is settled when no way of spending the power of members
+    /// who haven't voted yet could change it. Cast votes are taken as they
+    /// stand. A proposal short of quorum is never passed early, nor rejected
+    /// early while the outstanding power could still bring it to quorum.
+    /// Returns `None` if voting must continue; past the deadline this is
+    /// the same as `finalize_proposal`.
+    pub fn try_early_finalize(&mut self, proposal_id: &str) -> Result<Option<ProposalStatus>, SyndicateError> {
+        let now = self.clock.now();
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(SyndicateError::ProposalNotActive(proposal.status));
+        }
+        if now > proposal.deadline {
+            return self.finalize_at(proposal_id, now).map(Some);
+        }
+
+        // Power of active members, from the snapshot when there is one
+        let active: Vec<(&str, u64)> = if proposal.power_snapshot.is_empty() {
+            self.members.values()
+                .filter(|m| m.active)
+                .map(|m| (m.agent_id.as_str(), self.current_voting_power(m, now) as u64))
+                .collect()
+        } else {
+            proposal.power_snapshot.iter()
+                .filter(|(agent_id, _)| self.members.get(*agent_id).is_some_and(|m| m.active))
+                .map(|(agent_id, power)| (agent_id.as_str(), *power))
+                .collect()
+        };
+        let unvoted = |agent_id: &str| {
+            !proposal.voters.contains_key(agent_id) && !proposal.delegated_voters.contains_key(agent_id)
+        };
+        let total_voting_power: u64 = active.iter()
+            .filter(|(agent_id, _)| self.counts_toward_quorum(proposal, agent_id))
+            .map(|(_, power)| power)
+            .sum();
+        // Still to vote; the proposer's vote counts toward the outcome even
+        // when it's left out of quorum
+        let remaining: u64 = active.iter()
+            .filter(|(agent_id, _)| unvoted(agent_id))
+            .map(|(_, power)| power)
+            .sum();
+        let remaining_quorum: u64 = active.iter()
+            .filter(|(agent_id, _)| unvoted(agent_id) && self.counts_toward_quorum(proposal, agent_id))
+            .map(|(_, power)| power)
+            .sum();
+
+        let total_votes = proposal.votes_for + proposal.votes_against + proposal.votes_abstain
+            - self.excluded_quorum_votes(proposal);
+        let quorum_threshold = (total_voting_power * self.config.quorum_bps as u64) / 10000;
+        let threshold_bps = self.threshold_bps_for(proposal) as u64;
+        let (votes_for, votes_against) = (proposal.votes_for, proposal.votes_against);
+
+        let status = if total_votes + remaining_quorum < quorum_threshold {
+            ProposalStatus::Rejected
+        } else if total_votes < quorum_threshold {
+            return Ok(None);
+        } else if votes_for + votes_against > 0
+            && votes_for * 10000 >= (votes_for + votes_against + remaining) * threshold_bps
+        {
+            // Passes even if everyone left votes against
+            ProposalStatus::Passed
+        } else if votes_for + votes_against + remaining == 0
+            || (votes_for + remaining) * 10000 < (votes_for + votes_against + remaining) * threshold_bps
+        {
+            // Fails even if everyone left votes for
+            ProposalStatus::Rejected
+        } else {
+            return Ok(None);
+        };
+
+        self.proposals.get_mut(proposal_id).unwrap().early_finalized = true;
+        self.conclude(proposal_id, status, now);
+        Ok(Some(status))
+    }
+
+    /// Voting threshold a proposal is tallied against
+    fn threshold_bps_for(&self, proposal: &Proposal) -> u32 {
+        proposal.threshold_bps
+            .unwrap_or_else(|| self.config.threshold_for(proposal.proposal_type.kind()))
+    }
+
+    /// Whether a member's power belongs in a proposal's quorum denominator
+    fn counts_toward_quorum(&self, proposal: &Proposal, agent_id: &str) -> bool {
+        if self.config.quorum_excludes_proposer && agent_id == proposal.proposer {
+            return false;
+        }
+        self.members.get(agent_id).is_some_and(|m| m.active)
+    }
+
+    /// Cast power left out of a proposal's quorum participation
+    ///
+    /// With `quorum_excludes_proposer`, this is everything the proposer
+    /// voted with, delegated power included.
+    fn excluded_quorum_votes(&self, proposal: &Proposal) -> u64 {
+        if !self.config.quorum_excludes_proposer {
+            return 0;
+        }
+        proposal.voter_power.get(&proposal.proposer).copied().unwrap_or(0)
+    }
+
+    /// Close voting on a proposal with the given outcome
+    fn conclude(&mut self, proposal_id: &str, status: ProposalStatus, now: u64) {
+        let proposal = self.proposals.get_mut(proposal_id).unwrap();
+        proposal.status = status;
+        if status == ProposalStatus::Passed {
+            proposal.executable_at = Some(now.saturating_add(self.config.execution_delay_for(proposal.proposal_type.kind())));
+        }
+
+        if status == ProposalStatus::Rejected {
+            self.pending_memberships.remove(proposal_id);
+        }
+        self.record(SYSTEM_ACTOR, SyndicateEvent::ProposalFinalized {
+            proposal_id: proposal_id.to_string(),
+            status,
+        });
+    }
+
+    /// Execute a passed proposal
+    ///
+    /// Applies the proposal's effect to the syndicate and records the outcome
+    /// in `execution_result`. If applying fails, the proposal is moved to
+    /// `ExecutionFailed` and the underlying error is returned. Proposals
+    /// still inside their timelock are left untouched.
+    pub fn execute_proposal(&mut self, proposal_id: &str) -> Result<String, SyndicateError> {
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+
+        if proposal.status != ProposalStatus::Passed {
+            return Err(SyndicateError::ProposalNotPassed(proposal.status));
+        }
+        if let Some(until) = proposal.executable_at {
+            if self.clock.now() < until {
+                return Err(SyndicateError::TimelockActive { until });
+            }
+        }
+        let pending_dependency = proposal.depends_on.iter().find(|id| {
+            self.proposals.get(*id).map_or(true, |p| p.status != ProposalStatus::Executed)
+        });
+        if let Some(id) = pending_dependency {
+            return Err(SyndicateError::DependencyNotExecuted(id.clone()));
+        }
+
+        let proposal_type = proposal.proposal_type.clone();
+        let outcome = self.apply_proposal(proposal_id, &proposal_type);
+
+        let proposal = self.proposals.get_mut(proposal_id).unwrap();
+        let result = match outcome {
+            Ok(result) => {
+                proposal.status = ProposalStatus::Executed;
+                proposal.execution_result = Some(result.clone());
+                Ok(result)
+            }
+            Err(e) => {
+                proposal.status = ProposalStatus::ExecutionFailed;
+                proposal.execution_result = Some(format!("Execution failed: {}", e));
+                Err(e)
+            }
+        };
+        let status = proposal.status;
+        self.record(SYSTEM_ACTOR, SyndicateEvent::ProposalExecuted {
+            proposal_id: proposal_id.to_string(),
+            status,
+        });
+        result
+    }
+
+    /// Apply the effect of a proposal to syndicate state
+    fn apply_proposal(
+        &mut self,
+        proposal_id: &str,
+        proposal_type: &ProposalType,
+    ) -> Result<String, SyndicateError> {
+        match proposal_type {
+            ProposalType::AddMember { agent_id } => {
+                let pending = self.pending_memberships.remove(proposal_id)
+                    .ok_or_else(|| SyndicateError::PendingMembershipMissing(proposal_id.to_string()))?;
+                // This request's own reservation was released above
+                if !self.has_open_slots() {
+                    return Err(SyndicateError::MemberLimitReached);
+                }
+                self.add_member(agent_id.clone(), pending.reputation)?;
+                let now = self.clock.now();
+                if let Some(member) = self.members.get_mut(agent_id) {
+                    if pending.proof_id.is_some() {
+                        member.proof_updated_at = Some(now);
+                    }
+                    member.performance_proof = pending.proof_id;
+                    member.proof_generated_at = pending.proof_generated_at;
+                    member.proof_expires_at = pending.proof_expires_at;
+                }
+                Ok(format!("Added member {}", agent_id))
+            }
+            ProposalType::RemoveMember { agent_id, evidence } => {
+                let reason = format!("Removed by proposal {}", proposal_id);
+                self.remove_member(agent_id, &reason)?;
+                self.impose_ban(SYSTEM_ACTOR, agent_id, self.config.removal_ban_secs, &reason);
+                if evidence.is_empty() {
+                    return Ok(format!("Removed member {}", agent_id));
+                }
+                let summaries: Vec<String> = evidence.iter().map(|e| e.summary()).collect();
+                Ok(format!("Removed member {} (evidence: {})", agent_id, summaries.join("; ")))
+            }
+            ProposalType::UpdateConfig(change) => {
+                self.config = self.check_config_change(change)?;
+                self.push_config_version();
+                self.record(SYSTEM_ACTOR, SyndicateEvent::ConfigChanged { change: change.clone() });
+                Ok(format!("Updated {}", change.field()))
+            }
+            ProposalType::ExecuteAction { action_type, .. } => {
+                Ok(format!("Action {} approved for execution", action_type))
+            }
+            ProposalType::DistributeProfits { amount } => {
+                if *amount > self.treasury.pool() {
+                    return Err(SyndicateError::InsufficientTreasuryFunds {
+                        available: self.treasury.pool(),
+                        requested: *amount,
+                    });
+                }
+
+                // The fee portion simply stays in the pool
+                let shares = self.compute_distribution(*amount)?;
+                self.treasury.distribute(&shares)?;
+
+                let distributed: u64 = shares.iter().map(|(_, share)| share).sum();
+                let breakdown: Vec<String> = shares.iter()
+                    .map(|(agent_id, share)| format!("{}={}", agent_id, share))
+                    .collect();
+                Ok(format!(
+                    "Distributed {} (fee {}): {}",
+                    distributed,
+                    amount - distributed,
+                    breakdown.join(", ")
+                ))
+            }
+            ProposalType::Custom { title, .. } | ProposalType::StructuredCustom { title, .. } => {
+                Ok(format!("Custom proposal '{}' accepted", title))
+            }
+        }
+    }
+
+    /// Config that would result from a change, if it is valid
+    ///
+    /// Checked both when the proposal is created and again on execution,
+    /// since membership may have grown in between.
+    fn check_config_change(&self, change: &ConfigChange) -> Result<SyndicateConfig, SyndicateError> {
+        let mut config = self.config.clone();
+        change.apply_to(&mut config)?;
+        config.validate()?;
+
+        if config.max_members < self.occupied_slots() {
+            return Err(SyndicateError::InvalidConfig(format!(
+                "max_members {} is below current member count {}",
+                config.max_members,
+                self.occupied_slots()
+            )));
+        }
+        Ok(config)
+    }
+
+    /// Voting power for a reputation (with no contributions yet)
+    pub fn compute_voting_power(&self, reputation: u32) -> u32 {
+        let reputation_power = self.config.voting_model.voting_power(reputation);
+        self.config.voting_power_formula.power(reputation_power, 0)
+    }
+
+    /// Member's voting power at `now`, after role, formula and decay policy
+    fn current_voting_power(&self, member: &SyndicateMember, now: u64) -> u32 {
+        let base = self.config.member_voting_power(member);
+        let idle = now.saturating_sub(member.last_activity.max(member.joined_at));
+        self.config.decayed_voting_power(base, idle)
+    }
+
+    /// Recompute every member's `voting_power` under the decay policy
+    ///
+    /// Only `voting_power` changes; `reputation` is left alone, so a member
+    /// who becomes active again gets their full power back on the next run.
+    /// Proposals keep the power captured in their snapshot at creation, so
+    /// decay (or renewed activity) only affects proposals created afterwards.
+    pub fn apply_voting_power_decay(&mut self, now: u64) {
+        self.refresh_voting_power(now);
+    }
+
+    /// Recompute every member's `voting_power` from reputation and contributions
+    ///
+    /// Reputation updates and recorded contributions refresh only the
+    /// member concerned; run this, or `apply_voting_power_decay`, to bring
+    /// everyone else's decay up to date.
+    pub fn recalculate_voting_power(&mut self) {
+        let now = self.clock.now();
+        self.refresh_voting_power(now);
+    }
+
+    /// Store one member's current voting power
+    fn refresh_member_voting_power(&mut self, agent_id: &str) {
+        let now = self.clock.now();
+        if let Some(power) = self.members.get(agent_id).map(|m| self.current_voting_power(m, now)) {
+            self.members.get_mut(agent_id).unwrap().voting_power = power;
+        }
+    }
+
+    /// Store each member's current voting power
+    fn refresh_voting_power(&mut self, now: u64) {
+        let powers: Vec<(String, u32)> = self.members.values()
+            .map(|m| (m.agent_id.clone(), self.current_voting_power(m, now)))
+            .collect();
+        for (agent_id, power) in powers {
+            self.members.get_mut(&agent_id).unwrap().voting_power = power;
+        }
+    }
+
+    /// Get member count
+    pub fn member_count(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Get active members
+    pub fn active_members(&self) -> Vec<&SyndicateMember> {
+        self.members.values().filter(|m| m.active).collect()
+    }
+
+    /// Get member by ID
+    pub fn get_member(&self, agent_id: &str) -> Option<&SyndicateMember> {
+        self.members.get(agent_id)
+    }
+
+    /// Get proposal by ID
+    pub fn get_proposal(&self, proposal_id: &str) -> Option<&Proposal> {
+        self.proposals.get(proposal_id)
+    }
+
+    /// Votes on a proposal, oldest first
+    pub fn vote_breakdown(&self, proposal_id: &str) -> Result<Vec<&VoteRecord>, SyndicateError> {
+        let proposal = self.proposals.get(proposal_id)
+            .ok_or_else(|| SyndicateError::ProposalNotFound(proposal_id.to_string()))?;
+        let mut records: Vec<&VoteRecord> = proposal.votes.iter().collect();
+        records.sort_by_key(|r| r.timestamp);
+        Ok(records)
+    }
+
+    /// List active proposals
+    pub fn active_proposals(&self) -> Vec<&Proposal> {
+        self.proposals.values()
+            .filter(|p| p.status == ProposalStatus::Active)
+            .collect()
+    }
+
+    /// List proposals matching a filter, one page at a time
+    ///
+    /// Ordering is by creation time, ties broken by proposal sequence
+    /// number, so paging is stable between calls.
+    pub fn list_proposals(&self, filter: &ProposalFilter) -> ProposalPage<'_> {
+        let mut matching: Vec<&Proposal> = self.proposals.values()
+            .filter(|p| filter.matches(p))
+            .collect();
+        matching.sort_by_key(|p| (p.created_at, p.sequence));
+        if filter.sort == ProposalSort::NewestFirst {
+            matching.reverse();
+        }
+
+        let total = matching.len();
+        let items: Vec<&Proposal> = matching.into_iter()
+            .skip(filter.offset)
+            .take(filter.limit.unwrap_or(usize::MAX))
+            .collect();
+        let end = filter.offset + items.len();
+        let next_offset = (end < total).then_some(end);
+
+        ProposalPage { items, total, next_offset }
+    }
+
+    /// Summarize syndicate performance from member proofs
+    ///
+    /// Proofs that fail verification or don't belong to an active member
+    /// are discarded, and only the newest valid proof per member is kept.
+    /// The combined score averages each active member's proven PnL
+    /// threshold (clamped to 0-10000 bps, scaled to 0-1000), counting
+    /// members without a PnL proof as zero.
+    pub fn aggregate_performance(
+        &self,
+        proofs: &[PerformanceProof],
+        verifier: &ProofVerifier,
+    ) -> SyndicatePerformanceSummary {
+        let mut newest: HashMap<&str, &PerformanceProof> = HashMap::new();
+        let mut discarded = 0;
+
+        for proof in proofs {
+            let is_member = self.members.get(&proof.agent_id).is_some_and(|m| m.active);
+            let valid = verifier.verify(proof).is_ok_and(|r| r.valid);
+            if !is_member || !valid {
+                discarded += 1;
+                continue;
+            }
+            match newest.get(proof.agent_id.as_str()) {
+                Some(kept) if kept.generated_at >= proof.generated_at => discarded += 1,
+                Some(_) => {
+                    discarded += 1;
+                    newest.insert(&proof.agent_id, proof);
+                }
+                None => {
+                    newest.insert(&proof.agent_id, proof);
+                }
+            }
+        }
+
+        let active_members = self.members.values().filter(|m| m.active).count();
+        let proven_members = newest.len();
+
+        let mut pnl_thresholds = BTreeMap::new();
+        let mut score_total: u64 = 0;
+        for proof in newest.values().filter(|p| p.proof_type == ProofType::PnLThreshold) {
+            let threshold = proof.public_inputs.threshold;
+            *pnl_thresholds.entry(threshold).or_insert(0) += 1;
+            score_total += threshold.clamp(0, 10_000) as u64 / 10;
+        }
+
+        let coverage_bps = (proven_members * 10_000).checked_div(active_members).unwrap_or(0) as u32;
+        let combined_score = score_total.checked_div(active_members as u64).unwrap_or(0) as u32;
+
+        SyndicatePerformanceSummary {
+            syndicate_id: self.config.id.clone(),
+            active_members,
+            proven_members,
+            coverage_bps,
+            pnl_thresholds,
+            combined_score,
+            discarded_proofs: discarded,
+            computed_at: self.clock.now(),
+        }
+    }
+
+    /// Absorb another syndicate into this one
+    ///
+    /// Members are carried over, with duplicates resolved by the policy;
+    /// contribution scores of duplicates are added together. The absorbed
+    /// founder becomes an Admin here. Active proposals in the absorbed
+    /// syndicate, and passed ones not yet executed, are cancelled with a
+    /// merge note, taking their membership requests with them; its
+    /// treasury is folded into this one. Vote delegations are carried over
+    /// unless the delegator already delegates here or they'd form a cycle.
+    /// Everything not carried over is listed in the report. Fails without
+    /// changes if the combined membership would exceed `max_members`.
+    pub fn merge_from(&mut self, other: Syndicate, policy: MergePolicy) -> Result<MergeReport, SyndicateError> {
+        let absorbed_id = other.config.id.clone();
+        let mut config = match policy.config {
+            ConfigResolution::KeepSurviving => self.config.clone(),
+            ConfigResolution::TakeAbsorbed => SyndicateConfig {
+                id: self.config.id.clone(),
+                ..other.config.clone()
+            },
+        };
+        config.validate()?;
+
+        let incoming_slots = other.members.values()
+            .filter(|m| m.left_at.is_none() && !self.members.get(&m.agent_id).is_some_and(|e| e.left_at.is_none()))
+            .count();
+        if self.occupied_slots() + incoming_slots > config.max_members {
+            return Err(SyndicateError::MemberLimitReached);
+        }
+        std::mem::swap(&mut self.config, &mut config);
+        if policy.config == ConfigResolution::TakeAbsorbed {
+            self.push_config_version();
+        }
+
+        let mut members_added = Vec::new();
+        let mut members_merged = Vec::new();
+        for (agent_id, mut incoming) in other.members {
+            if incoming.role == MemberRole::Founder {
+                incoming.role = MemberRole::Admin;
+            }
+            let merged = match self.members.remove(&agent_id) {
+                None => {
+                    members_added.push(agent_id.clone());
+                    incoming
+                }
+                Some(existing) => {
+                    members_merged.push(agent_id.clone());
+                    Self::merge_member(existing, incoming, policy.duplicate_members)
+                }
+            };
+            self.members.insert(agent_id, merged);
+        }
+        members_added.sort();
+        members_merged.sort();
+        for member in self.members.values_mut() {
+            member.voting_power = self.config.member_voting_power(member);
+        }
+
+        for (agent_id, chain) in other.delegations {
+            self.delegations.entry(agent_id).or_insert(chain);
+        }
+        self.treasury.absorb(other.treasury);
+
+        let now = self.clock.now();
+        let mut incoming_delegations: Vec<VoteDelegation> = other.vote_delegations.into_values().collect();
+        incoming_delegations.sort_by(|a, b| a.delegator.cmp(&b.delegator));
+        let mut vote_delegations_added = Vec::new();
+        let mut vote_delegations_dropped = Vec::new();
+        for delegation in incoming_delegations {
+            let can_vote = |agent_id: &str| {
+                self.members.get(agent_id).is_some_and(|m| m.active && m.role.can_vote())
+            };
+            let usable = delegation.is_active_at(now)
+                && !self.vote_delegations.contains_key(&delegation.delegator)
+                && can_vote(&delegation.delegator)
+                && can_vote(&delegation.delegatee)
+                && !self.delegation_forms_cycle(&delegation.delegator, &delegation.delegatee, now);
+            if usable {
+                self.vote_delegations.insert(delegation.delegator.clone(), delegation.clone());
+                vote_delegations_added.push(delegation);
+            } else {
+                vote_delegations_dropped.push(delegation);
+            }
+        }
+        let mut dropped_membership_requests: Vec<PendingMembership> = other.pending_memberships.into_values().collect();
+        dropped_membership_requests.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+
+        let mut proposals: Vec<Proposal> = other.proposals.into_values().collect();
+        proposals.sort_by_key(|p| p.sequence);
+
+        let mut cancelled_proposals = Vec::new();
+        let mut imported_proposals = 0;
+        let mut discarded_proposals = Vec::new();
+        for mut proposal in proposals {
+            match proposal.status {
+                ProposalStatus::Active | ProposalStatus::Passed => {
+                    proposal.status = ProposalStatus::Cancelled;
+                    proposal.execution_result = Some(format!("Cancelled by merge into {}", self.config.id));
+                    cancelled_proposals.push(proposal);
+                }
+                ProposalStatus::Executed if policy.import_history => {
+                    imported_proposals += 1;
+                    self.record(SYSTEM_ACTOR, SyndicateEvent::ProposalImported {
+                        source_syndicate: absorbed_id.clone(),
+                        proposal_id: proposal.id,
+                        result: proposal.execution_result,
+                    });
+                }
+                _ => discarded_proposals.push(proposal.id),
+            }
+        }
+
+        self.record(SYSTEM_ACTOR, SyndicateEvent::SyndicateMerged { absorbed_id: absorbed_id.clone() });
+        Ok(MergeReport {
+            absorbed_id,
+            members_added,
+            members_merged,
+            cancelled_proposals,
+            imported_proposals,
+            discarded_proposals,
+            vote_delegations_added,
+            vote_delegations_dropped,
+            dropped_membership_requests,
+        })
+    }
+
+    /// Combine the two records of a member present in both merged syndicates
+    fn merge_member(
+        existing: SyndicateMember,
+        incoming: SyndicateMember,
+        resolution: DuplicateMemberResolution,
+    ) -> SyndicateMember {
+        let take_incoming = match resolution {
+            DuplicateMemberResolution::KeepHigherReputation => incoming.reputation > existing.reputation,
+            DuplicateMemberResolution::KeepExisting => false,
+        };
+        let role = if incoming.role.rank() > existing.role.rank() { incoming.role } else { existing.role };
+        let active = existing.active || incoming.active;
+        let left_at = if existing.left_at.is_none() || incoming.left_at.is_none() { None } else { existing.left_at };
+        let contribution_score = existing.contribution_score + incoming.contribution_score;
+        let joined_at = existing.joined_at.min(incoming.joined_at);
+        let last_activity = existing.last_activity.max(incoming.last_activity);
+
+        let base = if take_incoming { incoming } else { existing };
+        SyndicateMember {
+            role,
+            active,
+            left_at,
+            contribution_score,
+            joined_at,
+            last_activity,
+            ..base
+        }
+    }
+
+    /// Update member reputation
+    pub fn update_reputation(
+        &mut self,
+        agent_id: &str,
+        delta: i32,
+    ) -> Result<u32, SyndicateError> {
+        let member = self.members.get_mut(agent_id)
+            .ok_or_else(|| SyndicateError::NotMember(agent_id.to_string()))?;
+
+        let old_rep = member.reputation;
+        let new_rep = (member.reputation as i32 + delta).clamp(0, 1000) as u32;
+        member.reputation = new_rep;
+        self.refresh_member_voting_power(agent_id);
+
+        self.record(SYSTEM_ACTOR, SyndicateEvent::ReputationUpdated {
+            agent_id: agent_id.to_string(),
+            old: old_rep,
+            new: new_rep,
+        });
+        Ok(new_rep)
+    }
+
+    /// Record contribution
+    ///
+    /// The syndicate fee is taken into the treasury and the member is
+    /// credited with the rest. The fee is rounded down, so any fraction
+    /// stays with the member. For trusted callers; agents reporting their
+    /// own contributions go through `record_contribution_signed`.
+    pub fn record_contribution(
+        &mut self,
+        agent_id: &str,
+        amount: u64,
+    ) -> Result<(), SyndicateError> {
+        self.credit_contribution(agent_id, amount, None)
+    }
+
+    /// Record a contribution reported and signed by the member
+    ///
+    /// The public key must be the member's own (its SHA-256 is the agent
+    /// ID) and each nonce is accepted once per member. Reports timestamped
+    /// more than ten minutes from the current time are rejected, so nonces
+    /// are only kept that long. The audit log entry is attributed to the
+    /// member and carries the nonce.
+    pub fn record_contribution_signed(
+        &mut self,
+        report: &ContributionReport,
+        public_key: &[u8; 32],
+    ) -> Result<(), SyndicateError> {
+        if !key_belongs_to(public_key, &report.agent_id) {
+            return Err(SyndicateError::ReportKeyMismatch(report.agent_id.clone()));
+        }
+        if !self.members.contains_key(&report.agent_id) {
+            return Err(SyndicateError::NotMember(report.agent_id.clone()));
+        }
+        report.verify(public_key)
+            .map_err(|_| SyndicateError::InvalidReportSignature)?;
+        let now = self.clock.now();
+        if report.timestamp.abs_diff(now) > CONTRIBUTION_WINDOW_SECS {
+            return Err(SyndicateError::ReportOutOfWindow { timestamp: report.timestamp, now });
+        }
+        // A nonce older than the window can't be replayed, so stop keeping it
+        let cutoff = now.saturating_sub(CONTRIBUTION_WINDOW_SECS);
+        self.contribution_nonces.retain(|_, nonces| {
+            nonces.retain(|_, &mut timestamp| timestamp >= cutoff);
+            !nonces.is_empty()
+        });
+        if self.contribution_nonces.get(&report.agent_id).is_some_and(|n| n.contains_key(&report.nonce)) {
+            return Err(SyndicateError::NonceReused(report.nonce));
+        }
+
+        self.credit_contribution(&report.agent_id, report.amount, Some(report.nonce))?;
+        self.contribution_nonces.entry(report.agent_id.clone()).or_default().insert(report.nonce, report.timestamp);
+        Ok(())
+    }
+
+    /// Credit a contribution net of the syndicate fee
+    fn credit_contribution(&mut self, agent_id: &str, amount: u64, nonce: Option<u64>) -> Result<(), SyndicateError> {
+        let fee = self.fee_on(amount);
+        if !self.members.contains_key(agent_id) {
+            return Err(SyndicateError::NotMember(agent_id.to_string()));
+        }
+        self.treasury.accrue_fee(fee)?;
+
+        let member = self.members.get_mut(agent_id).unwrap();
+        member.contribution_score = member.contribution_score.saturating_add(amount - fee);
+        member.last_activity = self.clock.now();
+        self.refresh_member_voting_power(agent_id);
+
+        let actor = if nonce.is_some() { agent_id } else { SYSTEM_ACTOR };
+        self.record(actor, SyndicateEvent::ContributionRecorded {
+            agent_id: agent_id.to_string(),
+            amount,
+            fee,
+            nonce,
+        });
+        Ok(())
+    }
+
+    /// Total fees taken from contributions
+    pub fn total_fees_collected(&self) -> u64 {
+        self.treasury.fees_accrued()
+    }
+
+    /// Syndicate fee owed on an amount, rounded down
+    fn fee_on(&self, amount: u64) -> u64 {
+        (amount as u128 * self.config.syndicate_fee_bps as u128 / 10000) as u64
+    }
+
+    /// Split a profit amount across active members
+    ///
+    /// The syndicate fee is skimmed first; the rest is shared pro-rata by
+    /// `contribution_score`, or equally if nobody has contributed. Shares
+    /// are rounded down and the remainder goes to the highest contributor
+    /// (lowest agent ID on ties), so the shares always sum to the amount
+    /// after fees. Results are sorted by agent ID.
+    pub fn compute_distribution(&self, amount: u64) -> Result<Vec<(String, u64)>, SyndicateError> {
+        let mut recipients: Vec<&SyndicateMember> = self.members.values()
+            .filter(|m| m.active)
+            .collect();
+        if recipients.is_empty() {
+            return Err(SyndicateError::NoActiveMembers);
+        }
+        recipients.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+
+        let fee = self.fee_on(amount);
+        let distributable = amount - fee;
+
+        let total_score: u128 = recipients.iter().map(|m| m.contribution_score as u128).sum();
+        let weight = |m: &SyndicateMember| -> u128 {
+            if total_score == 0 { 1 } else { m.contribution_score as u128 }
+        };
+        let total_weight = if total_score == 0 { recipients.len() as u128 } else { total_score };
+
+        let mut shares: Vec<(String, u64)> = recipients.iter()
+            .map(|m| {
+                let share = distributable as u128 * weight(m) / total_weight;
+                (m.agent_id.clone(), share as u64)
+            })
+            .collect();
+
+        let assigned: u64 = shares.iter().map(|(_, share)| share).sum();
+        let top = recipients.iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                a.contribution_score.cmp(&b.contribution_score)
+                    .then_with(|| b.agent_id.cmp(&a.agent_id))
+            })
+            .map(|(i, _)| i)
+            .unwrap();
+        shares[top].1 += distributable - assigned;
+
+        Ok(shares)
+    }
+
+    /// Get treasury ledger
+    pub fn treasury(&self) -> &Treasury {
+        &self.treasury
+    }
+
+    /// Deposit funds into the treasury
+    pub fn deposit(&mut self, agent_id: &str, amount: u64) -> Result<(), SyndicateError> {
+        if !self.members.contains_key(agent_id) {
+            return Err(SyndicateError::NotMember(agent_id.to_string()));
+        }
+        self.treasury.deposit(agent_id, amount)?;
+        self.record(agent_id, SyndicateEvent::Deposited { amount });
+        Ok(())
+    }
+
+    /// Withdraw from a member's claimable balance
+    ///
+    /// Returns the remaining balance.
+    pub fn withdraw(&mut self, agent_id: &str, amount: u64) -> Result<u64, SyndicateError> {
+        let remaining = self.treasury.withdraw(agent_id, amount)?;
+        self.record(agent_id, SyndicateEvent::Withdrawn { amount });
+        Ok(remaining)
+    }
+
+    /// Set delegation chain for agent
+    pub fn set_delegation(&mut self, agent_id: String, chain: DelegationChain) {
+        self.delegations.insert(agent_id.clone(), chain);
+        self.record(SYSTEM_ACTOR, SyndicateEvent::DelegationChainSet { agent_id });
+    }
+
+    /// Get delegation chain
+    pub fn get_delegation(&self, agent_id: &str) -> Option<&DelegationChain> {
+        self.delegations.get(agent_id)
+    }
+
+    /// Full audit log, oldest first
+    pub fn events(&self) -> &VecDeque<SyndicateEventRecord> {
+        &self.events
+    }
+
+    /// Audit log entries at or after a timestamp
+    pub fn events_since(&self, timestamp: u64) -> Vec<&SyndicateEventRecord> {
+        self.events.iter().filter(|e| e.timestamp >= timestamp).collect()
+    }
+
+    /// Audit log entries an agent acted in or is the subject of
+    pub fn events_for_agent(&self, agent_id: &str) -> Vec<&SyndicateEventRecord> {
+        self.events.iter().filter(|e| e.involves(agent_id)).collect()
+    }
+
+    /// Write proposals, member joins, and role changes as JSON Lines
+    ///
+    /// One object per line, ordered by timestamp then agent or proposal ID,
+    /// so exporting the same state always gives the same bytes. Role
+    /// changes are limited to what the audit log still retains.
+    pub fn export_history<W: std::io::Write>(&self, writer: W) -> Result<(), SyndicateError> {
+        let entries = history::collect(self.members.values(), self.proposals.values(), &self.events);
+        history::write_entries(writer, &entries)
+    }
+
+    /// Rebuild read-only history from an `export_history` stream
+    pub fn import_history<R: std::io::BufRead>(reader: R) -> Result<GovernanceHistory, SyndicateError> {
+        GovernanceHistory::read(reader)
+    }
+
+    /// Append to the audit log, dropping the oldest entries past the cap
+    fn record(&mut self, actor: &str, event: SyndicateEvent) {
+        let timestamp = self.clock.now();
+
+        self.events.push_back(SyndicateEventRecord {
+            event,
+            actor: actor.to_string(),
+            timestamp,
+        });
+        while self.events.len() > self.config.max_events {
+            self.events.pop_front();
+        }
+    }
+}
+
+/// Whether `agent_id` is the one derived from `public_key`, as `SDKeyId` is
+fn key_belongs_to(public_key: &[u8; 32], agent_id: &str) -> bool {
+    hex::encode(Sha256::digest(public_key)) == agent_id
+}
+
+/// Syndicate errors
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SyndicateError {
+    #[error("Agent is already a member: {0}")]
+    AlreadyMember(String),
+
+    #[error("Agent is not a member: {0}")]
+    NotMember(String),
+
+    #[error("Member is inactive")]
+    MemberInactive,
+
+    #[error("Member limit reached")]
+    MemberLimitReached,
+
+    #[error("Agent is banned{}", until.map(|t| format!(" until {}", t)).unwrap_or_default())]
+    Banned { until: Option<u64> },
+
+    #[error("Agent is not banned: {0}")]
+    NotBanned(String),
+
+    #[error("Insufficient reputation: required {required}, actual {actual}")]
+    InsufficientReputation { required: u32, actual: u32 },
+
+    #[error("Performance proof required")]
+    ProofRequired,
+
+    #[error("Signing failed: {0}")]
+    Signing(#[from] SDKeyError),
+
+    #[error("Failed to write history: {0}")]
+    HistoryWrite(String),
+
+    #[error("Malformed history at line {line}: {reason}")]
+    MalformedHistory { line: usize, reason: String },
+
+    #[error("Invite signature is invalid")]
+    ForgedInvite,
+
+    #[error("Invite is for a different syndicate or agent")]
+    InviteNotApplicable,
+
+    #[error("Invite expired at {expires_at}")]
+    InviteExpired { expires_at: u64 },
+
+    #[error("Invite has already been used")]
+    InviteAlreadyUsed,
+
+    #[error("Invalid performance proof: {0}")]
+    InvalidProof(String),
+
+    #[error("Proof generated at {provided} is older than the current one from {current}")]
+    OutdatedProof { current: u64, provided: u64 },
+
+    #[error("Proof belongs to {actual}, not {expected}")]
+    ProofAgentMismatch { expected: String, actual: String },
+
+    #[error("Membership needs a PnL lower-bound proof, got {0:?}")]
+    WrongProofType(ProofType),
+
+    #[error("Proven PnL threshold {proven} bps is below the required {required} bps")]
+    InsufficientPerformance { required: i64, proven: i64 },
+
+    #[error("Proposal not found: {0}")]
+    ProposalNotFound(String),
+
+    #[error("Voting is closed")]
+    VotingClosed,
+
+    #[error("Voting is still open")]
+    VotingStillOpen,
+
+    #[error("Already voted on this proposal")]
+    AlreadyVoted,
+
+    #[error("Founder already set")]
+    FounderAlreadySet,
+
+    #[error("Permission denied")]
+    PermissionDenied,
+
+    #[error("Membership already requested: {0}")]
+    MembershipAlreadyRequested(String),
+
+    #[error("No pending membership for proposal {0}")]
+    PendingMembershipMissing(String),
+
+    #[error("Voter has not voted on this proposal")]
+    NotVoted,
+
+    #[error("Vote already cast by delegate: {0}")]
+    VotedByDelegate(String),
+
+    #[error("Delegation would create a cycle")]
+    CircularDelegation,
+
+    #[error("Delegation expiry must be in the future")]
+    InvalidDelegationExpiry,
+
+    #[error("Rejoin cooldown active until {until}")]
+    CooldownActive { until: u64 },
+
+    #[error("Founder cannot leave while other members remain")]
+    FounderCannotLeave,
+
+    #[error("Signed vote is for proposal {actual}, expected {expected}")]
+    VoteProposalMismatch { expected: String, actual: String },
+
+    #[error("No vote delegation from {0}")]
+    DelegationNotFound(String),
+
+    #[error("Invalid vote signature")]
+    InvalidVoteSignature,
+
+    #[error("Invalid contribution report signature")]
+    InvalidReportSignature,
+
+    #[error("Public key does not belong to {0}")]
+    ReportKeyMismatch(String),
+
+    #[error("Report nonce already used: {0}")]
+    NonceReused(u64),
+
+    #[error("Contribution report signed at {timestamp} is too far from the current time {now}")]
+    ReportOutOfWindow { timestamp: u64, now: u64 },
+
+    #[error("Vote signed at {signed_at}, before proposal was created at {created_at}")]
+    StaleVote { signed_at: u64, created_at: u64 },
+
+    #[error("Proposal {0} is not an ExecuteAction")]
+    NotAnAction(String),
+
+    #[error("Invalid action signature")]
+    InvalidActionSignature,
+
+    #[error("Public key does not belong to signer {0}")]
+    SignerKeyMismatch(String),
+
+    #[error("{0} was not an active member when the proposal was created")]
+    NotInPowerSnapshot(String),
+
+    #[error("Proposal rate limit reached, retry after {retry_after}")]
+    ProposalRateLimited { retry_after: u64 },
+
+    #[error("Cannot veto a proposal that is {0:?}")]
+    CannotVeto(ProposalStatus),
+
+    #[error("Veto limit reached for this period")]
+    VetoLimitReached,
+
+    #[error("Syndicate is invite-only")]
+    InvitationRequired,
+
+    #[error("RemoveMember proposals require evidence")]
+    EvidenceRequired,
+
+    #[error("Evidence item {0} is invalid")]
+    InvalidEvidence(usize),
+
+    #[error("Observers cannot vote")]
+    ObserverCannotVote,
+
+    #[error("Cannot remove the last founder")]
+    LastFounder,
+
+    #[error("Proposal {0} appears more than once in the batch")]
+    DuplicateBatchVote(String),
+
+    #[error("Proposal can't be amended once voting has started")]
+    AmendmentAfterVoting,
+
+    #[error("Proposal ID already in use: {0}")]
+    ProposalIdCollision(String),
+
+    #[error("Invalid proposal payload: {0}")]
+    InvalidPayload(String),
+
+    #[error("Proposal payload is {size} bytes, limit is {max}")]
+    PayloadTooLarge { size: usize, max: usize },
+
+    #[error("Dependency not found: {0}")]
+    UnknownDependency(String),
+
+    #[error("Dependency cycle through proposal {0}")]
+    DependencyCycle(String),
+
+    #[error("Dependency not yet executed: {0}")]
+    DependencyNotExecuted(String),
+
+    #[error("Proposal is not active (status: {0:?})")]
+    ProposalNotActive(ProposalStatus),
+
+    #[error("Proposal has not passed (status: {0:?})")]
+    ProposalNotPassed(ProposalStatus),
+
+    #[error("Proposal is timelocked until {until}")]
+    TimelockActive { until: u64 },
+
+    #[error("Insufficient balance: available {available}, requested {requested}")]
+    InsufficientBalance { available: u64, requested: u64 },
+
+    #[error("Insufficient treasury funds: available {available}, requested {requested}")]
+    InsufficientTreasuryFunds { available: u64, requested: u64 },
+
+    #[error("Treasury total would overflow adding {0}")]
+    TreasuryOverflow(u64),
+
+    #[error("Syndicate has no active members")]
+    NoActiveMembers,
+
+    #[error("Inconsistent snapshot: {0}")]
+    InconsistentSnapshot(String),
+
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
+
+    #[error("Legacy config change for field {0} cannot be executed; resubmit as a typed change")]
+    LegacyConfigChange(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+
+    #[test]
+    fn test_syndicate_creation() {
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            name: "Test Syndicate".into(),
+            ..Default::default()
+        };
+
+        let mut syndicate = Syndicate::new(config).unwrap();
+        syndicate.add_founder("founder-agent".into()).unwrap();
+
+        assert_eq!(syndicate.member_count(), 1);
+        
+        let founder = syndicate.get_member("founder-agent").unwrap();
+        assert_eq!(founder.role, MemberRole::Founder);
+    }
+
+    #[test]
+    fn test_voting() {
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            proposal_duration: 60,
+            ..Default::default()
+        };
+
+        let clock = MockClock::new(1_000);
+        let mut syndicate = Syndicate::new(config).unwrap().with_clock(clock.clone());
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate.add_member("member1".into(), 500).unwrap();
+        syndicate.add_member("member2".into(), 500).unwrap();
+
+        // Create proposal
+        let proposal = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom {
+                title: "Test".into(),
+                description: "Test proposal".into(),
+            },
+        ).unwrap();
+        let proposal_id = proposal.id.clone();
+
+        // Vote
+        syndicate.vote(&proposal_id, "founder", VoteChoice::For).unwrap();
+        syndicate.vote(&proposal_id, "member1", VoteChoice::For).unwrap();
+        syndicate.vote(&proposal_id, "member2", VoteChoice::Against).unwrap();
+
+        assert!(matches!(
+            syndicate.finalize_proposal(&proposal_id),
+            Err(SyndicateError::VotingStillOpen)
+        ));
+
+        // Move past the deadline
+        clock.advance(61);
 
         // Finalize
         let status = syndicate.finalize_proposal(&proposal_id).unwrap();
         assert_eq!(status, ProposalStatus::Passed); // 1500 for vs 500 against
     }
+
+    fn test_syndicate() -> Syndicate {
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap();
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate
+    }
+
+    /// Create a proposal, vote it through as founder, and finalize it
+    fn pass_proposal(syndicate: &mut Syndicate, proposal_type: ProposalType) -> String {
+        let id = syndicate.create_proposal("founder".into(), proposal_type).unwrap().id.clone();
+        pass_existing(syndicate, &id);
+        id
+    }
+
+    fn pass_existing(syndicate: &mut Syndicate, id: &str) {
+        syndicate.vote(id, "founder", VoteChoice::For).unwrap();
+        syndicate.proposals.get_mut(id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(id).unwrap(), ProposalStatus::Passed);
+    }
+
+    /// Custom proposal with no effects
+    fn custom() -> ProposalType {
+        ProposalType::Custom { title: "T".into(), description: String::new() }
+    }
+
+    fn test_proof(agent_id: &str) -> PerformanceProof {
+        let mut metrics = zk_proofs::PerformanceMetrics::new(
+            agent_id.into(),
+            zk_proofs::PerformancePeriod::Monthly,
+        );
+        metrics.pnl_bps = 500;
+        PerformanceProof::prove_pnl_threshold(&metrics, 0).unwrap()
+    }
+
+    /// Request membership for an agent and vote the proposal through
+    fn request_and_pass(syndicate: &mut Syndicate, agent_id: &str, reputation: u32) -> String {
+        let proof = test_proof(agent_id);
+        let id = syndicate.request_membership(agent_id.into(), reputation, Some(&proof), None).unwrap();
+        pass_existing(syndicate, &id);
+        id
+    }
+
+    #[test]
+    fn test_update_member_proof() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        syndicate.add_member("bob".into(), 500).unwrap();
+        let verifier = ProofVerifier::new();
+        let ids = |members: Vec<&SyndicateMember>| members.iter().map(|m| m.agent_id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(syndicate.members_with_stale_proofs(3600)), ["alice", "bob", "founder"]);
+
+        let proof = test_proof("alice");
+        assert!(matches!(
+            syndicate.update_member_proof("bob", &proof, &verifier),
+            Err(SyndicateError::ProofAgentMismatch { .. })
+        ));
+        syndicate.update_member_proof("alice", &proof, &verifier).unwrap();
+        let alice = syndicate.get_member("alice").unwrap();
+        assert_eq!(alice.performance_proof.as_deref(), Some(proof.id.as_str()));
+        assert_eq!((alice.proof_generated_at, alice.proof_expires_at), (Some(proof.generated_at), proof.expires_at));
+        assert!(alice.proof_updated_at.is_some());
+        assert_eq!(ids(syndicate.members_with_stale_proofs(3600)), ["bob", "founder"]);
+
+        // A proof older than the stored one is refused
+        syndicate.members.get_mut("alice").unwrap().proof_generated_at = Some(proof.generated_at + 1);
+        assert!(matches!(
+            syndicate.update_member_proof("alice", &proof, &verifier),
+            Err(SyndicateError::OutdatedProof { .. })
+        ));
+
+        syndicate.members.get_mut("alice").unwrap().proof_expires_at = Some(0);
+        assert_eq!(ids(syndicate.members_with_stale_proofs(3600)), ["alice", "bob", "founder"]);
+    }
+
+    #[test]
+    fn test_quorum_excludes_proposer() {
+        let mut syndicate = test_syndicate();
+        syndicate.config.proposal_cooldown_secs = 0;
+        syndicate.config.quorum_bps = 5000;
+        syndicate.add_member("alice".into(), 500).unwrap();
+
+        // The founder alone carries quorum by default
+        let id = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        pass_existing(&mut syndicate, &id);
+
+        syndicate.config.quorum_excludes_proposer = true;
+        let id = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+        assert_eq!(syndicate.try_early_finalize(&id).unwrap(), None);
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Rejected);
+
+        // Another member's participation brings it to quorum; the proposer's vote still counts
+        let id = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+        syndicate.vote(&id, "alice", VoteChoice::Against).unwrap();
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Passed);
+
+        // Nothing is settled early while the proposer could still swing it
+        let id = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        let proposal = syndicate.proposals.get_mut(&id).unwrap();
+        proposal.power_snapshot.insert("founder".into(), 700);
+        proposal.power_snapshot.insert("alice".into(), 300);
+        syndicate.vote(&id, "alice", VoteChoice::For).unwrap();
+        assert_eq!(syndicate.try_early_finalize(&id).unwrap(), None);
+        syndicate.vote(&id, "founder", VoteChoice::Against).unwrap();
+        assert_eq!(syndicate.try_early_finalize(&id).unwrap(), Some(ProposalStatus::Rejected));
+    }
+
+    #[test]
+    fn test_try_early_finalize() {
+        let mut syndicate = test_syndicate();
+        syndicate.config.quorum_bps = 5000;
+        syndicate.config.voting_threshold_bps = 6000;
+        for agent in ["a", "b", "c", "d"] {
+            syndicate.add_member(agent.into(), 500).unwrap();
+        }
+        let open = |syndicate: &mut Syndicate| {
+            let id = syndicate.create_proposal("founder".into(), ProposalType::Custom {
+                title: "Early".into(),
+                description: String::new(),
+            }).unwrap().id.clone();
+            let proposal = syndicate.proposals.get_mut(&id).unwrap();
+            proposal.power_snapshot.values_mut().for_each(|power| *power = 100);
+            id
+        };
+
+        // Short of quorum while the rest could still reach it
+        let id = open(&mut syndicate);
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+        syndicate.vote(&id, "a", VoteChoice::For).unwrap();
+        assert_eq!(syndicate.try_early_finalize(&id).unwrap(), None);
+
+        // 300 for out of 500: even 200 against keeps it at the 60% threshold
+        syndicate.vote(&id, "b", VoteChoice::For).unwrap();
+        assert_eq!(syndicate.try_early_finalize(&id).unwrap(), Some(ProposalStatus::Passed));
+        assert!(syndicate.get_proposal(&id).unwrap().early_finalized);
+        assert!(matches!(syndicate.vote(&id, "c", VoteChoice::Against), Err(SyndicateError::VotingClosed)));
+
+        // 300 against out of 500: at most 40% can vote for
+        let id = open(&mut syndicate);
+        for agent in ["a", "b"] {
+            syndicate.vote(&id, agent, VoteChoice::Against).unwrap();
+        }
+        assert_eq!(syndicate.try_early_finalize(&id).unwrap(), None);
+        syndicate.vote(&id, "c", VoteChoice::Against).unwrap();
+        assert_eq!(syndicate.try_early_finalize(&id).unwrap(), Some(ProposalStatus::Rejected));
+        assert!(syndicate.get_proposal(&id).unwrap().early_finalized);
+    }
+
+    #[test]
+    fn test_pending_admissions_reserve_slots() {
+        const APPLICANTS: usize = 6;
+        let mut syndicate = test_syndicate();
+        // Founder plus N-2 newcomers
+        syndicate.config.max_members = 1 + APPLICANTS - 2;
+
+        let results: Vec<_> = (0..APPLICANTS)
+            .map(|i| {
+                let agent = format!("agent-{}", i);
+                syndicate.request_membership(agent.clone(), 450, Some(&test_proof(&agent)), None)
+            })
+            .collect();
+        let ids: Vec<String> = results.iter().filter_map(|r| r.as_ref().ok().cloned()).collect();
+        assert_eq!(ids.len(), APPLICANTS - 2);
+        assert!(results[APPLICANTS - 2..].iter().all(|r| matches!(r, Err(SyndicateError::MemberLimitReached))));
+        assert_eq!(syndicate.pending_admissions(), APPLICANTS - 2);
+
+        // Cancelling and rejecting both release the reservation
+        syndicate.cancel_proposal("founder", &ids[0]).unwrap();
+        syndicate.proposals.get_mut(&ids[1]).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&ids[1]).unwrap(), ProposalStatus::Rejected);
+        assert_eq!(syndicate.pending_admissions(), APPLICANTS - 4);
+        let late: Vec<String> = (APPLICANTS..APPLICANTS + 3)
+            .filter_map(|i| {
+                let agent = format!("agent-{}", i);
+                syndicate.request_membership(agent.clone(), 450, Some(&test_proof(&agent)), None).ok()
+            })
+            .collect();
+        assert_eq!(late.len(), 2);
+
+        for id in ids[2..].iter().chain(&late) {
+            pass_existing(&mut syndicate, id);
+            syndicate.execute_proposal(id).unwrap();
+        }
+        assert_eq!(syndicate.member_count(), 1 + APPLICANTS - 2);
+        assert_eq!(syndicate.pending_admissions(), 0);
+    }
+
+    #[test]
+    fn test_execute_membership_proposals() {
+        let mut syndicate = test_syndicate();
+
+        let id = request_and_pass(&mut syndicate, "agent-a", 450);
+        syndicate.execute_proposal(&id).unwrap();
+        assert_eq!(syndicate.get_proposal(&id).unwrap().status, ProposalStatus::Executed);
+        assert_eq!(syndicate.get_member("agent-a").unwrap().reputation, 450);
+        assert!(syndicate.pending_memberships().is_empty());
+
+        // Executing twice is refused
+        assert!(matches!(
+            syndicate.execute_proposal(&id),
+            Err(SyndicateError::ProposalNotPassed(ProposalStatus::Executed))
+        ));
+
+        let id = pass_proposal(&mut syndicate, ProposalType::RemoveMember { agent_id: "agent-a".into(), evidence: vec![] });
+        syndicate.execute_proposal(&id).unwrap();
+        assert!(syndicate.get_member("agent-a").is_none());
+    }
+
+    #[test]
+    fn test_pending_membership_admission() {
+        let mut syndicate = test_syndicate();
+        let proof = test_proof("agent-a");
+
+        let id = syndicate.request_membership("agent-a".into(), 600, Some(&proof), None).unwrap();
+        assert!(matches!(
+            syndicate.request_membership("agent-a".into(), 600, Some(&proof), None),
+            Err(SyndicateError::MembershipAlreadyRequested(_))
+        ));
+        let pending = &syndicate.pending_memberships()[&id];
+        assert_eq!(pending.agent_id, "agent-a");
+        assert_eq!(pending.proof_id.as_deref(), Some(proof.id.as_str()));
+
+        pass_existing(&mut syndicate, &id);
+        syndicate.execute_proposal(&id).unwrap();
+        let member = syndicate.get_member("agent-a").unwrap();
+        assert_eq!(member.reputation, 600);
+        assert_eq!(member.performance_proof.as_deref(), Some(proof.id.as_str()));
+
+        // Rejected requests leave the queue
+        let proof = test_proof("agent-b");
+        let id = syndicate.request_membership("agent-b".into(), 600, Some(&proof), None).unwrap();
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Rejected);
+        assert!(syndicate.pending_memberships().is_empty());
+    }
+
+    #[test]
+    fn test_add_member_without_pending_record_fails() {
+        let mut syndicate = test_syndicate();
+        let id = pass_proposal(&mut syndicate, ProposalType::AddMember { agent_id: "agent-a".into() });
+
+        assert!(matches!(
+            syndicate.execute_proposal(&id),
+            Err(SyndicateError::PendingMembershipMissing(_))
+        ));
+        assert_eq!(syndicate.get_proposal(&id).unwrap().status, ProposalStatus::ExecutionFailed);
+        assert!(syndicate.get_member("agent-a").is_none());
+    }
+
+    #[test]
+    fn test_remove_member() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("member1".into(), 500).unwrap();
+        syndicate.add_member("member2".into(), 400).unwrap();
+        syndicate.set_delegation("member1".into(), DelegationChain::new());
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+        syndicate.vote(&id, "member1", VoteChoice::For).unwrap();
+        syndicate.vote(&id, "member2", VoteChoice::Against).unwrap();
+
+        let record = syndicate.remove_member("member1", "inactive").unwrap();
+        assert_eq!(record.reason, "inactive");
+        assert!(syndicate.get_member("member1").is_none());
+        assert!(syndicate.get_delegation("member1").is_none());
+
+        // Removed member's vote no longer counts
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.votes_for, 0);
+        assert_eq!(proposal.votes_against, 400);
+        assert!(!proposal.voters.contains_key("member1"));
+
+        assert!(matches!(
+            syndicate.remove_member("member1", "again"),
+            Err(SyndicateError::NotMember(_))
+        ));
+        assert!(matches!(
+            syndicate.remove_member("founder", "coup"),
+            Err(SyndicateError::LastFounder)
+        ));
+
+        assert_eq!(syndicate.removed_members().len(), 1);
+        assert_eq!(syndicate.removed_members()[0].agent_id, "member1");
+    }
+
+    #[test]
+    fn test_role_transitions() {
+        use MemberRole::*;
+
+        let roles = [Observer, Member, Approver, Admin, Founder];
+        for actor_role in roles {
+            for from in roles {
+                for to in roles {
+                    let mut syndicate = test_syndicate();
+                    syndicate.add_member("actor".into(), 500).unwrap();
+                    syndicate.add_member("target".into(), 500).unwrap();
+                    syndicate.members.get_mut("actor").unwrap().role = actor_role;
+                    syndicate.members.get_mut("target").unwrap().role = from;
+
+                    let legal = from != Founder
+                        && to != Founder
+                        && match actor_role {
+                            Founder => true,
+                            Admin => {
+                                matches!(from, Observer | Member | Approver)
+                                    && matches!(to, Observer | Member | Approver)
+                            }
+                            _ => false,
+                        };
+
+                    let result = syndicate.set_role("actor", "target", to);
+                    if legal {
+                        assert_eq!(result.unwrap(), from, "{:?}: {:?} -> {:?}", actor_role, from, to);
+                        assert_eq!(syndicate.get_member("target").unwrap().role, to);
+                    } else {
+                        assert!(
+                            matches!(result, Err(SyndicateError::PermissionDenied)),
+                            "{:?}: {:?} -> {:?}", actor_role, from, to,
+                        );
+                        assert_eq!(syndicate.get_member("target").unwrap().role, from);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_role_restrictions() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("admin".into(), 500).unwrap();
+        syndicate.set_role("founder", "admin", MemberRole::Admin).unwrap();
+
+        // Cannot change own role
+        assert!(matches!(
+            syndicate.set_role("admin", "admin", MemberRole::Member),
+            Err(SyndicateError::PermissionDenied)
+        ));
+        assert!(matches!(
+            syndicate.set_role("founder", "founder", MemberRole::Admin),
+            Err(SyndicateError::PermissionDenied)
+        ));
+
+        // Founder cannot be demoted
+        assert!(matches!(
+            syndicate.set_role("admin", "founder", MemberRole::Member),
+            Err(SyndicateError::PermissionDenied)
+        ));
+
+        assert!(matches!(
+            syndicate.set_role("outsider", "admin", MemberRole::Member),
+            Err(SyndicateError::NotMember(_))
+        ));
+    }
+
+    #[test]
+    fn test_cancel_proposal() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("member1".into(), 500).unwrap();
+        syndicate.add_member("member2".into(), 500).unwrap();
+
+        let id = syndicate.create_proposal(
+            "member1".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+
+        // Unrelated regular member cannot cancel
+        assert!(matches!(
+            syndicate.cancel_proposal("member2", &id),
+            Err(SyndicateError::PermissionDenied)
+        ));
+
+        // Proposer can
+        syndicate.cancel_proposal("member1", &id).unwrap();
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Cancelled);
+        assert_eq!(proposal.votes_for, 1000);
+        assert!(syndicate.active_proposals().is_empty());
+
+        // Cancelled proposals take no more votes and stay cancelled
+        assert!(matches!(syndicate.vote(&id, "member2", VoteChoice::For), Err(SyndicateError::VotingClosed)));
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Cancelled);
+        assert!(matches!(
+            syndicate.cancel_proposal("founder", &id),
+            Err(SyndicateError::ProposalNotActive(ProposalStatus::Cancelled))
+        ));
+    }
+
+    #[test]
+    fn test_cancel_finalized_proposal() {
+        let mut syndicate = test_syndicate();
+        let id = pass_proposal(&mut syndicate, ProposalType::Custom {
+            title: "Test".into(),
+            description: String::new(),
+        });
+
+        assert!(matches!(
+            syndicate.cancel_proposal("founder", &id),
+            Err(SyndicateError::ProposalNotActive(ProposalStatus::Passed))
+        ));
+
+        syndicate.execute_proposal(&id).unwrap();
+        assert!(matches!(
+            syndicate.cancel_proposal("founder", &id),
+            Err(SyndicateError::ProposalNotActive(ProposalStatus::Executed))
+        ));
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let config = SyndicateConfig {
+            quorum_bps: 10_001,
+            ..Default::default()
+        };
+        assert!(matches!(Syndicate::new(config), Err(SyndicateError::InvalidConfig(_))));
+
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        for change in [
+            ConfigChange::SetQuorumBps(20_000),
+            ConfigChange::SetSyndicateFeeBps(10_001),
+            ConfigChange::SetMaxMembers(1),
+            ConfigChange::Legacy { field: "quorum_bps".into(), value: "3000".into() },
+        ] {
+            assert!(syndicate.create_proposal("founder".into(), ProposalType::UpdateConfig(change)).is_err());
+        }
+        assert_eq!(syndicate.config().quorum_bps, 2000);
+
+        // Re-checked at execution time
+        let id = pass_proposal(&mut syndicate, ProposalType::UpdateConfig(ConfigChange::SetMaxMembers(2)));
+        syndicate.add_member("bob".into(), 500).unwrap();
+        assert!(matches!(syndicate.execute_proposal(&id), Err(SyndicateError::InvalidConfig(_))));
+        assert_eq!(syndicate.config().max_members, 100);
+    }
+
+    #[test]
+    fn test_legacy_config_change_deserializes() {
+        let json = r#"{"UpdateConfig":{"field":"max_members","value":"banana"}}"#;
+        let proposal_type: ProposalType = serde_json::from_str(json).unwrap();
+        let ProposalType::UpdateConfig(change) = proposal_type else {
+            panic!("expected UpdateConfig");
+        };
+        assert_eq!(change, ConfigChange::Legacy { field: "max_members".into(), value: "banana".into() });
+
+        let mut syndicate = test_syndicate();
+        let id = pass_proposal(&mut syndicate, custom());
+        syndicate.proposals.get_mut(&id).unwrap().proposal_type = ProposalType::UpdateConfig(change);
+        assert!(matches!(
+            syndicate.execute_proposal(&id),
+            Err(SyndicateError::LegacyConfigChange(field)) if field == "max_members"
+        ));
+
+        let typed = serde_json::to_string(&ProposalType::UpdateConfig(ConfigChange::SetQuorumBps(3000))).unwrap();
+        let round_trip: ProposalType = serde_json::from_str(&typed).unwrap();
+        assert!(matches!(round_trip, ProposalType::UpdateConfig(ConfigChange::SetQuorumBps(3000))));
+    }
+
+    #[test]
+    fn test_configurable_quorum() {
+        for (quorum_bps, expected) in [
+            (1000, ProposalStatus::Passed),
+            (5000, ProposalStatus::Rejected),
+        ] {
+            let config = SyndicateConfig {
+                id: "syndicate-001".into(),
+                quorum_bps,
+                ..Default::default()
+            };
+            let mut syndicate = Syndicate::new(config).unwrap();
+            syndicate.add_founder("founder".into()).unwrap();
+            for i in 0..8 {
+                syndicate.add_member(format!("member{}", i), 1000).unwrap();
+            }
+
+            // 2000 of 9000 voting power participates (~22%)
+            let id = syndicate.create_proposal(
+                "member0".into(),
+                ProposalType::Custom { title: "Test".into(), description: String::new() },
+            ).unwrap().id.clone();
+            syndicate.vote(&id, "member0", VoteChoice::For).unwrap();
+            syndicate.vote(&id, "member1", VoteChoice::For).unwrap();
+            syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+
+            assert_eq!(syndicate.finalize_proposal(&id).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_vote_delegation() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 300).unwrap();
+        syndicate.add_member("bob".into(), 400).unwrap();
+        syndicate.add_member("carol".into(), 500).unwrap();
+
+        // alice -> bob -> carol
+        syndicate.delegate_vote("alice", "bob", u64::MAX).unwrap();
+        syndicate.delegate_vote("bob", "carol", u64::MAX).unwrap();
+        assert_eq!(syndicate.effective_voting_power("carol"), 1200);
+        assert_eq!(syndicate.effective_voting_power("bob"), 700);
+
+        // carol -> alice would close the loop
+        assert!(matches!(
+            syndicate.delegate_vote("carol", "alice", u64::MAX),
+            Err(SyndicateError::CircularDelegation)
+        ));
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+
+        // bob votes first, so carol only carries her own power
+        syndicate.vote(&id, "bob", VoteChoice::Against).unwrap();
+        syndicate.vote(&id, "carol", VoteChoice::For).unwrap();
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.votes_against, 700);
+        assert_eq!(proposal.votes_for, 500);
+
+        // alice's power was already cast through bob
+        assert!(matches!(
+            syndicate.vote(&id, "alice", VoteChoice::For),
+            Err(SyndicateError::VotedByDelegate(ref d)) if d == "bob"
+        ));
+
+        // Delegated power is counted once in finalization
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Passed);
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.votes_for + proposal.votes_against, 2200);
+    }
+
+    #[test]
+    fn test_delegator_votes_after_delegatee_leaves() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 300).unwrap();
+        syndicate.add_member("bob".into(), 400).unwrap();
+        syndicate.delegate_vote("alice", "bob", u64::MAX).unwrap();
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+        syndicate.vote(&id, "bob", VoteChoice::Against).unwrap();
+        assert_eq!(syndicate.get_proposal(&id).unwrap().votes_against, 700);
+
+        // bob's vote goes with him, and alice gets hers back
+        syndicate.leave("bob").unwrap();
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.votes_against, 0);
+        assert!(proposal.delegated_voters.is_empty());
+        syndicate.vote(&id, "alice", VoteChoice::For).unwrap();
+        assert_eq!(syndicate.get_proposal(&id).unwrap().votes_for, 300);
+    }
+
+    #[test]
+    fn test_expired_vote_delegation() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 300).unwrap();
+        syndicate.add_member("bob".into(), 400).unwrap();
+
+        assert!(matches!(
+            syndicate.delegate_vote("alice", "bob", 1),
+            Err(SyndicateError::InvalidDelegationExpiry)
+        ));
+
+        syndicate.delegate_vote("alice", "bob", u64::MAX).unwrap();
+        syndicate.vote_delegations.get_mut("alice").unwrap().expires_at = 1;
+        assert_eq!(syndicate.effective_voting_power("bob"), 400);
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+        syndicate.vote(&id, "bob", VoteChoice::For).unwrap();
+        syndicate.vote(&id, "alice", VoteChoice::Against).unwrap();
+
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.votes_for, 400);
+        assert_eq!(proposal.votes_against, 300);
+    }
+
+    #[test]
+    fn test_revoke_and_lapse_vote_delegation() {
+        let clock = MockClock::new(1_000);
+        let mut syndicate = test_syndicate().with_clock(clock.clone());
+        syndicate.add_member("alice".into(), 300).unwrap();
+        syndicate.add_member("bob".into(), 400).unwrap();
+        syndicate.add_member("carol".into(), 500).unwrap();
+        syndicate.delegate_vote("alice", "bob", 1_100).unwrap();
+        syndicate.delegate_vote("carol", "bob", 5_000).unwrap();
+        let delegators = |s: &Syndicate| s.active_delegations().iter().map(|d| d.delegator.clone()).collect::<Vec<_>>();
+        assert_eq!(delegators(&syndicate), ["alice", "carol"]);
+
+        // Voting directly suppresses the delegation on that proposal only
+        let first = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        syndicate.vote(&first, "carol", VoteChoice::Against).unwrap();
+        syndicate.vote(&first, "bob", VoteChoice::For).unwrap();
+        assert_eq!(syndicate.get_proposal(&first).unwrap().votes_for, 700);
+        assert!(syndicate.get_vote_delegation("carol").is_some());
+
+        // A delegation lapsing after the vote leaves the recorded power in place
+        clock.set(1_100);
+        assert_eq!(delegators(&syndicate), ["carol"]);
+        syndicate.proposals.get_mut(&first).unwrap().deadline = 0;
+        syndicate.finalize_proposal(&first).unwrap();
+        let proposal = syndicate.get_proposal(&first).unwrap();
+        assert_eq!((proposal.votes_for, proposal.votes_against), (700, 500));
+
+        syndicate.revoke_vote_delegation("carol").unwrap();
+        assert!(matches!(syndicate.revoke_vote_delegation("carol"), Err(SyndicateError::DelegationNotFound(_))));
+        assert!(syndicate.active_delegations().is_empty());
+        assert_eq!(syndicate.effective_voting_power("bob"), 400);
+    }
+
+    #[test]
+    fn test_abstain_votes() {
+        let mut syndicate = test_syndicate();
+        for i in 0..4 {
+            syndicate.add_member(format!("member{}", i), 1000).unwrap();
+        }
+
+        // 5000 total power, 20% quorum = 1000
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+        syndicate.vote(&id, "member0", VoteChoice::For).unwrap();
+        syndicate.vote(&id, "member1", VoteChoice::Abstain).unwrap();
+        syndicate.vote(&id, "member2", VoteChoice::Abstain).unwrap();
+        assert_eq!(syndicate.get_proposal(&id).unwrap().votes_abstain, 2000);
+
+        // 1000 for vs 0 against; abstentions only help quorum
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Passed);
+
+        // Abstentions alone meet quorum but cannot pass a proposal
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+        syndicate.vote(&id, "member0", VoteChoice::Abstain).unwrap();
+        syndicate.vote(&id, "member1", VoteChoice::Abstain).unwrap();
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Rejected);
+    }
+
+    #[test]
+    fn test_change_vote() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("member1".into(), 500).unwrap();
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+
+        assert!(matches!(
+            syndicate.change_vote(&id, "member1", VoteChoice::For),
+            Err(SyndicateError::NotVoted)
+        ));
+
+        syndicate.vote(&id, "member1", VoteChoice::Against).unwrap();
+
+        // Power changes after voting; the recorded 500 is what moves
+        syndicate.update_reputation("member1", 200).unwrap();
+        syndicate.change_vote(&id, "member1", VoteChoice::For).unwrap();
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.votes_for, 500);
+        assert_eq!(proposal.votes_against, 0);
+        assert_eq!(proposal.voters["member1"], VoteChoice::For);
+
+        syndicate.change_vote(&id, "member1", VoteChoice::Abstain).unwrap();
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.votes_for, 0);
+        assert_eq!(proposal.votes_abstain, 500);
+
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert!(matches!(
+            syndicate.change_vote(&id, "member1", VoteChoice::For),
+            Err(SyndicateError::VotingClosed)
+        ));
+    }
+
+    #[test]
+    fn test_delegate_changes_vote() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("member1".into(), 500).unwrap();
+        syndicate.add_member("member2".into(), 500).unwrap();
+        syndicate.delegate_vote("member1", "member2", u64::MAX).unwrap();
+        let id = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+
+        syndicate.vote(&id, "member2", VoteChoice::For).unwrap();
+        syndicate.change_vote(&id, "member2", VoteChoice::Against).unwrap();
+
+        // member1's power still goes with member2's vote
+        assert!(matches!(
+            syndicate.vote(&id, "member1", VoteChoice::For),
+            Err(SyndicateError::VotedByDelegate(ref d)) if d == "member2"
+        ));
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!((proposal.votes_for, proposal.votes_against), (0, 1000));
+        assert_eq!(proposal.votes.len(), 1);
+        assert_eq!(proposal.votes[0].choice, VoteChoice::Against);
+    }
+
+    #[test]
+    fn test_legacy_voter_map_deserializes() {
+        let mut syndicate = test_syndicate();
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+        let mut json = serde_json::to_value(syndicate.get_proposal(&id).unwrap()).unwrap();
+        json["voters"] = serde_json::json!({ "alice": true, "bob": false, "carol": "Abstain" });
+        json.as_object_mut().unwrap().remove("votes_abstain");
+
+        let proposal: Proposal = serde_json::from_value(json).unwrap();
+        assert_eq!(proposal.voters["alice"], VoteChoice::For);
+        assert_eq!(proposal.voters["bob"], VoteChoice::Against);
+        assert_eq!(proposal.voters["carol"], VoteChoice::Abstain);
+        assert_eq!(proposal.votes_abstain, 0);
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in [0u32, 1, 2, 3, 4, 15, 16, 17, 999, 1000, 1024, u32::MAX] {
+            let root = isqrt(n) as u64;
+            assert!(root * root <= n as u64 && (root + 1) * (root + 1) > n as u64, "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_voting_models() {
+        for (voting_model, expected) in [
+            (VotingModel::ReputationLinear, ProposalStatus::Passed),   // 1000 vs 800
+            (VotingModel::Quadratic, ProposalStatus::Rejected),        // 31 vs 40
+            (VotingModel::OnePersonOneVote, ProposalStatus::Rejected), // 1 vs 2
+            (VotingModel::Capped { max: 500 }, ProposalStatus::Rejected), // 500 vs 800
+        ] {
+            let config = SyndicateConfig {
+                id: "syndicate-001".into(),
+                voting_model,
+                ..Default::default()
+            };
+            let mut syndicate = Syndicate::new(config).unwrap();
+            syndicate.add_founder("founder".into()).unwrap();
+            syndicate.add_member("member1".into(), 400).unwrap();
+            syndicate.add_member("member2".into(), 400).unwrap();
+            assert_eq!(
+                syndicate.get_member("member1").unwrap().voting_power,
+                voting_model.voting_power(400)
+            );
+
+            let id = syndicate.create_proposal(
+                "founder".into(),
+                ProposalType::Custom { title: "Test".into(), description: String::new() },
+            ).unwrap().id.clone();
+            syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+            syndicate.vote(&id, "member1", VoteChoice::Against).unwrap();
+            syndicate.vote(&id, "member2", VoteChoice::Against).unwrap();
+            syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+
+            assert_eq!(syndicate.finalize_proposal(&id).unwrap(), expected, "{:?}", voting_model);
+        }
+    }
+
+    #[test]
+    fn test_sweep_expired() {
+        let mut syndicate = test_syndicate();
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            let id = syndicate.create_proposal(
+                "founder".into(),
+                ProposalType::Custom { title: format!("Test {}", i), description: String::new() },
+            ).unwrap().id.clone();
+            ids.push(id);
+        }
+        syndicate.vote(&ids[0], "founder", VoteChoice::For).unwrap();
+        syndicate.cancel_proposal("founder", &ids[2]).unwrap();
+
+        let deadline = syndicate.get_proposal(&ids[0]).unwrap().deadline;
+
+        // Nothing has expired yet
+        assert!(syndicate.sweep_expired(deadline).is_empty());
+
+        let swept = syndicate.sweep_expired(deadline + 1);
+        assert_eq!(swept.len(), 2);
+        assert!(swept.contains(&(ids[0].clone(), ProposalStatus::Passed)));
+        assert!(swept.contains(&(ids[1].clone(), ProposalStatus::Rejected)));
+        assert_eq!(syndicate.get_proposal(&ids[2]).unwrap().status, ProposalStatus::Cancelled);
+
+        // Idempotent
+        assert!(syndicate.sweep_expired(deadline + 1).is_empty());
+    }
+
+    #[test]
+    fn test_merge_syndicates() {
+        let build = |id: &str, founder: &str, members: &[(&str, u32, u64)]| {
+            let config = SyndicateConfig {
+                id: id.into(),
+                name: id.to_uppercase(),
+                syndicate_fee_bps: 0,
+                ..Default::default()
+            };
+            let mut syndicate = Syndicate::new(config).unwrap();
+            syndicate.add_founder(founder.into()).unwrap();
+            for (agent, reputation, contribution) in members {
+                syndicate.add_member(agent.to_string(), *reputation).unwrap();
+                syndicate.record_contribution(agent, *contribution).unwrap();
+            }
+            syndicate
+        };
+        let contributions = |s: &Syndicate| s.members.values().map(|m| m.contribution_score).sum::<u64>();
+
+        let mut survivor = build("alpha", "f1", &[("alice", 500, 100), ("bob", 400, 10)]);
+        let mut absorbed = build("beta", "f2", &[("alice", 700, 50), ("carol", 300, 20)]);
+        let executed = absorbed.create_proposal("f2".into(), custom()).unwrap().id.clone();
+        absorbed.vote(&executed, "f2", VoteChoice::For).unwrap();
+        absorbed.proposals.get_mut(&executed).unwrap().deadline = 0;
+        absorbed.finalize_proposal(&executed).unwrap();
+        absorbed.execute_proposal(&executed).unwrap();
+        let active = absorbed.create_proposal("f2".into(), custom()).unwrap().id.clone();
+        let passed = absorbed.create_proposal("f2".into(), custom()).unwrap().id.clone();
+        absorbed.vote(&passed, "f2", VoteChoice::For).unwrap();
+        absorbed.proposals.get_mut(&passed).unwrap().deadline = 0;
+        absorbed.finalize_proposal(&passed).unwrap();
+        let rejected = absorbed.create_proposal("f2".into(), custom()).unwrap().id.clone();
+        absorbed.vote(&rejected, "f2", VoteChoice::Against).unwrap();
+        absorbed.proposals.get_mut(&rejected).unwrap().deadline = 0;
+        absorbed.finalize_proposal(&rejected).unwrap();
+        let request = absorbed.request_membership("dave".into(), 900, Some(&test_proof("dave")), None).unwrap();
+        absorbed.delegate_vote("carol", "f2", u64::MAX).unwrap();
+        absorbed.delegate_vote("alice", "carol", u64::MAX).unwrap();
+        survivor.delegate_vote("alice", "bob", u64::MAX).unwrap();
+        let expected_contributions = contributions(&survivor) + contributions(&absorbed);
+
+        let report = survivor.merge_from(absorbed, MergePolicy::default()).unwrap();
+        assert_eq!(survivor.member_count(), 5);
+        assert_eq!(report.members_added, ["carol", "f2"]);
+        assert_eq!(report.members_merged, ["alice"]);
+        assert_eq!(contributions(&survivor), expected_contributions);
+        let alice = survivor.get_member("alice").unwrap();
+        assert_eq!((alice.reputation, alice.contribution_score, alice.voting_power), (700, 150, 700));
+        assert_eq!(survivor.get_member("f1").unwrap().role, MemberRole::Founder);
+        assert_eq!(survivor.get_member("f2").unwrap().role, MemberRole::Admin);
+        assert_eq!(survivor.config().name, "ALPHA");
+
+        let cancelled: Vec<&str> = report.cancelled_proposals.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(cancelled, [active.as_str(), passed.as_str(), request.as_str()]);
+        for proposal in &report.cancelled_proposals {
+            assert_eq!(proposal.status, ProposalStatus::Cancelled);
+            assert!(proposal.execution_result.as_ref().unwrap().contains("merge"));
+        }
+        assert_eq!(report.imported_proposals, 1);
+        assert_eq!(report.discarded_proposals, [rejected.as_str()]);
+        assert_eq!(report.dropped_membership_requests.len(), 1);
+        assert_eq!(report.dropped_membership_requests[0].agent_id, "dave");
+        assert!(survivor.get_member("dave").is_none());
+
+        // alice already delegates here
+        let delegators = |delegations: &[VoteDelegation]| {
+            delegations.iter().map(|d| d.delegator.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(delegators(&report.vote_delegations_added), ["carol"]);
+        assert_eq!(delegators(&report.vote_delegations_dropped), ["alice"]);
+        assert_eq!(survivor.get_vote_delegation("alice").unwrap().delegatee, "bob");
+        assert_eq!(survivor.get_vote_delegation("carol").unwrap().delegatee, "f2");
+        assert!(survivor.events().iter().any(|e| matches!(
+            &e.event,
+            SyndicateEvent::ProposalImported { proposal_id, .. } if *proposal_id == executed
+        )));
+
+        // The other policy choices
+        let mut survivor = build("alpha", "f1", &[("alice", 500, 100)]);
+        let absorbed = build("beta", "f2", &[("alice", 700, 50)]);
+        let policy = MergePolicy {
+            duplicate_members: DuplicateMemberResolution::KeepExisting,
+            config: ConfigResolution::TakeAbsorbed,
+            import_history: false,
+        };
+        survivor.merge_from(absorbed, policy).unwrap();
+        assert_eq!(survivor.get_member("alice").unwrap().reputation, 500);
+        assert_eq!((survivor.id(), survivor.config().name.as_str()), ("alpha", "BETA"));
+
+        // Delegations that would close a cycle are dropped
+        let mut survivor = build("alpha", "f1", &[("alice", 500, 0), ("bob", 500, 0)]);
+        survivor.delegate_vote("bob", "alice", u64::MAX).unwrap();
+        let mut absorbed = build("beta", "f2", &[("alice", 500, 0), ("bob", 500, 0)]);
+        absorbed.delegate_vote("alice", "bob", u64::MAX).unwrap();
+        let report = survivor.merge_from(absorbed, MergePolicy::default()).unwrap();
+        assert_eq!(delegators(&report.vote_delegations_dropped), ["alice"]);
+        assert!(survivor.get_vote_delegation("alice").is_none());
+
+        // Too many members combined
+        let mut survivor = build("alpha", "f1", &[("alice", 500, 0)]);
+        survivor.config.max_members = 3;
+        let absorbed = build("beta", "f2", &[("bob", 500, 0)]);
+        assert!(matches!(survivor.merge_from(absorbed, MergePolicy::default()), Err(SyndicateError::MemberLimitReached)));
+        assert_eq!(survivor.member_count(), 2);
+    }
+
+    #[test]
+    fn test_vote_batch() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        let ids: Vec<String> = (0..19)
+            .map(|i| {
+                syndicate.create_proposal("founder".into(), ProposalType::Custom {
+                    title: format!("P{}", i),
+                    description: String::new(),
+                }).unwrap().id.clone()
+            })
+            .collect();
+        syndicate.vote(&ids[3], "alice", VoteChoice::For).unwrap();
+        syndicate.proposals.get_mut(&ids[7]).unwrap().deadline = 0;
+
+        let mut batch: Vec<(String, bool)> = ids.iter().enumerate().map(|(i, id)| (id.clone(), i % 2 == 0)).collect();
+        batch.push(("missing".into(), true));
+        let results = syndicate.vote_batch("alice", batch).unwrap();
+
+        assert_eq!(results.len(), 20);
+        assert!(matches!(results[3], Err(SyndicateError::AlreadyVoted)));
+        assert!(matches!(results[7], Err(SyndicateError::VotingClosed)));
+        assert!(matches!(results[19], Err(SyndicateError::ProposalNotFound(_))));
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 17);
+        assert_eq!(syndicate.get_proposal(&ids[0]).unwrap().voters["alice"], VoteChoice::For);
+        assert_eq!(syndicate.get_proposal(&ids[1]).unwrap().voters["alice"], VoteChoice::Against);
+
+        // Duplicates abort the whole batch
+        let dup = vec![(ids[8].clone(), VoteChoice::For), (ids[8].clone(), VoteChoice::Against)];
+        assert!(matches!(syndicate.vote_batch("founder", dup), Err(SyndicateError::DuplicateBatchVote(_))));
+        assert!(!syndicate.get_proposal(&ids[8]).unwrap().voters.contains_key("founder"));
+    }
+
+    #[test]
+    fn test_vote_breakdown() {
+        let clock = MockClock::new(100);
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap().with_clock(clock.clone());
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        syndicate.add_member("bob".into(), 400).unwrap();
+        let id = syndicate.create_proposal("founder".into(), ProposalType::Custom {
+            title: "T".into(),
+            description: String::new(),
+        }).unwrap().id.clone();
+
+        syndicate.vote(&id, "bob", VoteChoice::Against).unwrap();
+        clock.advance(10);
+        syndicate.vote(&id, "alice", VoteChoice::For).unwrap();
+        clock.advance(10);
+        syndicate.change_vote(&id, "bob", VoteChoice::For).unwrap();
+
+        let breakdown: Vec<_> = syndicate.vote_breakdown(&id).unwrap()
+            .into_iter()
+            .map(|r| (r.voter.as_str(), r.choice, r.power, r.timestamp))
+            .collect();
+        assert_eq!(breakdown, [
+            ("alice", VoteChoice::For, 500, 110),
+            ("bob", VoteChoice::For, 400, 120),
+        ]);
+        assert_eq!(syndicate.get_proposal(&id).unwrap().voters["bob"], VoteChoice::For);
+
+        // Later reputation changes don't alter what the vote carried
+        syndicate.update_reputation("alice", 400).unwrap();
+        syndicate.leave("alice").unwrap();
+        assert_eq!(syndicate.get_proposal(&id).unwrap().votes_for, 400);
+
+        // Proposals from before vote records are rebuilt from the old maps
+        let mut snapshot = syndicate.snapshot();
+        snapshot.proposals.get_mut(&id).unwrap().votes.clear();
+        let restored = Syndicate::restore(snapshot).unwrap();
+        let records = restored.vote_breakdown(&id).unwrap();
+        assert_eq!((records.len(), records[0].power, records[0].timestamp), (1, 400, 100));
+        assert!(matches!(restored.vote_breakdown("missing"), Err(SyndicateError::ProposalNotFound(_))));
+    }
+
+    #[test]
+    fn test_structured_custom_payload() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Rebalance {
+            target: String,
+            weight_bps: u32,
+        }
+
+        let mut syndicate = test_syndicate();
+        syndicate.config.proposal_cooldown_secs = 0;
+        syndicate.config.max_custom_payload_bytes = 64;
+        let structured = |payload| ProposalType::StructuredCustom {
+            title: "Rebalance".into(),
+            description: String::new(),
+            payload,
+        };
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            structured(serde_json::json!({ "target": "ETH", "weight_bps": 2500 })),
+        ).unwrap().id.clone();
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.proposal_type.kind(), ProposalKind::Custom);
+        assert_eq!(
+            proposal.payload_as::<Rebalance>().unwrap().unwrap(),
+            Rebalance { target: "ETH".into(), weight_bps: 2500 }
+        );
+        assert!(matches!(proposal.payload_as::<Vec<u32>>(), Some(Err(SyndicateError::InvalidPayload(_)))));
+
+        assert!(matches!(
+            syndicate.create_proposal("founder".into(), structured(serde_json::json!([1, 2, 3]))),
+            Err(SyndicateError::InvalidPayload(_))
+        ));
+        assert!(matches!(
+            syndicate.create_proposal("founder".into(), structured(serde_json::json!({ "blob": "x".repeat(64) }))),
+            Err(SyndicateError::PayloadTooLarge { max: 64, .. })
+        ));
+
+        pass_existing(&mut syndicate, &id);
+        assert_eq!(syndicate.execute_proposal(&id).unwrap(), "Custom proposal 'Rebalance' accepted");
+    }
+
+    #[test]
+    fn test_proposal_dependencies() {
+        let mut syndicate = test_syndicate();
+        syndicate.config.proposal_cooldown_secs = 0;
+        let create = |syndicate: &mut Syndicate, proposal_type, depends_on: &[&String]| {
+            syndicate.create_proposal_with_dependencies(
+                "founder".into(),
+                proposal_type,
+                depends_on.iter().map(|id| id.to_string()).collect(),
+            ).map(|p| p.id.clone())
+        };
+
+        let config = create(&mut syndicate, ProposalType::UpdateConfig(ConfigChange::SetSyndicateFeeBps(100)), &[]).unwrap();
+        let payout = create(&mut syndicate, custom(), &[&config]).unwrap();
+        let announce = create(&mut syndicate, custom(), &[&payout]).unwrap();
+        assert!(matches!(
+            create(&mut syndicate, custom(), &[&"nope".to_string()]),
+            Err(SyndicateError::UnknownDependency(id)) if id == "nope"
+        ));
+        for id in [&config, &payout, &announce] {
+            pass_existing(&mut syndicate, id);
+        }
+
+        // Dependencies gate execution without consuming the proposal
+        assert!(matches!(syndicate.execute_proposal(&announce), Err(SyndicateError::DependencyNotExecuted(id)) if id == payout));
+        assert!(matches!(syndicate.execute_proposal(&payout), Err(SyndicateError::DependencyNotExecuted(id)) if id == config));
+        assert_eq!(syndicate.get_proposal(&payout).unwrap().status, ProposalStatus::Passed);
+        for id in [&config, &payout, &announce] {
+            syndicate.execute_proposal(id).unwrap();
+        }
+        assert_eq!(syndicate.config().syndicate_fee_bps, 100);
+
+        // A cycle anywhere downstream is refused
+        syndicate.proposals.get_mut(&config).unwrap().depends_on = vec![announce.clone()];
+        assert!(matches!(create(&mut syndicate, custom(), &[&payout]), Err(SyndicateError::DependencyCycle(_))));
+    }
+
+    #[test]
+    fn test_bans() {
+        let clock = MockClock::new(1_000);
+        let mut syndicate = test_syndicate().with_clock(clock.clone());
+        syndicate.add_member("admin".into(), 500).unwrap();
+        syndicate.add_member("mallory".into(), 500).unwrap();
+        syndicate.members.get_mut("admin").unwrap().role = MemberRole::Admin;
+
+        // Removal by proposal bans permanently by default
+        let id = pass_proposal(&mut syndicate, ProposalType::RemoveMember { agent_id: "mallory".into(), evidence: vec![] });
+        syndicate.execute_proposal(&id).unwrap();
+        assert!(matches!(
+            syndicate.request_membership("mallory".into(), 500, Some(&test_proof("mallory")), None),
+            Err(SyndicateError::Banned { until: None })
+        ));
+        assert!(matches!(syndicate.add_member("mallory".into(), 500), Err(SyndicateError::Banned { until: None })));
+
+        // Timed bans lapse
+        assert!(matches!(syndicate.ban("mallory", "eve", None, "spam"), Err(SyndicateError::NotMember(_))));
+        syndicate.ban("admin", "eve", Some(100), "spam").unwrap();
+        assert!(matches!(syndicate.add_member("eve".into(), 500), Err(SyndicateError::Banned { until: Some(1_100) })));
+        clock.advance(100);
+        assert!(!syndicate.is_banned("eve"));
+        syndicate.add_member("eve".into(), 500).unwrap();
+        syndicate.ban("admin", "oscar", Some(u64::MAX), "spam").unwrap();
+        assert_eq!(syndicate.ban_record("oscar").unwrap().until, Some(u64::MAX));
+        assert!(syndicate.is_banned("oscar"));
+
+        // Bans survive snapshots
+        let json = serde_json::to_string(&syndicate.snapshot()).unwrap();
+        let mut syndicate = Syndicate::restore(serde_json::from_str(&json).unwrap()).unwrap().with_clock(clock);
+        assert!(syndicate.is_banned("mallory"));
+
+        // Only founders lift bans
+        assert!(matches!(syndicate.unban("admin", "mallory"), Err(SyndicateError::PermissionDenied)));
+        syndicate.unban("founder", "mallory").unwrap();
+        assert!(matches!(syndicate.unban("founder", "mallory"), Err(SyndicateError::NotBanned(_))));
+        syndicate.add_member("mallory".into(), 500).unwrap();
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        syndicate.add_member("bob".into(), 400).unwrap();
+        syndicate.add_member("carol".into(), 300).unwrap();
+        syndicate.delegate_vote("carol", "alice", u64::MAX).unwrap();
+        syndicate.members.get_mut("alice").unwrap().role = MemberRole::Admin;
+
+        let passed = pass_proposal(&mut syndicate, ProposalType::RemoveMember { agent_id: "bob".into(), evidence: vec![] });
+        let active = syndicate.create_proposal(
+            "alice".into(),
+            ProposalType::DistributeProfits { amount: 500 },
+        ).unwrap().id.clone();
+        syndicate.vote(&active, "alice", VoteChoice::For).unwrap();
+        syndicate.vote(&active, "bob", VoteChoice::Against).unwrap();
+        syndicate.execute_proposal(&passed).unwrap();
+
+        let json = serde_json::to_string(&syndicate.snapshot()).unwrap();
+        let snapshot: SyndicateSnapshot = serde_json::from_str(&json).unwrap();
+        let mut restored = Syndicate::restore(snapshot).unwrap();
+
+        assert_eq!(restored.member_count(), syndicate.member_count());
+        assert_eq!(restored.removed_members().len(), 1);
+        assert_eq!(restored.effective_voting_power("alice"), 800);
+        let proposal = restored.get_proposal(&active).unwrap();
+        assert_eq!(proposal.votes_for, 800);
+        assert_eq!(proposal.votes_against, 0);
+        assert_eq!(restored.get_proposal(&passed).unwrap().status, ProposalStatus::Executed);
+
+        // Counter carries over, so new IDs don't collide
+        let next = restored.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Next".into(), description: String::new() },
+        ).unwrap().id.clone();
+        assert!(next != passed && next != active);
+        assert_eq!(restored.proposal_counter, syndicate.proposal_counter + 1);
+    }
+
+    #[test]
+    fn test_restored_instances_do_not_collide() {
+        let clock = MockClock::new(1_000);
+        let mut syndicate = test_syndicate().with_clock(clock.clone());
+        let original = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+
+        // Two instances from the same snapshot, same proposer, same second
+        let snapshot = syndicate.snapshot();
+        let mut a = Syndicate::restore(snapshot.clone()).unwrap().with_clock(clock.clone());
+        let mut b = Syndicate::restore(snapshot).unwrap().with_clock(clock.clone());
+        let from_a = a.create_proposal("founder".into(), custom()).unwrap().clone();
+        let from_b = b.create_proposal("founder".into(), custom()).unwrap().clone();
+
+        assert_ne!(from_a.id, from_b.id);
+        assert_ne!(from_a.id, original);
+        assert_eq!((from_a.sequence, from_b.sequence), (2, 2));
+        assert_eq!(a.get_proposal(&original).unwrap().sequence, 1);
+
+        // Legacy IDs keep their sequence through a restore
+        let mut snapshot = syndicate.snapshot();
+        let mut legacy = snapshot.proposals.remove(&original).unwrap();
+        legacy.id = "syndicate-001-1".into();
+        legacy.sequence = 0;
+        snapshot.proposals.insert(legacy.id.clone(), legacy);
+        let restored = Syndicate::restore(snapshot).unwrap();
+        assert_eq!(restored.get_proposal("syndicate-001-1").unwrap().sequence, 1);
+    }
+
+    #[test]
+    fn test_restore_rejects_inconsistent_snapshot() {
+        let mut syndicate = test_syndicate();
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+
+        let mut snapshot = syndicate.snapshot();
+        snapshot.proposals.get_mut(&id).unwrap().voters.insert("ghost".into(), VoteChoice::For);
+        assert!(matches!(
+            Syndicate::restore(snapshot),
+            Err(SyndicateError::InconsistentSnapshot(_))
+        ));
+
+        let mut snapshot = syndicate.snapshot();
+        snapshot.proposal_counter = 0;
+        assert!(matches!(
+            Syndicate::restore(snapshot),
+            Err(SyndicateError::InconsistentSnapshot(_))
+        ));
+    }
+
+    #[test]
+    fn test_treasury_ledger() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        syndicate.add_member("bob".into(), 500).unwrap();
+
+        let conserved = |s: &Syndicate| {
+            let t = s.treasury();
+            t.total_holdings() == t.total_inflow() - t.total_withdrawn()
+        };
+
+        syndicate.deposit("alice", 1000).unwrap();
+        assert!(matches!(syndicate.deposit("outsider", 10), Err(SyndicateError::NotMember(_))));
+
+        // 5% fee on contributions
+        syndicate.record_contribution("bob", 2000).unwrap();
+        assert_eq!(syndicate.treasury().fees_accrued(), 100);
+        assert_eq!(syndicate.treasury().pool(), 1100);
+        assert!(conserved(&syndicate));
+
+        // bob is the only contributor and receives all 855 left after fees
+        let id = pass_proposal(&mut syndicate, ProposalType::DistributeProfits { amount: 900 });
+        syndicate.execute_proposal(&id).unwrap();
+        assert_eq!(syndicate.treasury().pool(), 245);
+        assert_eq!(syndicate.treasury().balance("bob"), 855);
+        assert!(conserved(&syndicate));
+
+        assert!(matches!(
+            syndicate.withdraw("bob", 856),
+            Err(SyndicateError::InsufficientBalance { available: 855, requested: 856 })
+        ));
+        assert_eq!(syndicate.withdraw("bob", 800).unwrap(), 55);
+        assert_eq!(syndicate.withdraw("bob", 55).unwrap(), 0);
+        assert_eq!(syndicate.treasury().balance("bob"), 0);
+        assert!(conserved(&syndicate));
+
+        // Distribution larger than the pool fails and leaves balances untouched
+        let id = pass_proposal(&mut syndicate, ProposalType::DistributeProfits { amount: 10_000 });
+        assert!(matches!(
+            syndicate.execute_proposal(&id),
+            Err(SyndicateError::InsufficientTreasuryFunds { .. })
+        ));
+        assert_eq!(syndicate.treasury().pool(), 245);
+        assert!(conserved(&syndicate));
+    }
+
+    #[test]
+    fn test_contribution_fees() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        syndicate.config.syndicate_fee_bps = 333;
+
+        let mut expected_fees = 0;
+        for amount in 1..=1_000u64 {
+            syndicate.record_contribution("alice", amount).unwrap();
+            expected_fees += amount * 333 / 10000;
+        }
+        let contributed: u64 = (1..=1_000).sum();
+        let alice = syndicate.get_member("alice").unwrap().contribution_score;
+        assert_eq!(syndicate.total_fees_collected(), expected_fees);
+        assert_eq!(alice + syndicate.total_fees_collected(), contributed);
+        // Rounding down leaves the member with more than the exact split
+        assert!(alice * 10000 >= contributed * (10000 - 333));
+
+        // No fee credits the full amount
+        syndicate.config.syndicate_fee_bps = 0;
+        syndicate.record_contribution("alice", 77).unwrap();
+        assert_eq!(syndicate.get_member("alice").unwrap().contribution_score, alice + 77);
+        assert_eq!(syndicate.total_fees_collected(), expected_fees);
+    }
+
+    #[test]
+    fn test_compute_distribution_rounding() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        syndicate.add_member("bob".into(), 500).unwrap();
+
+        // No contributions: equal split of 95 after the 5% fee
+        let shares = syndicate.compute_distribution(100).unwrap();
+        assert_eq!(shares, vec![
+            ("alice".to_string(), 33),
+            ("bob".to_string(), 31),
+            ("founder".to_string(), 31),
+        ]);
+
+        // Pro-rata 19:38:57 after fees -> 15, 31, 47 with remainder 2 to the top contributor
+        syndicate.record_contribution("alice", 20).unwrap();
+        syndicate.record_contribution("bob", 40).unwrap();
+        syndicate.record_contribution("founder", 60).unwrap();
+        let shares = syndicate.compute_distribution(100).unwrap();
+        assert_eq!(shares, vec![
+            ("alice".to_string(), 15),
+            ("bob".to_string(), 31),
+            ("founder".to_string(), 49),
+        ]);
+        assert_eq!(shares.iter().map(|(_, s)| s).sum::<u64>(), 95);
+
+        // Execution records the split and keeps the fee in the pool
+        syndicate.deposit("founder", 100).unwrap();
+        let pool_before = syndicate.treasury().pool();
+        let id = pass_proposal(&mut syndicate, ProposalType::DistributeProfits { amount: 100 });
+        let result = syndicate.execute_proposal(&id).unwrap();
+        assert!(result.contains("alice=15") && result.contains("founder=49"), "{}", result);
+        assert_eq!(syndicate.treasury().pool(), pool_before - 95);
+        assert_eq!(syndicate.treasury().balance("bob"), 31);
+    }
+
+    #[test]
+    fn test_leave_and_rejoin_cooldown() {
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            rejoin_cooldown_secs: 3600,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap();
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate.add_member("alice".into(), 500).unwrap();
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+        syndicate.vote(&id, "alice", VoteChoice::For).unwrap();
+
+        assert!(matches!(syndicate.leave("founder"), Err(SyndicateError::FounderCannotLeave)));
+
+        syndicate.leave("alice").unwrap();
+        let alice = syndicate.get_member("alice").unwrap();
+        assert!(!alice.active);
+        assert!(alice.left_at.is_some());
+        assert_eq!(syndicate.get_proposal(&id).unwrap().votes_for, 0);
+        assert_eq!(syndicate.active_members().len(), 1);
+
+        let proof = test_proof("alice");
+        assert!(matches!(
+            syndicate.request_membership("alice".into(), 500, Some(&proof), None),
+            Err(SyndicateError::CooldownActive { .. })
+        ));
+        assert!(matches!(
+            syndicate.add_member("alice".into(), 500),
+            Err(SyndicateError::CooldownActive { .. })
+        ));
+
+        // Once the cooldown has elapsed the agent can rejoin
+        syndicate.members.get_mut("alice").unwrap().left_at = Some(0);
+        syndicate.add_member("alice".into(), 500).unwrap();
+        assert!(syndicate.get_member("alice").unwrap().active);
+
+        // Founder can leave once alone
+        syndicate.leave("alice").unwrap();
+        syndicate.leave("founder").unwrap();
+    }
+
+    #[test]
+    fn test_audit_log() {
+        let mut syndicate = test_syndicate();
+        let id = request_and_pass(&mut syndicate, "alice", 500);
+        syndicate.execute_proposal(&id).unwrap();
+        syndicate.update_reputation("alice", 50).unwrap();
+
+        let kinds: Vec<_> = syndicate.events_for_agent("alice")
+            .into_iter()
+            .map(|e| e.event.clone())
+            .collect();
+        assert!(kinds.contains(&SyndicateEvent::MembershipRequested {
+            agent_id: "alice".into(),
+            proposal_id: id.clone(),
+        }));
+        assert!(kinds.contains(&SyndicateEvent::MemberAdded { agent_id: "alice".into(), reputation: 500 }));
+        assert!(kinds.contains(&SyndicateEvent::ReputationUpdated { agent_id: "alice".into(), old: 500, new: 550 }));
+
+        let vote = syndicate.events().iter()
+            .find(|e| matches!(e.event, SyndicateEvent::VoteCast { .. }))
+            .unwrap();
+        assert_eq!(vote.actor, "founder");
+
+        assert_eq!(syndicate.events_since(0).len(), syndicate.events().len());
+        assert!(syndicate.events_since(u64::MAX).is_empty());
+
+        let restored = Syndicate::restore(syndicate.snapshot()).unwrap();
+        assert_eq!(restored.events(), syndicate.events());
+    }
+
+    #[test]
+    fn test_audit_log_rotation() {
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            max_events: 3,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap();
+        syndicate.add_founder("founder".into()).unwrap();
+        for delta in 1..=5 {
+            syndicate.update_reputation("founder", -delta).unwrap();
+        }
+
+        assert_eq!(syndicate.events().len(), 3);
+        assert_eq!(
+            syndicate.events()[0].event,
+            SyndicateEvent::ReputationUpdated { agent_id: "founder".into(), old: 997, new: 994 }
+        );
+    }
+
+    #[test]
+    fn test_signed_contributions() {
+        use sdkey_manager::{AgentMetadata, AgentPermissions};
+
+        let clock = MockClock::new(1_000);
+        let mut syndicate = test_syndicate().with_clock(clock.clone());
+        syndicate.config.syndicate_fee_bps = 0;
+        let key = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let other = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let agent = key.agent_id();
+        syndicate.add_member(agent.clone(), 500).unwrap();
+
+        let report = ContributionReport::sign(&key, 250, 1, 1_000).unwrap();
+        assert!(matches!(
+            syndicate.record_contribution_signed(&report, &other.public_key()),
+            Err(SyndicateError::ReportKeyMismatch(_))
+        ));
+        let tampered = ContributionReport { amount: 9_999, ..report.clone() };
+        assert!(matches!(
+            syndicate.record_contribution_signed(&tampered, &key.public_key()),
+            Err(SyndicateError::InvalidReportSignature)
+        ));
+
+        syndicate.record_contribution_signed(&report, &key.public_key()).unwrap();
+        assert!(matches!(
+            syndicate.record_contribution_signed(&report, &key.public_key()),
+            Err(SyndicateError::NonceReused(1))
+        ));
+        syndicate.record_contribution(&agent, 50).unwrap();
+        assert_eq!(syndicate.get_member(&agent).unwrap().contribution_score, 300);
+
+        // Signed and trusted reports are told apart in the audit log
+        let logged: Vec<(&str, Option<u64>)> = syndicate.events().iter()
+            .filter_map(|e| match &e.event {
+                SyndicateEvent::ContributionRecorded { nonce, .. } => Some((e.actor.as_str(), *nonce)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(logged, [(agent.as_str(), Some(1)), (SYSTEM_ACTOR, None)]);
+
+        // Used nonces survive snapshots
+        let mut restored = Syndicate::restore(syndicate.snapshot()).unwrap().with_clock(clock.clone());
+        assert!(matches!(
+            restored.record_contribution_signed(&report, &key.public_key()),
+            Err(SyndicateError::NonceReused(1))
+        ));
+
+        // Reports far from the current time are rejected
+        let future = ContributionReport::sign(&key, 10, 2, 1_000 + CONTRIBUTION_WINDOW_SECS + 1).unwrap();
+        assert!(matches!(
+            syndicate.record_contribution_signed(&future, &key.public_key()),
+            Err(SyndicateError::ReportOutOfWindow { now: 1_000, .. })
+        ));
+        clock.advance(CONTRIBUTION_WINDOW_SECS + 1);
+        assert!(matches!(
+            syndicate.record_contribution_signed(&report, &key.public_key()),
+            Err(SyndicateError::ReportOutOfWindow { timestamp: 1_000, .. })
+        ));
+
+        // Nonces that have left the window are dropped
+        let later = ContributionReport::sign(&key, 10, 3, clock.now()).unwrap();
+        syndicate.record_contribution_signed(&later, &key.public_key()).unwrap();
+        assert_eq!(syndicate.contribution_nonces[&agent], HashMap::from([(3, clock.now())]));
+    }
+
+    #[test]
+    fn test_signed_votes() {
+        use sdkey_manager::{AgentMetadata, AgentPermissions};
+
+        let mut syndicate = test_syndicate();
+        let key = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let other = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let voter = key.agent_id();
+        syndicate.add_member(voter.clone(), 500).unwrap();
+
+        let proposal = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap();
+        let (id, created_at) = (proposal.id.clone(), proposal.created_at);
+
+        let signed = SignedVote::sign(&key, id.clone(), voter.clone(), VoteChoice::For, created_at).unwrap();
+
+        assert!(matches!(
+            syndicate.vote_signed("syndicate-001-99", &signed, &key.public_key()),
+            Err(SyndicateError::VoteProposalMismatch { .. })
+        ));
+        assert!(matches!(
+            syndicate.vote_signed(&id, &signed, &other.public_key()),
+            Err(SyndicateError::SignerKeyMismatch(_))
+        ));
+
+        // A valid signature by someone else's key doesn't vote as the founder
+        let impersonation = SignedVote::sign(&other, id.clone(), "founder", VoteChoice::For, created_at).unwrap();
+        assert!(matches!(
+            syndicate.vote_signed(&id, &impersonation, &other.public_key()),
+            Err(SyndicateError::SignerKeyMismatch(ref m)) if m == "founder"
+        ));
+
+        // Moving text between fields changes the message
+        let mut shifted = signed.clone();
+        shifted.proposal_id = format!("{}:{}", id, voter);
+        shifted.voter = String::new();
+        assert_ne!(shifted.signing_message(), signed.signing_message());
+
+        let mut tampered = signed.clone();
+        tampered.choice = VoteChoice::Against;
+        assert!(matches!(
+            syndicate.vote_signed(&id, &tampered, &key.public_key()),
+            Err(SyndicateError::InvalidVoteSignature)
+        ));
+
+        let stale = SignedVote::sign(&key, id.clone(), voter.clone(), VoteChoice::For, created_at - 1).unwrap();
+        assert!(matches!(
+            syndicate.vote_signed(&id, &stale, &key.public_key()),
+            Err(SyndicateError::StaleVote { .. })
+        ));
+
+        syndicate.vote_signed(&id, &signed, &key.public_key()).unwrap();
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.votes_for, proposal.power_snapshot[&voter]);
+        assert_eq!(proposal.vote_receipts.get(&voter), Some(&signed));
+    }
+
+    #[test]
+    fn test_action_co_signing() {
+        use sdkey_manager::{AgentMetadata, AgentPermissions};
+
+        let mut syndicate = test_syndicate();
+        let founder_key = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let alice_key = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let (founder, alice) = (founder_key.agent_id(), alice_key.agent_id());
+        syndicate.add_member(founder.clone(), 500).unwrap();
+        syndicate.add_member(alice.clone(), 500).unwrap();
+
+        let mut params = HashMap::new();
+        params.insert("token".to_string(), "ETH".to_string());
+        params.insert("size".to_string(), "10".to_string());
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::ExecuteAction { action_type: "swap".into(), params },
+        ).unwrap().id.clone();
+        let message = syndicate.get_proposal(&id).unwrap().action_signing_message().unwrap();
+        let founder_sig = founder_key.sign_hex(&message).unwrap();
+
+        // Not passed yet
+        assert!(matches!(
+            syndicate.co_sign_action(&id, &founder, &founder_key.public_key(), &founder_sig),
+            Err(SyndicateError::ProposalNotPassed(ProposalStatus::Active))
+        ));
+        pass_existing(&mut syndicate, &id);
+
+        syndicate.co_sign_action(&id, &founder, &founder_key.public_key(), &founder_sig).unwrap();
+        syndicate.co_sign_action(&id, &founder, &founder_key.public_key(), &founder_sig).unwrap();
+        assert!(syndicate.action_ready(&id, 2).is_none());
+
+        assert!(matches!(
+            syndicate.co_sign_action(&id, "mallory", &alice_key.public_key(), &founder_sig),
+            Err(SyndicateError::NotMember(_))
+        ));
+        assert!(matches!(
+            syndicate.co_sign_action(&id, &alice, &alice_key.public_key(), &founder_sig),
+            Err(SyndicateError::InvalidActionSignature)
+        ));
+
+        // A member's own valid signature can't be passed off as another's
+        let alice_sig = alice_key.sign_hex(&message).unwrap();
+        assert!(matches!(
+            syndicate.co_sign_action(&id, "founder", &alice_key.public_key(), &alice_sig),
+            Err(SyndicateError::SignerKeyMismatch(ref m)) if m == "founder"
+        ));
+        syndicate.co_sign_action(&id, &alice, &alice_key.public_key(), &alice_sig).unwrap();
+
+        let bundle = syndicate.action_ready(&id, 2).unwrap();
+        let signers: Vec<_> = bundle.iter().map(|s| s.signer.as_str()).collect();
+        assert_eq!(signers, [founder.as_str(), alice.as_str()]);
+    }
+
+    #[test]
+    fn test_list_proposals_paging() {
+        let clock = MockClock::new(1_000);
+        let mut syndicate = test_syndicate().with_clock(clock.clone());
+        syndicate.add_member("alice".into(), 500).unwrap();
+        syndicate.members.get_mut("alice").unwrap().role = MemberRole::Admin;
+
+        let mut ids = Vec::new();
+        for i in 0..50 {
+            // Pairs of proposals share a timestamp to exercise tie-breaking
+            if i % 2 == 0 {
+                clock.advance(10);
+            }
+            let (proposer, proposal_type) = if i % 5 == 0 {
+                ("alice", ProposalType::DistributeProfits { amount: 1 })
+            } else {
+                ("founder", ProposalType::Custom { title: format!("P{}", i), description: String::new() })
+            };
+            let id = syndicate.create_proposal(proposer.into(), proposal_type).unwrap().id.clone();
+            if i % 3 == 0 {
+                syndicate.cancel_proposal("founder", &id).unwrap();
+            }
+            ids.push(id);
+        }
+
+        let mut seen = Vec::new();
+        let mut filter = ProposalFilter { limit: Some(15), ..Default::default() };
+        loop {
+            let page = syndicate.list_proposals(&filter);
+            assert_eq!(page.total, 50);
+            seen.extend(page.items.iter().map(|p| p.id.clone()));
+            match page.next_offset {
+                Some(offset) => filter.offset = offset,
+                None => break,
+            }
+        }
+        let newest_first: Vec<_> = ids.iter().rev().cloned().collect();
+        assert_eq!(seen, newest_first);
+
+        let oldest = syndicate.list_proposals(&ProposalFilter {
+            sort: ProposalSort::OldestFirst,
+            limit: Some(3),
+            ..Default::default()
+        });
+        let oldest_ids: Vec<_> = oldest.items.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(oldest_ids, [ids[0].as_str(), ids[1].as_str(), ids[2].as_str()]);
+        assert_eq!(oldest.next_offset, Some(3));
+
+        let cancelled = syndicate.list_proposals(&ProposalFilter {
+            status: Some(ProposalStatus::Cancelled),
+            ..Default::default()
+        });
+        assert_eq!(cancelled.total, 17);
+        assert_eq!(cancelled.next_offset, None);
+
+        let by_alice = syndicate.list_proposals(&ProposalFilter {
+            proposer: Some("alice".into()),
+            kind: Some(ProposalKind::DistributeProfits),
+            ..Default::default()
+        });
+        assert_eq!(by_alice.total, 10);
+
+        let window = syndicate.list_proposals(&ProposalFilter {
+            created_after: Some(1_010),
+            created_before: Some(1_030),
+            ..Default::default()
+        });
+        assert_eq!(window.total, 4);
+    }
+
+    #[test]
+    fn test_voting_power_snapshot() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 500).unwrap();
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+
+        // Changes after creation don't affect this proposal
+        syndicate.update_reputation("alice", 400).unwrap();
+        syndicate.add_member("bob".into(), 900).unwrap();
+
+        syndicate.vote(&id, "alice", VoteChoice::For).unwrap();
+        assert_eq!(syndicate.get_proposal(&id).unwrap().votes_for, 500);
+        assert!(matches!(
+            syndicate.vote(&id, "bob", VoteChoice::Against),
+            Err(SyndicateError::NotInPowerSnapshot(_))
+        ));
+
+        // Quorum is measured against the snapshot total (1500), not the live 2800
+        let proposal = syndicate.proposals.get_mut(&id).unwrap();
+        assert_eq!(proposal.power_snapshot.values().sum::<u64>(), 1500);
+        proposal.deadline = 0;
+        syndicate.config.quorum_bps = 3000;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Passed);
+    }
+
+    #[test]
+    fn test_deactivate_inactive_members() {
+        let clock = MockClock::new(1_000);
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            quorum_bps: 5000,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap().with_clock(clock.clone());
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate.add_member("alice".into(), 1000).unwrap();
+        syndicate.add_member("bob".into(), 1000).unwrap();
+        syndicate.add_member("carol".into(), 500).unwrap();
+
+        clock.advance(100);
+        syndicate.record_contribution("carol", 10).unwrap();
+        clock.advance(100);
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ).unwrap().id.clone();
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+
+        // 1000 of 3500 votes: below the 50% quorum
+        clock.advance(syndicate.config().proposal_duration + 1);
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Rejected);
+
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::Custom { title: "Retry".into(), description: String::new() },
+        ).unwrap().id.clone();
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+
+        // Alice and Bob have been idle since joining at 1_000; Carol contributed at 1_100
+        let max_idle = clock.now() - 1_050;
+        assert_eq!(syndicate.deactivate_inactive(max_idle), ["alice", "bob"]);
+        assert!(syndicate.deactivate_inactive(max_idle).is_empty());
+
+        // Now 1000 of 1500 votes
+        clock.advance(syndicate.config().proposal_duration + 1);
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Passed);
+
+        assert!(matches!(syndicate.reactivate("carol", "alice"), Err(SyndicateError::PermissionDenied)));
+        syndicate.reactivate("founder", "alice").unwrap();
+        assert!(syndicate.get_member("alice").unwrap().active);
+        assert_eq!(syndicate.get_member("alice").unwrap().last_activity, clock.now());
+    }
+
+    #[test]
+    fn test_threshold_overrides() {
+        let mut config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            ..Default::default()
+        };
+        config.threshold_overrides.insert(ProposalKind::RemoveMember, 7500);
+        config.threshold_overrides.insert(ProposalKind::UpdateConfig, 6667);
+        let mut syndicate = Syndicate::new(config.clone()).unwrap();
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate.add_member("alice".into(), 1000).unwrap();
+        syndicate.add_member("bob".into(), 500).unwrap();
+
+        // 2000 for vs 500 against = 80%
+        let mut run = |proposal_type: ProposalType, against: &[&str]| {
+            let id = syndicate.create_proposal("founder".into(), proposal_type).unwrap().id.clone();
+            for voter in ["founder", "alice", "bob"] {
+                let choice = if against.contains(&voter) { VoteChoice::Against } else { VoteChoice::For };
+                syndicate.vote(&id, voter, choice).unwrap();
+            }
+            syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+            syndicate.finalize_proposal(&id).unwrap()
+        };
+
+        assert_eq!(run(ProposalType::RemoveMember { agent_id: "bob".into(), evidence: vec![] }, &["bob"]), ProposalStatus::Passed);
+        // 1500 for vs 1000 against = 60%
+        assert_eq!(run(ProposalType::RemoveMember { agent_id: "bob".into(), evidence: vec![] }, &["alice"]), ProposalStatus::Rejected);
+        assert_eq!(
+            run(ProposalType::UpdateConfig(ConfigChange::SetQuorumBps(3000)), &["alice"]),
+            ProposalStatus::Rejected
+        );
+        assert_eq!(
+            run(custom(), &["alice"]),
+            ProposalStatus::Passed
+        );
+
+        config.threshold_overrides.insert(ProposalKind::Custom, 10_001);
+        assert!(matches!(Syndicate::new(config), Err(SyndicateError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_proposal_rate_limits() {
+        let clock = MockClock::new(1_000);
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            max_active_proposals_per_member: Some(2),
+            proposal_cooldown_secs: 60,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap().with_clock(clock.clone());
+        syndicate.add_founder("founder".into()).unwrap();
+
+        let first = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        assert!(matches!(
+            syndicate.create_proposal("founder".into(), custom()),
+            Err(SyndicateError::ProposalRateLimited { retry_after: 1_060 })
+        ));
+
+        clock.advance(60);
+        syndicate.create_proposal("founder".into(), custom()).unwrap();
+        clock.advance(60);
+        let deadline = syndicate.get_proposal(&first).unwrap().deadline;
+        assert!(matches!(
+            syndicate.create_proposal("founder".into(), custom()),
+            Err(SyndicateError::ProposalRateLimited { retry_after }) if retry_after == deadline + 1
+        ));
+
+        // System proposals are exempt
+        syndicate.request_membership("alice".into(), 500, Some(&test_proof("alice")), None).unwrap();
+        syndicate.request_membership("bob".into(), 500, Some(&test_proof("bob")), None).unwrap();
+
+        // Cancelling frees a slot
+        syndicate.cancel_proposal("founder", &first).unwrap();
+        syndicate.create_proposal("founder".into(), custom()).unwrap();
+    }
+
+    #[test]
+    fn test_amend_proposal() {
+        let clock = MockClock::new(1_000);
+        let mut syndicate = test_syndicate().with_clock(clock.clone());
+        syndicate.add_member("alice".into(), 500).unwrap();
+        let id = syndicate.create_proposal("alice".into(), ProposalType::Custom {
+            title: "Tpyo".into(),
+            description: String::new(),
+        }).unwrap().id.clone();
+        let deadline = syndicate.get_proposal(&id).unwrap().deadline;
+
+        clock.advance(60);
+        let fixed = || ProposalType::Custom { title: "Typo".into(), description: String::new() };
+        syndicate.amend_proposal("alice", &id, fixed()).unwrap();
+        syndicate.amend_proposal("founder", &id, fixed()).unwrap();
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert!(matches!(&proposal.proposal_type, ProposalType::Custom { title, .. } if title == "Typo"));
+        assert_eq!((proposal.amendment_count, proposal.created_at, proposal.deadline), (2, 1_000, deadline));
+
+        // Members can't amend into proposal types they couldn't create
+        assert!(matches!(
+            syndicate.amend_proposal("alice", &id, ProposalType::DistributeProfits { amount: 1 }),
+            Err(SyndicateError::PermissionDenied)
+        ));
+        syndicate.add_member("bob".into(), 500).unwrap();
+        assert!(matches!(syndicate.amend_proposal("bob", &id, fixed()), Err(SyndicateError::PermissionDenied)));
+
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+        assert!(matches!(
+            syndicate.amend_proposal("alice", &id, fixed()),
+            Err(SyndicateError::AmendmentAfterVoting)
+        ));
+    }
+
+    #[test]
+    fn test_create_proposal_permissions() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("member".into(), 500).unwrap();
+        syndicate.add_member("admin".into(), 500).unwrap();
+        syndicate.add_member("observer".into(), 500).unwrap();
+        syndicate.set_role("founder", "admin", MemberRole::Admin).unwrap();
+        syndicate.set_role("founder", "observer", MemberRole::Observer).unwrap();
+        let payout = || ProposalType::DistributeProfits { amount: 1 };
+
+        assert!(matches!(
+            syndicate.create_proposal("outsider".into(), custom()),
+            Err(SyndicateError::NotMember(_))
+        ));
+        assert!(matches!(
+            syndicate.create_proposal(SYSTEM_ACTOR.into(), custom()),
+            Err(SyndicateError::NotMember(_))
+        ));
+
+        syndicate.create_proposal("admin".into(), payout()).unwrap();
+        syndicate.create_proposal("member".into(), custom()).unwrap();
+        assert!(matches!(syndicate.create_proposal("member".into(), payout()), Err(SyndicateError::PermissionDenied)));
+        assert!(matches!(syndicate.create_proposal("observer".into(), custom()), Err(SyndicateError::PermissionDenied)));
+
+        syndicate.config.members_can_propose_custom = false;
+        assert!(matches!(syndicate.create_proposal("member".into(), custom()), Err(SyndicateError::PermissionDenied)));
+
+        syndicate.members.get_mut("admin").unwrap().active = false;
+        assert!(matches!(syndicate.create_proposal("admin".into(), custom()), Err(SyndicateError::MemberInactive)));
+    }
+
+    #[test]
+    fn test_contribution_weighted_voting_power() {
+        let mut syndicate = test_syndicate();
+        syndicate.config.syndicate_fee_bps = 0;
+        syndicate.add_member("grinder".into(), 300).unwrap();
+        syndicate.add_member("veteran".into(), 900).unwrap();
+        syndicate.record_contribution("grinder", 5_000).unwrap();
+
+        // The default formula ignores contributions
+        assert_eq!(syndicate.get_member("grinder").unwrap().voting_power, 300);
+
+        syndicate.config.voting_power_formula = VotingPowerFormula {
+            reputation_weight_bps: 5_000,
+            contribution_weight_bps: 10_000,
+            contribution_divisor: 10,
+            contribution_cap: 1_000,
+        };
+        syndicate.recalculate_voting_power();
+        assert_eq!(syndicate.get_member("grinder").unwrap().voting_power, 650); // 150 + 500
+        assert_eq!(syndicate.get_member("veteran").unwrap().voting_power, 450);
+
+        // Contributions count up to the cap
+        syndicate.record_contribution("grinder", 100_000).unwrap();
+        assert_eq!(syndicate.get_member("grinder").unwrap().voting_power, 1_150);
+
+        let id = syndicate.create_proposal("founder".into(), ProposalType::Custom {
+            title: "T".into(),
+            description: String::new(),
+        }).unwrap().id.clone();
+        syndicate.vote(&id, "grinder", VoteChoice::For).unwrap();
+        syndicate.vote(&id, "veteran", VoteChoice::Against).unwrap();
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Passed);
+    }
+
+    #[test]
+    fn test_voting_power_decay() {
+        let clock = MockClock::new(0);
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            voting_power_half_life_secs: Some(1_000),
+            min_voting_power: 50,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap().with_clock(clock.clone());
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate.add_member("alice".into(), 800).unwrap();
+        let power = |s: &Syndicate| s.get_member("alice").unwrap().voting_power;
+
+        clock.set(1_000);
+        syndicate.apply_voting_power_decay(1_000);
+        assert_eq!(power(&syndicate), 400);
+
+        clock.set(3_000);
+        syndicate.apply_voting_power_decay(3_000);
+        assert_eq!(power(&syndicate), 100);
+        syndicate.apply_voting_power_decay(10_000);
+        assert_eq!(power(&syndicate), 50);
+        assert_eq!(syndicate.get_member("alice").unwrap().reputation, 800);
+
+        // The snapshot holds the decayed power even after alice becomes active
+        let id = syndicate.create_proposal("founder".into(), ProposalType::Custom {
+            title: "T".into(),
+            description: String::new(),
+        }).unwrap().id.clone();
+        assert_eq!(syndicate.get_proposal(&id).unwrap().power_snapshot["alice"], 100);
+        syndicate.vote(&id, "alice", VoteChoice::For).unwrap();
+        assert_eq!(syndicate.get_proposal(&id).unwrap().votes_for, 100);
+
+        syndicate.apply_voting_power_decay(3_000);
+        assert_eq!(power(&syndicate), 800);
+
+        // Another member's update leaves alice's power for the decay step
+        clock.set(5_000);
+        syndicate.update_reputation("founder", 10).unwrap();
+        syndicate.record_contribution("founder", 100).unwrap();
+        assert_eq!(power(&syndicate), 800);
+        let founder = syndicate.get_member("founder").unwrap();
+        assert_eq!(founder.voting_power, syndicate.config().member_voting_power(founder));
+        syndicate.apply_voting_power_decay(5_000);
+        assert_eq!(power(&syndicate), 200);
+    }
+
+    #[test]
+    fn test_observers_excluded_from_quorum() {
+        let mut syndicate = test_syndicate();
+        syndicate.config.quorum_bps = 5000;
+        syndicate.add_member("alice".into(), 500).unwrap();
+        for observer in ["obs-1", "obs-2"] {
+            syndicate.add_member(observer.into(), 1000).unwrap();
+            syndicate.set_role("founder", observer, MemberRole::Observer).unwrap();
+            assert_eq!(syndicate.get_member(observer).unwrap().voting_power, 0);
+        }
+
+        // 1000 of the 1500 member power; observers would push the denominator to 3500
+        let id = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        assert!(!syndicate.get_proposal(&id).unwrap().power_snapshot.contains_key("obs-1"));
+        assert!(matches!(syndicate.vote(&id, "obs-1", VoteChoice::For), Err(SyndicateError::ObserverCannotVote)));
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Passed);
+
+        // 500 of 1500 misses quorum
+        let id = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        syndicate.vote(&id, "alice", VoteChoice::For).unwrap();
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Rejected);
+
+        // Observers keep their reputation and contributions and can be promoted
+        syndicate.config.syndicate_fee_bps = 0;
+        syndicate.record_contribution("obs-1", 100).unwrap();
+        syndicate.update_reputation("obs-1", -100).unwrap();
+        assert_eq!(syndicate.get_member("obs-1").unwrap().voting_power, 0);
+        syndicate.set_role("founder", "obs-1", MemberRole::Member).unwrap();
+        let member = syndicate.get_member("obs-1").unwrap();
+        assert_eq!((member.contribution_score, member.voting_power), (100, 900));
+
+        // 1000 of 2400 misses quorum once obs-1 counts
+        let id = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        syndicate.vote(&id, "founder", VoteChoice::For).unwrap();
+        syndicate.proposals.get_mut(&id).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&id).unwrap(), ProposalStatus::Rejected);
+    }
+
+    #[test]
+    fn test_overflowing_amounts_and_durations() {
+        let clock = MockClock::new(1_000);
+        let mut syndicate = test_syndicate().with_clock(clock);
+        syndicate.add_member("alice".into(), 500).unwrap();
+
+        syndicate.deposit("alice", 1_000).unwrap();
+        assert!(matches!(syndicate.deposit("alice", u64::MAX), Err(SyndicateError::TreasuryOverflow(u64::MAX))));
+        assert_eq!(syndicate.treasury().pool(), 1_000);
+        assert_eq!(syndicate.treasury().deposited_by("alice"), 1_000);
+
+        syndicate.record_contribution("alice", u64::MAX).unwrap();
+        syndicate.record_contribution("alice", u64::MAX).unwrap();
+        assert_eq!(syndicate.get_member("alice").unwrap().contribution_score, u64::MAX);
+
+        syndicate.config.proposal_duration = u64::MAX;
+        syndicate.config.execution_delay_secs.insert(ProposalKind::Custom, u64::MAX);
+        let id = syndicate.create_proposal("founder".into(), custom()).unwrap().id.clone();
+        assert_eq!(syndicate.get_proposal(&id).unwrap().deadline, u64::MAX);
+        pass_existing(&mut syndicate, &id);
+        assert_eq!(syndicate.get_proposal(&id).unwrap().executable_at, Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_execution_timelock() {
+        let clock = MockClock::new(1_000);
+        let mut config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            ..Default::default()
+        };
+        config.execution_delay_secs.insert(ProposalKind::UpdateConfig, 3_600);
+        let mut syndicate = Syndicate::new(config).unwrap().with_clock(clock.clone());
+        syndicate.add_founder("founder".into()).unwrap();
+        let set_name = || ProposalType::UpdateConfig(ConfigChange::SetName("Renamed".into()));
+
+        let id = pass_proposal(&mut syndicate, set_name());
+        assert_eq!(syndicate.get_proposal(&id).unwrap().executable_at, Some(4_600));
+        assert!(matches!(
+            syndicate.execute_proposal(&id),
+            Err(SyndicateError::TimelockActive { until: 4_600 })
+        ));
+        assert_eq!(syndicate.get_proposal(&id).unwrap().status, ProposalStatus::Passed);
+
+        clock.advance(3_600);
+        syndicate.execute_proposal(&id).unwrap();
+        assert_eq!(syndicate.config().name, "Renamed");
+
+        // Members can still react during the window
+        let cancelled = pass_proposal(&mut syndicate, set_name());
+        syndicate.cancel_proposal("founder", &cancelled).unwrap();
+        assert_eq!(syndicate.get_proposal(&cancelled).unwrap().status, ProposalStatus::Cancelled);
+
+        let vetoed = pass_proposal(&mut syndicate, set_name());
+        syndicate.veto("founder", &vetoed, "wait").unwrap();
+        clock.advance(3_600);
+        assert!(matches!(
+            syndicate.execute_proposal(&vetoed),
+            Err(SyndicateError::ProposalNotPassed(ProposalStatus::Vetoed))
+        ));
+
+        // Kinds without a delay execute immediately
+        let custom = pass_proposal(&mut syndicate, ProposalType::Custom {
+            title: "T".into(),
+            description: String::new(),
+        });
+        syndicate.execute_proposal(&custom).unwrap();
+    }
+
+    #[test]
+    fn test_veto() {
+        let clock = MockClock::new(1_000);
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            max_vetoes_per_period: Some(2),
+            veto_period_secs: 1_000,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap().with_clock(clock.clone());
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate.add_member("alice".into(), 500).unwrap();
+
+        let active = syndicate.create_proposal("alice".into(), custom()).unwrap().id.clone();
+        assert!(matches!(syndicate.veto("alice", &active, "no"), Err(SyndicateError::PermissionDenied)));
+
+        syndicate.veto("founder", &active, "conflicts with charter").unwrap();
+        let proposal = syndicate.get_proposal(&active).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Vetoed);
+        assert_eq!(proposal.veto.as_ref().unwrap().reason, "conflicts with charter");
+
+        let passed = pass_proposal(&mut syndicate, custom());
+        syndicate.veto("founder", &passed, "too risky").unwrap();
+        assert_eq!(syndicate.vetoes_used("founder"), 2);
+
+        let executed = pass_proposal(&mut syndicate, custom());
+        syndicate.execute_proposal(&executed).unwrap();
+        assert!(matches!(
+            syndicate.veto("founder", &executed, "late"),
+            Err(SyndicateError::CannotVeto(ProposalStatus::Executed))
+        ));
+
+        let another = syndicate.create_proposal("alice".into(), custom()).unwrap().id.clone();
+        assert!(matches!(syndicate.veto("founder", &another, "again"), Err(SyndicateError::VetoLimitReached)));
+
+        // The window rolls forward
+        clock.advance(1_001);
+        assert_eq!(syndicate.vetoes_used("founder"), 0);
+        syndicate.veto("founder", &another, "again").unwrap();
+    }
+
+    #[test]
+    fn test_invite_only_membership() {
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            visibility: SyndicateVisibility::InviteOnly,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap();
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        let proof = test_proof("bob");
+
+        assert!(matches!(
+            syndicate.request_membership("bob".into(), 500, Some(&proof), None),
+            Err(SyndicateError::InvitationRequired)
+        ));
+        assert!(matches!(syndicate.invite("alice", "bob"), Err(SyndicateError::PermissionDenied)));
+
+        syndicate.invite("founder", "bob").unwrap();
+        assert_eq!(syndicate.invitations()["bob"].invited_by, "founder");
+        syndicate.request_membership("bob".into(), 500, Some(&proof), None).unwrap();
+        assert!(syndicate.invitations().is_empty());
+    }
+
+    fn pnl_proof(agent_id: &str, pnl_bps: i64, threshold_bps: i64) -> PerformanceProof {
+        let mut metrics = zk_proofs::PerformanceMetrics::new(
+            agent_id.into(),
+            zk_proofs::PerformancePeriod::Monthly,
+        );
+        metrics.pnl_bps = pnl_bps;
+        PerformanceProof::prove_pnl_threshold(&metrics, threshold_bps).unwrap()
+    }
+
+    #[test]
+    fn test_signed_invites() {
+        use sdkey_manager::{AgentMetadata, AgentPermissions};
+
+        let clock = MockClock::new(1_000);
+        let founder = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let member = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            visibility: SyndicateVisibility::InviteOnly,
+            min_reputation: 500,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap().with_clock(clock.clone());
+        syndicate.add_founder(founder.agent_id()).unwrap();
+        syndicate.add_member(member.agent_id(), 600).unwrap();
+
+        assert!(matches!(
+            syndicate.create_invite(&member, "alice", 100),
+            Err(SyndicateError::PermissionDenied)
+        ));
+        let invite = syndicate.create_invite(&founder, "alice", 100).unwrap();
+        assert!(invite.verify());
+
+        // Someone else's key can't vouch for the founder
+        let mut forged = invite.clone();
+        forged.issuer_public_key = member.public_key_hex();
+        forged.signature = member.sign_hex(&forged.signing_message()).unwrap();
+        assert!(matches!(
+            syndicate.request_membership("alice".into(), 0, None, Some(&forged)),
+            Err(SyndicateError::ForgedInvite)
+        ));
+
+        let mut retargeted = invite.clone();
+        retargeted.invitee_agent_id = "mallory".into();
+        assert!(matches!(
+            syndicate.request_membership("mallory".into(), 0, None, Some(&retargeted)),
+            Err(SyndicateError::ForgedInvite)
+        ));
+        assert!(matches!(
+            syndicate.request_membership("bob".into(), 0, None, Some(&invite)),
+            Err(SyndicateError::InviteNotApplicable)
+        ));
+
+        // Reputation and proof gates are skipped, but only once
+        syndicate.request_membership("alice".into(), 0, None, Some(&invite)).unwrap();
+        assert!(matches!(
+            syndicate.request_membership("alice".into(), 0, None, Some(&invite)),
+            Err(SyndicateError::InviteAlreadyUsed)
+        ));
+        // Re-encoding the signature doesn't make it a new invite
+        let mut reencoded = invite.clone();
+        reencoded.signature = reencoded.signature.to_uppercase();
+        assert!(reencoded.verify());
+        assert!(matches!(
+            syndicate.request_membership("alice".into(), 0, None, Some(&reencoded)),
+            Err(SyndicateError::InviteAlreadyUsed)
+        ));
+
+        let forever = syndicate.create_invite(&founder, "dave", u64::MAX).unwrap();
+        assert_eq!(forever.expires_at, u64::MAX);
+
+        let late = syndicate.create_invite(&founder, "carol", 100).unwrap();
+        clock.advance(100);
+        assert!(matches!(
+            syndicate.request_membership("carol".into(), 0, None, Some(&late)),
+            Err(SyndicateError::InviteExpired { expires_at: 1_100 })
+        ));
+    }
+
+    #[test]
+    fn test_membership_proof_verification() {
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            min_pnl_bps: 200,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap();
+        syndicate.add_founder("founder".into()).unwrap();
+        let request = |s: &mut Syndicate, proof: &PerformanceProof| {
+            s.request_membership("alice".into(), 500, Some(proof), None)
+        };
+
+        // A valid proof, but for someone else
+        assert!(matches!(
+            request(&mut syndicate, &pnl_proof("bob", 500, 300)),
+            Err(SyndicateError::ProofAgentMismatch { .. })
+        ));
+        assert!(matches!(
+            request(&mut syndicate, &pnl_proof("alice", 500, 100)),
+            Err(SyndicateError::InsufficientPerformance { required: 200, proven: 100 })
+        ));
+
+        let mut tampered = pnl_proof("alice", 500, 300);
+        tampered.public_inputs.threshold = 400;
+        assert!(matches!(request(&mut syndicate, &tampered), Err(SyndicateError::InvalidProof(_))));
+
+        let mut metrics = zk_proofs::PerformanceMetrics::new("alice".into(), zk_proofs::PerformancePeriod::Monthly);
+        metrics.sharpe_ratio_x100 = 250;
+        let sharpe = PerformanceProof::prove_sharpe_threshold(&metrics, 200).unwrap();
+        assert!(matches!(
+            request(&mut syndicate, &sharpe),
+            Err(SyndicateError::WrongProofType(ProofType::SharpeThreshold))
+        ));
+
+        let id = request(&mut syndicate, &pnl_proof("alice", 500, 300)).unwrap();
+        assert_eq!(syndicate.pending_memberships()[&id].agent_id, "alice");
+    }
+
+    #[test]
+    fn test_aggregate_performance() {
+        let mut syndicate = test_syndicate();
+        syndicate.add_member("alice".into(), 500).unwrap();
+        syndicate.add_member("bob".into(), 500).unwrap();
+        syndicate.add_member("carol".into(), 500).unwrap();
+
+        let mut stale = pnl_proof("alice", 500, 100);
+        stale.generated_at -= 10;
+        let mut tampered = pnl_proof("bob", 500, 100);
+        tampered.public_inputs.threshold = 5000;
+
+        let proofs = vec![
+            pnl_proof("founder", 3000, 2000),
+            stale,
+            pnl_proof("alice", 3000, 1000),
+            tampered,
+            pnl_proof("mallory", 3000, 2000),
+        ];
+        let summary = syndicate.aggregate_performance(&proofs, &ProofVerifier::new());
+
+        assert_eq!(summary.active_members, 4);
+        assert_eq!(summary.proven_members, 2);
+        assert_eq!(summary.coverage_bps, 5000);
+        assert_eq!(summary.discarded_proofs, 3);
+        assert_eq!(summary.pnl_thresholds, BTreeMap::from([(1000, 1), (2000, 1)]));
+        // (200 + 100 + 0 + 0) / 4
+        assert_eq!(summary.combined_score, 75);
+
+        let json = serde_json::to_string(&summary).unwrap();
+        let restored: SyndicatePerformanceSummary = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, summary);
+    }
+
+    #[test]
+    fn test_removal_evidence() {
+        use sdkey_manager::{AgentMetadata, AgentPermissions};
+
+        let config = SyndicateConfig {
+            id: "syndicate-001".into(),
+            require_removal_evidence: true,
+            ..Default::default()
+        };
+        let mut syndicate = Syndicate::new(config).unwrap();
+        syndicate.add_founder("founder".into()).unwrap();
+        syndicate.add_member("bob".into(), 500).unwrap();
+        let key = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+
+        assert!(matches!(
+            syndicate.create_proposal("founder".into(), ProposalType::RemoveMember {
+                agent_id: "bob".into(),
+                evidence: vec![],
+            }),
+            Err(SyndicateError::EvidenceRequired)
+        ));
+
+        // A statement signed about someone else doesn't count
+        let misdirected = EvidenceItem::signed_statement(&key, "founder", "alice", "front-ran the pool").unwrap();
+        assert!(matches!(
+            syndicate.create_proposal("founder".into(), ProposalType::RemoveMember {
+                agent_id: "bob".into(),
+                evidence: vec![misdirected],
+            }),
+            Err(SyndicateError::InvalidEvidence(0))
+        ));
+
+        let evidence = vec![
+            EvidenceItem::SlashedEvent { event_id: "slash-42".into() },
+            EvidenceItem::signed_statement(&key, "founder", "bob", "front-ran the pool").unwrap(),
+        ];
+        let id = pass_proposal(&mut syndicate, ProposalType::RemoveMember { agent_id: "bob".into(), evidence });
+
+        let json = serde_json::to_string(syndicate.get_proposal(&id).unwrap()).unwrap();
+        assert!(json.contains("slash-42") && json.contains("front-ran the pool"));
+
+        assert_eq!(
+            syndicate.execute_proposal(&id).unwrap(),
+            "Removed member bob (evidence: slashed event slash-42; statement by founder)"
+        );
+    }
+
+    #[test]
+    fn test_execute_config_and_other_proposals() {
+        let mut syndicate = test_syndicate();
+        syndicate.deposit("founder", 1000).unwrap();
+
+        let id = pass_proposal(&mut syndicate, ProposalType::UpdateConfig(ConfigChange::SetMaxMembers(42)));
+        assert_eq!(syndicate.execute_proposal(&id).unwrap(), "Updated max_members");
+        assert_eq!(syndicate.config().max_members, 42);
+
+        let id = pass_proposal(&mut syndicate, ProposalType::UpdateConfig(
+            ConfigChange::SetVotingModel(VotingModel::Quadratic),
+        ));
+        syndicate.execute_proposal(&id).unwrap();
+        assert_eq!(syndicate.config().voting_model, VotingModel::Quadratic);
+
+        for proposal_type in [
+            ProposalType::ExecuteAction { action_type: "swap".into(), params: HashMap::new() },
+            ProposalType::DistributeProfits { amount: 1000 },
+            ProposalType::Custom { title: "Test".into(), description: String::new() },
+        ] {
+            let id = pass_proposal(&mut syndicate, proposal_type);
+            let result = syndicate.execute_proposal(&id).unwrap();
+            let proposal = syndicate.get_proposal(&id).unwrap();
+            assert_eq!(proposal.status, ProposalStatus::Executed);
+            assert_eq!(proposal.execution_result.as_deref(), Some(result.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_config_history() {
+        let clock = MockClock::new(1_000);
+        let mut syndicate = test_syndicate().with_clock(clock.clone());
+        syndicate.config.proposal_cooldown_secs = 0;
+        syndicate.config.max_config_versions = 3;
+        syndicate.add_member("alice".into(), 500).unwrap();
+        let custom = custom();
+        let earlier = syndicate.create_proposal("alice".into(), custom).unwrap().id.clone();
+
+        clock.set(2_000);
+        let id = pass_proposal(&mut syndicate, ProposalType::UpdateConfig(ConfigChange::SetVotingThresholdBps(9000)));
+        syndicate.execute_proposal(&id).unwrap();
+        assert_eq!(syndicate.config_at(1_999).unwrap().voting_threshold_bps, 5000);
+        assert_eq!(syndicate.config_at(2_000).unwrap().voting_threshold_bps, 9000);
+
+        // Tallied against the 50% threshold in force when it was created
+        syndicate.vote(&earlier, "founder", VoteChoice::For).unwrap();
+        syndicate.vote(&earlier, "alice", VoteChoice::Against).unwrap();
+        syndicate.proposals.get_mut(&earlier).unwrap().deadline = 0;
+        assert_eq!(syndicate.finalize_proposal(&earlier).unwrap(), ProposalStatus::Passed);
+
+        for (at, fee) in [(3_000, 100), (4_000, 200)] {
+            clock.set(at);
+            let id = pass_proposal(&mut syndicate, ProposalType::UpdateConfig(ConfigChange::SetSyndicateFeeBps(fee)));
+            syndicate.execute_proposal(&id).unwrap();
+        }
+        let changed_at: Vec<u64> = syndicate.config_versions().iter().map(|(at, _)| *at).collect();
+        assert_eq!(changed_at, [2_000, 3_000, 4_000]);
+        assert_eq!(syndicate.compacted_config_versions(), 1);
+        assert!(syndicate.config_at(1_500).is_none());
+        assert_eq!(syndicate.config_at(3_500).unwrap().syndicate_fee_bps, 100);
+
+        let restored = Syndicate::restore(syndicate.snapshot()).unwrap();
+        assert_eq!(restored.config_versions().len(), 3);
+        assert_eq!(restored.compacted_config_versions(), 1);
+    }
+
+    #[test]
+    fn test_failed_execution() {
+        let mut syndicate = test_syndicate();
+
+        // Active proposals cannot be executed
+        let id = syndicate.create_proposal(
+            "founder".into(),
+            ProposalType::AddMember { agent_id: "agent-a".into() },
+        ).unwrap().id.clone();
+        assert!(matches!(
+            syndicate.execute_proposal(&id),
+            Err(SyndicateError::ProposalNotPassed(ProposalStatus::Active))
+        ));
+
+        // Member limit hit between passing and executing
+        let id = request_and_pass(&mut syndicate, "agent-b", 500);
+        syndicate.config.max_members = 1;
+        assert!(matches!(
+            syndicate.execute_proposal(&id),
+            Err(SyndicateError::MemberLimitReached)
+        ));
+
+        let proposal = syndicate.get_proposal(&id).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::ExecutionFailed);
+        assert!(proposal.execution_result.as_ref().unwrap().starts_with("Execution failed"));
+        assert!(syndicate.get_member("agent-b").is_none());
+    }
 }
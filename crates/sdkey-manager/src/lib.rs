@@ -8,7 +8,7 @@ mod sdkey;
 mod delegation;
 
 pub use permissions::{AgentPermissions, PermissionLevel, TradingRestrictions};
-pub use sdkey::{AgentSDKey, AgentMetadata, PublicAgentIdentity, SDKeyError, SDKeyId};
+pub use sdkey::{verify_signature, AgentSDKey, AgentMetadata, PublicAgentIdentity, SDKeyError, SDKeyId};
 pub use delegation::{Delegation, DelegationChain, DelegationError};
 
 /// Re-export common types
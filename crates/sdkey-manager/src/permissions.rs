@@ -3,11 +3,12 @@
 use serde::{Deserialize, Serialize};
 
 /// Permission level for agent operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PermissionLevel {
     /// Read-only access - can query but not execute
     ReadOnly,
     /// Limited trading with strict constraints
+    #[default]
     Limited,
     /// Standard trading within defined parameters
     Standard,
@@ -17,12 +18,6 @@ pub enum PermissionLevel {
     Admin,
 }
 
-impl Default for PermissionLevel {
-    fn default() -> Self {
-        Self::Limited
-    }
-}
-
 /// Trading restrictions for an agent
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradingRestrictions {
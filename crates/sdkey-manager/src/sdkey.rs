@@ -241,6 +241,20 @@ impl AgentSDKey {
     }
 }
 
+/// Verify a signature against a raw Ed25519 public key
+pub fn verify_signature(
+    public_key: &[u8; 32],
+    message: &[u8],
+    signature: &[u8; 64],
+) -> Result<(), SDKeyError> {
+    let verifying_key = VerifyingKey::from_bytes(public_key)
+        .map_err(|_| SDKeyError::InvalidPublicKey)?;
+    let sig = Signature::from_bytes(signature);
+    verifying_key
+        .verify(message, &sig)
+        .map_err(|_| SDKeyError::InvalidSignature)
+}
+
 /// Public agent identity (can be shared)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PublicAgentIdentity {
@@ -267,6 +281,9 @@ pub enum SDKeyError {
     #[error("Invalid signature")]
     InvalidSignature,
 
+    #[error("Invalid public key")]
+    InvalidPublicKey,
+
     #[error("Signing failed: {0}")]
     SigningFailed(String),
 }
@@ -308,6 +325,21 @@ mod tests {
         assert!(key.verify(tampered, &signature).is_err());
     }
 
+    #[test]
+    fn test_verify_with_public_key() {
+        let key = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+        let other = AgentSDKey::generate(AgentMetadata::default(), AgentPermissions::default());
+
+        let message = b"vote";
+        let signature = key.sign(message).unwrap();
+
+        assert!(verify_signature(&key.public_key(), message, &signature).is_ok());
+        assert!(matches!(
+            verify_signature(&other.public_key(), message, &signature),
+            Err(SDKeyError::InvalidSignature)
+        ));
+    }
+
     #[test]
     fn test_deterministic_from_seed() {
         let seed = [42u8; 32];
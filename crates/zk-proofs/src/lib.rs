@@ -9,8 +9,8 @@ mod proofs;
 mod verifier;
 
 pub use performance::{PerformanceMetrics, PerformancePeriod};
-pub use proofs::{PerformanceProof, ProofError, ProofType};
-pub use verifier::{ProofVerifier, VerificationResult};
+pub use proofs::{PerformanceProof, ProofError, ProofType, ThresholdCondition};
+pub use verifier::{OnChainProofData, ProofVerifier, VerificationResult};
 
 /// Re-export common types
 pub mod prelude {
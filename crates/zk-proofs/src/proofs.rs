@@ -144,7 +144,7 @@ impl PerformanceProof {
             let mut hasher = Sha256::new();
             hasher.update(metrics.agent_id.as_bytes());
             hasher.update(now.to_le_bytes());
-            hasher.update(&[proof_type as u8]);
+            hasher.update([proof_type as u8]);
             format!("proof_{}", hex::encode(&hasher.finalize()[..8]))
         };
 
@@ -188,7 +188,7 @@ impl PerformanceProof {
         let mut hasher = Sha256::new();
         hasher.update(metrics.commitment().as_bytes());
         hasher.update(threshold.to_le_bytes());
-        hasher.update(&[condition as u8]);
+        hasher.update([condition as u8]);
         hasher.update(b"PLACEHOLDER_PROOF_V1");
         hasher.finalize().to_vec()
     }
@@ -55,6 +55,7 @@ impl VerificationResult {
 /// Verifier for ZK proofs
 pub struct ProofVerifier {
     /// Accepted proof versions
+    #[allow(dead_code)]
     accepted_versions: Vec<String>,
     /// Maximum proof age in seconds
     max_proof_age: u64,
@@ -136,7 +137,7 @@ impl ProofVerifier {
         let mut hasher = Sha256::new();
         hasher.update(proof.commitment.as_bytes());
         hasher.update(proof.public_inputs.threshold.to_le_bytes());
-        hasher.update(&[proof.public_inputs.condition as u8]);
+        hasher.update([proof.public_inputs.condition as u8]);
         hasher.update(b"PLACEHOLDER_PROOF_V1");
         hasher.finalize().to_vec()
     }